@@ -0,0 +1,98 @@
+//! Allocations and time per encoded CCR, with a new `Vec` per message, a reused `Vec` and a
+//! pre-sized buffer. Run with `cargo bench --bench encode`.
+
+use diameter_interface::modeling::diameter::DiameterMessage;
+use diameter_interface::modeling::message::credit_control::{
+    CreditControlRequest, SubscriptionId, SubscriptionIdType,
+};
+use diameter_interface::modeling::typed::TypedMessage;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::hint::black_box;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Instant;
+
+const ITERATIONS: usize = 100_000;
+
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn credit_control_request() -> DiameterMessage {
+    let mut request = CreditControlRequest::new(
+        "client.example.com;1876543210;523",
+        "client.example.com",
+        "example.com",
+        "example.com",
+        16777238,
+        "gx@example.com",
+    );
+    request.cc_request_number = 1;
+    request.subscription_id.push(SubscriptionId::new(
+        SubscriptionIdType::EndUserImsi,
+        "001010123456789",
+    ));
+    request.subscription_id.push(SubscriptionId::new(
+        SubscriptionIdType::EndUserE164,
+        "15551234567",
+    ));
+    request.to_message(1, 1)
+}
+
+fn bench<F: FnMut()>(name: &str, mut f: F) {
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        f();
+    }
+    let elapsed = start.elapsed();
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed) - allocations;
+    println!(
+        "{:<24} {:>6.2} allocations/message {:>8.1} ns/message",
+        name,
+        allocations as f64 / ITERATIONS as f64,
+        elapsed.as_nanos() as f64 / ITERATIONS as f64,
+    );
+}
+
+fn main() {
+    let message = credit_control_request();
+    println!("CCR of {} octets", message.encoded_len());
+
+    bench("encode_to, new Vec", || {
+        let mut buffer = Vec::new();
+        message.encode_to(&mut buffer).unwrap();
+        black_box(&buffer);
+    });
+
+    let mut buffer = Vec::new();
+    bench("encode_to, reused Vec", || {
+        buffer.clear();
+        message.encode_to(&mut buffer).unwrap();
+        black_box(&buffer);
+    });
+
+    let mut buffer = vec![0u8; message.encoded_len()];
+    bench("encode_into", || {
+        let length = message.encode_into(&mut buffer).unwrap();
+        black_box(&buffer[..length]);
+    });
+}
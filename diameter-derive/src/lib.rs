@@ -0,0 +1,394 @@
+//! Derive macros for `diameter-interface`, see `diameter_interface::modeling::typed`.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{
+    Data, DeriveInput, Fields, GenericArgument, Ident, LitInt, Path, PathArguments, Type,
+    parse_macro_input,
+};
+
+/// Implements `AvpGroup` and `AvpField` so the struct encodes as a Grouped AVP.
+#[proc_macro_derive(DiameterAvp, attributes(avp))]
+pub fn derive_diameter_avp(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_avp(&input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+/// Implements `AvpGroup` and `TypedMessage` from `#[diameter(command = .., application = ..)]`.
+#[proc_macro_derive(DiameterMessage, attributes(avp, diameter))]
+pub fn derive_diameter_message(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_message(&input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+enum Repetition {
+    Required,
+    Optional,
+    Repeated,
+}
+
+struct AvpFieldAttr {
+    ident: Ident,
+    ty: Type,
+    code: u32,
+    vendor_id: Option<u32>,
+    mandatory: bool,
+    min: usize,
+    repetition: Repetition,
+}
+
+struct GroupFields {
+    avps: Vec<AvpFieldAttr>,
+    rest: Option<Ident>,
+}
+
+fn expand_avp(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let group = expand_group(input)?;
+    Ok(quote! {
+        #group
+
+        impl ::diameter_interface::modeling::typed::AvpField for #name {
+            fn to_avp_value(&self) -> ::diameter_interface::modeling::avp::avp::AvpValue {
+                ::diameter_interface::modeling::avp::group::Grouped::new(
+                    ::diameter_interface::modeling::typed::AvpGroup::to_avps(self),
+                )
+                .into()
+            }
+
+            fn from_avp_value(
+                value: &::diameter_interface::modeling::avp::avp::AvpValue,
+            ) -> ::diameter_interface::errors::DiameterResult<Self> {
+                match value.as_grouped() {
+                    Some(avps) => ::diameter_interface::modeling::typed::AvpGroup::from_avps(avps),
+                    None => Err(::diameter_interface::errors::Error::DecodeError(
+                        "Unexpected AVP value type",
+                    )),
+                }
+            }
+        }
+    })
+}
+
+fn expand_message(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let group = expand_group(input)?;
+
+    let mut command: Option<Path> = None;
+    let mut application: Option<Path> = None;
+    let mut application_field: Option<Ident> = None;
+    let mut request = false;
+    let mut proxyable = false;
+    for attr in input.attrs.iter().filter(|a| a.path().is_ident("diameter")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("command") {
+                command = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("application") {
+                application = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("application_field") {
+                application_field = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("request") {
+                request = true;
+            } else if meta.path.is_ident("proxyable") {
+                proxyable = true;
+            } else {
+                return Err(meta.error("unsupported diameter attribute"));
+            }
+            Ok(())
+        })?;
+    }
+    let missing =
+        |what| syn::Error::new_spanned(name, format!("missing #[diameter({} = ..)]", what));
+    let command = command.ok_or_else(|| missing("command"))?;
+    let application = application.ok_or_else(|| missing("application"))?;
+    // Session messages carry their application in a field, e.g. Auth-Application-Id, which
+    // answers may leave out
+    let optional = |field: &Ident| {
+        let fields = parse_fields(input)?;
+        match fields.avps.iter().find(|avp| avp.ident == *field) {
+            Some(avp) => Ok(matches!(avp.repetition, Repetition::Optional)),
+            None => Err(syn::Error::new_spanned(
+                field,
+                "application_field must name an AVP field",
+            )),
+        }
+    };
+    let application_id = match application_field {
+        Some(field) => {
+            let application_id = match optional(&field)? {
+                true => quote! {
+                    self.#field.and_then(|id| {
+                        ::diameter_interface::modeling::message::application_id::ApplicationId::try_from(id).ok()
+                    })
+                },
+                false => quote! {
+                    ::diameter_interface::modeling::message::application_id::ApplicationId::try_from(self.#field).ok()
+                },
+            };
+            Some(quote! {
+                fn application_id(&self) -> ::diameter_interface::modeling::message::application_id::ApplicationId {
+                    #application_id.unwrap_or(Self::APPLICATION_ID)
+                }
+            })
+        }
+        None => None,
+    };
+
+    Ok(quote! {
+        #group
+
+        impl ::diameter_interface::modeling::typed::TypedMessage for #name {
+            const COMMAND_CODE: ::diameter_interface::modeling::message::command_code::CommandCode =
+                ::diameter_interface::modeling::message::command_code::CommandCode::#command;
+            const APPLICATION_ID: ::diameter_interface::modeling::message::application_id::ApplicationId =
+                ::diameter_interface::modeling::message::application_id::ApplicationId::#application;
+            const REQUEST: bool = #request;
+            const PROXYABLE: bool = #proxyable;
+
+            #application_id
+        }
+    })
+}
+
+fn expand_group(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let fields = parse_fields(input)?;
+
+    let encode = fields.avps.iter().map(|field| {
+        let ident = &field.ident;
+        let code = field.code;
+        let vendor_id = option_tokens(field.vendor_id);
+        let flags = match field.mandatory {
+            true => quote!(::diameter_interface::modeling::avp::avp::AvpFlags::M),
+            false => quote!(::diameter_interface::modeling::avp::avp::AvpFlags::O),
+        };
+        let push = quote! {
+            __avps.push(::diameter_interface::modeling::avp::avp::Avp::new(
+                #code,
+                #flags,
+                #vendor_id,
+                ::diameter_interface::modeling::typed::AvpField::to_avp_value(value),
+            ));
+        };
+        match field.repetition {
+            Repetition::Required => quote! { let value = &self.#ident; #push },
+            Repetition::Optional => quote! { if let Some(value) = &self.#ident { #push } },
+            Repetition::Repeated => quote! { for value in &self.#ident { #push } },
+        }
+    });
+    let encode_rest = fields.rest.iter().map(|rest| {
+        quote! { __avps.extend(self.#rest.iter().cloned()); }
+    });
+
+    let declare = fields.avps.iter().map(|field| {
+        let ident = &field.ident;
+        let ty = &field.ty;
+        match field.repetition {
+            Repetition::Required | Repetition::Optional => {
+                quote! { let mut #ident: Option<#ty> = None; }
+            }
+            Repetition::Repeated => {
+                quote! { let mut #ident: ::diameter_interface::modeling::typed::__private::Vec<#ty> = ::diameter_interface::modeling::typed::__private::Vec::new(); }
+            }
+        }
+    });
+    let decode = fields.avps.iter().map(|field| {
+        let ident = &field.ident;
+        let code = field.code;
+        let vendor_id = option_tokens(field.vendor_id);
+        let value = quote! {
+            ::diameter_interface::modeling::typed::AvpField::from_avp_value(__avp.value())?
+        };
+        // Further occurrences of a single-valued field are kept with the unmapped AVPs
+        let extra = match fields.rest {
+            Some(ref rest) => quote! { #rest.push(__avp.clone()) },
+            None => quote! {
+                return Err(::diameter_interface::errors::Error::TooManyAvps(#code, #vendor_id))
+            },
+        };
+        let store = match field.repetition {
+            Repetition::Required | Repetition::Optional => quote! {
+                match #ident {
+                    None => #ident = Some(#value),
+                    Some(_) => #extra,
+                }
+            },
+            Repetition::Repeated => quote! { #ident.push(#value); },
+        };
+        quote! { (#code, #vendor_id) => { #store } }
+    });
+    let (declare_rest, decode_rest) = match fields.rest {
+        Some(ref rest) => (
+            quote! { let mut #rest = ::diameter_interface::modeling::typed::__private::Vec::new(); },
+            quote! { _ => #rest.push(__avp.clone()), },
+        ),
+        None => (quote! {}, quote! { _ => {} }),
+    };
+    let check_min = fields
+        .avps
+        .iter()
+        .filter(|field| field.min > 0)
+        .map(|field| {
+            let ident = &field.ident;
+            let code = field.code;
+            let vendor_id = option_tokens(field.vendor_id);
+            let min = field.min;
+            quote! {
+                if #ident.len() < #min {
+                    return Err(::diameter_interface::errors::Error::MissingAvp(#code, #vendor_id));
+                }
+            }
+        });
+    let construct = fields.avps.iter().map(|field| {
+        let ident = &field.ident;
+        let code = field.code;
+        let vendor_id = option_tokens(field.vendor_id);
+        match field.repetition {
+            Repetition::Required => quote! {
+                #ident: #ident.ok_or(::diameter_interface::errors::Error::MissingAvp(#code, #vendor_id))?,
+            },
+            Repetition::Optional | Repetition::Repeated => quote! { #ident, },
+        }
+    });
+    let construct_rest = fields.rest.iter().map(|rest| quote! { #rest, });
+
+    Ok(quote! {
+        impl ::diameter_interface::modeling::typed::AvpGroup for #name {
+            fn to_avps(&self) -> ::diameter_interface::modeling::typed::__private::Vec<::diameter_interface::modeling::avp::avp::Avp> {
+                let mut __avps = ::diameter_interface::modeling::typed::__private::Vec::new();
+                #(#encode)*
+                #(#encode_rest)*
+                __avps
+            }
+
+            #[allow(unreachable_patterns)]
+            fn from_avps(
+                __avps: &[::diameter_interface::modeling::avp::avp::Avp],
+            ) -> ::diameter_interface::errors::DiameterResult<Self> {
+                #(#declare)*
+                #declare_rest
+                for __avp in __avps {
+                    match (__avp.code(), __avp.vendor_id()) {
+                        #(#decode)*
+                        #decode_rest
+                    }
+                }
+                #(#check_min)*
+                Ok(Self {
+                    #(#construct)*
+                    #(#construct_rest)*
+                })
+            }
+        }
+    })
+}
+
+fn parse_fields(input: &DeriveInput) -> syn::Result<GroupFields> {
+    let Data::Struct(ref data) = input.data else {
+        return Err(syn::Error::new_spanned(input, "expected a struct"));
+    };
+    let Fields::Named(ref named) = data.fields else {
+        return Err(syn::Error::new_spanned(input, "expected named fields"));
+    };
+
+    let mut fields = GroupFields {
+        avps: vec![],
+        rest: None,
+    };
+    for field in &named.named {
+        let ident = field.ident.clone().unwrap();
+        let mut code: Option<u32> = None;
+        let mut vendor_id: Option<u32> = None;
+        let mut mandatory = false;
+        let mut min: Option<usize> = None;
+        let mut rest = false;
+        for attr in field.attrs.iter().filter(|a| a.path().is_ident("avp")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("code") {
+                    code = Some(meta.value()?.parse::<LitInt>()?.base10_parse()?);
+                } else if meta.path.is_ident("vendor") {
+                    vendor_id = Some(meta.value()?.parse::<LitInt>()?.base10_parse()?);
+                } else if meta.path.is_ident("mandatory") {
+                    mandatory = true;
+                } else if meta.path.is_ident("min") {
+                    min = Some(meta.value()?.parse::<LitInt>()?.base10_parse()?);
+                } else if meta.path.is_ident("rest") {
+                    rest = true;
+                } else {
+                    return Err(meta.error("unsupported avp attribute"));
+                }
+                Ok(())
+            })?;
+        }
+
+        if rest {
+            if fields.rest.is_some() {
+                return Err(syn::Error::new_spanned(
+                    field,
+                    "only one #[avp(rest)] field is allowed",
+                ));
+            }
+            fields.rest = Some(ident);
+            continue;
+        }
+        let code =
+            code.ok_or_else(|| syn::Error::new_spanned(field, "missing #[avp(code = ..)]"))?;
+        let (repetition, ty) = match (
+            wrapped_type(&field.ty, "Option"),
+            wrapped_type(&field.ty, "Vec"),
+        ) {
+            (Some(inner), _) => (Repetition::Optional, inner.clone()),
+            (None, Some(inner)) if !is_u8(inner) => (Repetition::Repeated, inner.clone()),
+            _ => (Repetition::Required, field.ty.clone()),
+        };
+        if min.is_some() && !matches!(repetition, Repetition::Repeated) {
+            return Err(syn::Error::new_spanned(
+                field,
+                "#[avp(min = ..)] applies to Vec fields",
+            ));
+        }
+        fields.avps.push(AvpFieldAttr {
+            ident,
+            ty,
+            code,
+            vendor_id,
+            mandatory,
+            min: min.unwrap_or(0),
+            repetition,
+        });
+    }
+    Ok(fields)
+}
+
+/// Returns `T` for `Option<T>`/`Vec<T>`, matched on the last path segment.
+fn wrapped_type<'a>(ty: &'a Type, wrapper: &str) -> Option<&'a Type> {
+    let Type::Path(path) = ty else { return None };
+    let segment = path.path.segments.last()?;
+    if segment.ident != wrapper {
+        return None;
+    }
+    let PathArguments::AngleBracketed(ref args) = segment.arguments else {
+        return None;
+    };
+    match args.args.first()? {
+        GenericArgument::Type(inner) => Some(inner),
+        _ => None,
+    }
+}
+
+// `Vec<u8>` is an OctetString, not a repeated AVP.
+fn is_u8(ty: &Type) -> bool {
+    matches!(ty, Type::Path(path) if path.path.is_ident("u8"))
+}
+
+fn option_tokens(value: Option<u32>) -> TokenStream2 {
+    match value {
+        Some(value) => quote!(Some(#value)),
+        None => quote!(None),
+    }
+}
@@ -0,0 +1,387 @@
+//! # Dictionary Code Generation
+//!
+//! Generates Rust source from a `Dictionary`, meant to be called from a `build.rs`:
+//! ```ignore
+//! // build.rs
+//! let mut dict = Dictionary::new(&[&DEFAULT_DICT_XML]);
+//! dict.try_load_xml(&std::fs::read_to_string("dictionary/vendor.xml")?)?;
+//! let out_dir = std::env::var("OUT_DIR")?;
+//! codegen::write_to(&dict, std::path::Path::new(&out_dir).join("dictionary.rs"))?;
+//! println!("cargo:rerun-if-changed=dictionary/vendor.xml");
+//!
+//! // src/lib.rs
+//! mod dictionary {
+//!     include!(concat!(env!("OUT_DIR"), "/dictionary.rs"));
+//! }
+//! ccr.add(dictionary::avp::cc_request_type(dictionary::enums::CcRequestType::InitialRequest));
+//! ```
+//! The generated file contains:
+//! - `vendor`: a constant per vendor id, e.g. `vendor::_3GPP`.
+//! - `code`: a constant per AVP code, e.g. `code::SESSION_ID`.
+//! - `enums`: a Rust enum per Enumerated AVP with its dictionary values.
+//! - `avp`: a typed constructor (`avp::session_id("...")`) and getter
+//!   (`avp::get_session_id(message.avps())`) per AVP.
+//!
+//! Names that are Rust keywords get a `_` suffix, as in `avp::type_`:
+//! ```
+//! # use diameter_interface::codegen;
+//! # use diameter_interface::modeling::message::dictionary::{Dictionary, DEFAULT_DICT_XML};
+//! let dict = Dictionary::new(&[&DEFAULT_DICT_XML]);
+//! let source = codegen::generate(&dict);
+//! assert!(source.contains("pub const SESSION_ID: u32 = 263;"));
+//! assert!(source.contains("pub fn get_session_id(avps: &[Avp]) -> Option<&str>"));
+//! ```
+
+use crate::errors::DiameterResult;
+use crate::modeling::avp::avp::AvpType;
+use crate::modeling::message::dictionary::{AvpDefinition, Dictionary};
+use std::collections::HashSet;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+const CRATE: &str = "::diameter_interface";
+
+struct GeneratedAvp<'a> {
+    definition: &'a AvpDefinition,
+    constant: String,
+    function: String,
+    enum_type: Option<String>,
+}
+
+pub fn write_to<P: AsRef<Path>>(dictionary: &Dictionary, path: P) -> DiameterResult<()> {
+    fs::write(path, generate(dictionary))?;
+    Ok(())
+}
+
+pub fn generate(dictionary: &Dictionary) -> String {
+    let avps = name_avps(dictionary);
+    let mut out = String::new();
+    writeln!(
+        out,
+        "// @generated by diameter_interface::codegen. Do not edit."
+    )
+    .unwrap();
+
+    writeln!(out, "\n#[allow(dead_code)]\npub mod vendor {{").unwrap();
+    let mut vendor_names = HashSet::new();
+    for (vendor_id, name) in dictionary.vendors() {
+        let constant = unique(screaming_snake_case(name), vendor_id, &mut vendor_names);
+        writeln!(out, "    pub const {}: u32 = {};", constant, vendor_id).unwrap();
+    }
+    writeln!(out, "}}").unwrap();
+
+    writeln!(out, "\n#[allow(dead_code)]\npub mod code {{").unwrap();
+    for avp in &avps {
+        writeln!(
+            out,
+            "    pub const {}: u32 = {};",
+            avp.constant, avp.definition.code
+        )
+        .unwrap();
+    }
+    writeln!(out, "}}").unwrap();
+
+    writeln!(
+        out,
+        "\n#[allow(dead_code, clippy::enum_variant_names)]\npub mod enums {{"
+    )
+    .unwrap();
+    for avp in &avps {
+        if let Some(ref enum_type) = avp.enum_type {
+            write_enum(&mut out, enum_type, avp.definition);
+        }
+    }
+    writeln!(out, "}}").unwrap();
+
+    writeln!(out, "\n#[allow(dead_code)]\npub mod avp {{").unwrap();
+    writeln!(
+        out,
+        "    use {}::modeling::avp::avp::{{Avp, AvpFlags}};",
+        CRATE
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "\n    fn find(avps: &[Avp], code: u32, vendor_id: Option<u32>) -> Option<&Avp> {{\n        \
+         avps.iter().find(|avp| avp.code() == code && avp.vendor_id() == vendor_id)\n    }}"
+    )
+    .unwrap();
+    for avp in &avps {
+        write_accessors(&mut out, avp);
+    }
+    writeln!(out, "}}").unwrap();
+    out
+}
+
+fn name_avps(dictionary: &Dictionary) -> Vec<GeneratedAvp<'_>> {
+    let mut constants = HashSet::new();
+    // The `find` helper of the `avp` module
+    let mut functions = HashSet::from(["find".to_string()]);
+    let mut enum_types = HashSet::new();
+    dictionary
+        .avps()
+        .map(|definition| {
+            let suffix = definition.vendor_id.unwrap_or(definition.code);
+            let enum_type = match (&definition.avp_type, definition.enum_values.is_empty()) {
+                (AvpType::Enumerated, false) => Some(unique(
+                    upper_camel_case(&definition.name),
+                    suffix,
+                    &mut enum_types,
+                )),
+                _ => None,
+            };
+            GeneratedAvp {
+                definition,
+                constant: unique(
+                    screaming_snake_case(&definition.name),
+                    suffix,
+                    &mut constants,
+                ),
+                function: unique_function(snake_case(&definition.name), suffix, &mut functions),
+                enum_type,
+            }
+        })
+        .collect()
+}
+
+fn write_enum(out: &mut String, enum_type: &str, definition: &AvpDefinition) {
+    let mut variants = HashSet::new();
+    let variants: Vec<(String, i32)> = definition
+        .enum_values
+        .iter()
+        .map(|(value, name)| {
+            let variant = unique(upper_camel_case(name), *value as u32, &mut variants);
+            (variant, *value)
+        })
+        .collect();
+
+    writeln!(out, "    /// Values of the {} AVP.", definition.name).unwrap();
+    writeln!(
+        out,
+        "    #[derive(Debug, Clone, Copy, PartialEq, Eq)]\n    #[repr(i32)]\n    pub enum {} {{",
+        enum_type
+    )
+    .unwrap();
+    for (variant, value) in &variants {
+        writeln!(out, "        {} = {},", variant, value).unwrap();
+    }
+    writeln!(out, "    }}\n").unwrap();
+    writeln!(
+        out,
+        "    impl ::core::convert::TryFrom<i32> for {} {{\n        type Error = i32;\n\n        \
+         fn try_from(value: i32) -> ::core::result::Result<Self, Self::Error> {{\n            \
+         match value {{",
+        enum_type
+    )
+    .unwrap();
+    for (variant, value) in &variants {
+        writeln!(
+            out,
+            "                {} => ::core::result::Result::Ok({}::{}),",
+            value, enum_type, variant
+        )
+        .unwrap();
+    }
+    writeln!(
+        out,
+        "                _ => ::core::result::Result::Err(value),\n            }}\n        }}\n    }}\n"
+    )
+    .unwrap();
+}
+
+/// Generic parameters, constructor argument type, AVP value expression, getter return type and
+/// getter conversion.
+struct Accessor {
+    generics: String,
+    argument: String,
+    value: String,
+    getter: String,
+    conversion: &'static str,
+}
+
+fn write_accessors(out: &mut String, avp: &GeneratedAvp) {
+    let definition = avp.definition;
+    let accessor = match (&definition.avp_type, &avp.enum_type) {
+        (AvpType::Enumerated, Some(enum_type)) => Accessor {
+            generics: String::new(),
+            argument: format!("super::enums::{}", enum_type),
+            value: data("enumerated::Enumerated", "value as i32"),
+            getter: format!("super::enums::{}", enum_type),
+            conversion: "as_i32().and_then(|value| value.try_into().ok())",
+        },
+        (AvpType::Enumerated | AvpType::Integer32, _) => {
+            scalar("i32", "integer32::Integer32", "as_i32()")
+        }
+        (AvpType::Integer64, _) => scalar("i64", "integer64::Integer64", "as_i64()"),
+        (AvpType::Unsigned32, _) => scalar("u32", "unsigned32::Unsigned32", "as_u32()"),
+        (AvpType::Unsigned64, _) => scalar("u64", "unsigned64::Unsigned64", "as_u64()"),
+        (AvpType::Float32, _) => scalar("f32", "float32::Float32", "as_f32()"),
+        (AvpType::Float64, _) => scalar("f64", "float64::Float64", "as_f64()"),
+        (AvpType::UTF8String | AvpType::Identity, _) => Accessor {
+            generics: String::new(),
+            argument: "impl Into<String>".to_string(),
+            value: data("utf8_string::UTF8String", "value.into()"),
+            getter: "&str".to_string(),
+            conversion: "as_str()",
+        },
+        (AvpType::OctetString, _) => Accessor {
+            generics: String::new(),
+            argument: "impl Into<Vec<u8>>".to_string(),
+            value: data("octet_string::OctetString", "value.into()"),
+            getter: "&[u8]".to_string(),
+            conversion: "as_bytes()",
+        },
+        (AvpType::DiameterURI, _) => Accessor {
+            generics: String::new(),
+            argument: format!("{}::modeling::avp::diameter_uri::Uri", CRATE),
+            value: data("diameter_uri::DiameterURI", "value"),
+            getter: format!("&{}::modeling::avp::diameter_uri::Uri", CRATE),
+            conversion: "as_uri()",
+        },
+        (AvpType::Time, _) => {
+            let time = format!(
+                "{0}::modeling::avp::time::DateTime<{0}::modeling::avp::time::Utc>",
+                CRATE
+            );
+            Accessor {
+                generics: String::new(),
+                argument: time.clone(),
+                value: data("time::Time", "value"),
+                getter: format!("&{}", time),
+                conversion: "as_time()",
+            }
+        }
+        (AvpType::Address, _) => scalar("std::net::IpAddr", "address::Address", "as_ip_addr()"),
+        (AvpType::AddressIPv4, _) => scalar("std::net::Ipv4Addr", "ipv4::IPv4", "as_ipv4()"),
+        (AvpType::AddressIPv6, _) => scalar("std::net::Ipv6Addr", "ipv6::IPv6", "as_ipv6()"),
+        (AvpType::Grouped, _) => Accessor {
+            generics: String::new(),
+            argument: "Vec<Avp>".to_string(),
+            value: data("group::Grouped", "value"),
+            getter: "&[Avp]".to_string(),
+            conversion: "as_grouped()",
+        },
+        (AvpType::Custom(_), _) => Accessor {
+            generics: format!("<T: {}::modeling::avp::custom::AvpCodec>", CRATE),
+            argument: "T".to_string(),
+            value: format!("{}::modeling::avp::custom::Custom::from_codec(value)", CRATE),
+            getter: "&T".to_string(),
+            conversion: "as_custom()",
+        },
+        (AvpType::Unknown, _) => return,
+    };
+    let flags = if definition.m_flag { "M" } else { "O" };
+    let vendor_id = match definition.vendor_id {
+        Some(vendor_id) => format!("Some({})", vendor_id),
+        None => "None".to_string(),
+    };
+
+    writeln!(out, "\n    /// Builds a {} AVP.", definition.name).unwrap();
+    writeln!(
+        out,
+        "    pub fn {}{}(value: {}) -> Avp {{\n        \
+         Avp::new(super::code::{}, AvpFlags::{}, {}, {})\n    }}",
+        avp.function, accessor.generics, accessor.argument, avp.constant, flags, vendor_id, accessor.value
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "\n    /// Returns the first {} AVP in `avps`.",
+        definition.name
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "    pub fn get_{}{}(avps: &[Avp]) -> Option<{}> {{\n        \
+         find(avps, super::code::{}, {}).and_then(|avp| avp.value().{})\n    }}",
+        avp.function, accessor.generics, accessor.getter, avp.constant, vendor_id, accessor.conversion
+    )
+    .unwrap();
+}
+
+fn data(path: &str, argument: &str) -> String {
+    format!("{}::modeling::avp::{}::new({})", CRATE, path, argument)
+}
+
+fn scalar(rust_type: &str, path: &str, conversion: &'static str) -> Accessor {
+    Accessor {
+        generics: String::new(),
+        argument: rust_type.to_string(),
+        value: data(path, "value"),
+        getter: rust_type.to_string(),
+        conversion,
+    }
+}
+
+fn words(name: &str) -> Vec<String> {
+    name.split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| word.to_string())
+        .collect()
+}
+
+fn snake_case(name: &str) -> String {
+    identifier(words(name).join("_").to_lowercase(), "_")
+}
+
+fn screaming_snake_case(name: &str) -> String {
+    identifier(words(name).join("_").to_uppercase(), "_")
+}
+
+fn upper_camel_case(name: &str) -> String {
+    let camel: String = words(name)
+        .iter()
+        .map(|word| {
+            let lower = word.to_lowercase();
+            let mut chars = lower.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect();
+    identifier(camel, "Value")
+}
+
+/// Strict, reserved and edition-dependent keywords of Rust 2024. Raw identifiers cannot be used
+/// for `crate`, `self`, `Self` and `super`, so every keyword gets a `_` suffix instead.
+const KEYWORDS: [&str; 52] = [
+    "Self", "abstract", "as", "async", "await", "become", "box", "break", "const", "continue",
+    "crate", "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if",
+    "impl", "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv",
+    "pub", "ref", "return", "self", "static", "struct", "super", "trait", "true", "try", "type",
+    "typeof", "union", "unsafe", "unsized", "use", "virtual", "where", "while",
+];
+
+fn identifier(name: String, digit_prefix: &str) -> String {
+    match name.chars().next() {
+        None => format!("{}unnamed", digit_prefix),
+        Some(first) if first.is_ascii_digit() => format!("{}{}", digit_prefix, name),
+        Some(_) if KEYWORDS.contains(&name.as_str()) => format!("{}_", name),
+        Some(_) => name,
+    }
+}
+
+/// Disambiguates constructor names, which also clash with the `get_` getter of another AVP,
+/// e.g. the getter of `Foo` and the constructor of `Get-Foo`.
+fn unique_function(name: String, suffix: u32, used: &mut HashSet<String>) -> String {
+    let clashes = |name: &str| used.contains(name) || used.contains(&format!("get_{}", name));
+    let name = match clashes(&name) || name.strip_prefix("get_").is_some_and(|f| used.contains(f)) {
+        true => format!("{}_{}", name, suffix),
+        false => name,
+    };
+    used.insert(format!("get_{}", name));
+    used.insert(name.clone());
+    name
+}
+
+/// Disambiguates clashing names, e.g. the same AVP name defined by two vendors.
+fn unique(name: String, suffix: u32, used: &mut HashSet<String>) -> String {
+    let name = match used.contains(&name) {
+        true => format!("{}_{}", name, suffix),
+        false => name,
+    };
+    used.insert(name.clone());
+    name
+}
@@ -1,12 +1,16 @@
-use std::fmt;
-use std::fmt::{Debug, Display, Formatter};
+use core::fmt;
+use core::fmt::{Debug, Display, Formatter};
 
 #[derive(Debug)]
 pub enum Error {
     ClientError(&'static str),
+    #[cfg(feature = "std")]
     IoError(std::io::Error),
     EncodeError(&'static str),
     DecodeError(&'static str),
+    DictionaryError(&'static str),
+    MissingAvp(u32, Option<u32>), // code, vendor id
+    TooManyAvps(u32, Option<u32>), // code, vendor id
 }
 
 pub type DiameterResult<T> = Result<T, Error>;
@@ -15,15 +19,26 @@ impl Display for Error {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         match self {
             Error::ClientError(msg) => write!(f, "{}", msg),
+            #[cfg(feature = "std")]
             Error::IoError(e) => write!(f, "{}", e),
             Error::EncodeError(msg) => write!(f, "{}", msg),
             Error::DecodeError(msg) => write!(f, "{}", msg),
+            Error::DictionaryError(msg) => write!(f, "{}", msg),
+            Error::MissingAvp(code, None) => write!(f, "Missing required AVP {}", code),
+            Error::MissingAvp(code, Some(vendor_id)) => {
+                write!(f, "Missing required AVP {} (vendor {})", code, vendor_id)
+            }
+            Error::TooManyAvps(code, None) => write!(f, "AVP {} occurs too many times", code),
+            Error::TooManyAvps(code, Some(vendor_id)) => {
+                write!(f, "AVP {} (vendor {}) occurs too many times", code, vendor_id)
+            }
         }
     }
 }
 
-impl std::error::Error for Error {}
+impl core::error::Error for Error {}
 
+#[cfg(feature = "std")]
 impl From<std::io::Error> for Error {
     fn from(err: std::io::Error) -> Self {
         Error::IoError(err)
@@ -1,3 +1,11 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+extern crate self as diameter_interface;
+
+#[cfg(feature = "std")]
+pub mod codegen;
 pub mod errors;
 pub mod modeling;
+#[cfg(feature = "std")]
 pub mod transport;
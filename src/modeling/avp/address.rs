@@ -0,0 +1,63 @@
+use crate::errors::DiameterResult;
+use crate::errors::Error::DecodeError;
+use crate::modeling::avp::AvpData;
+use crate::modeling::avp::avp::AvpValue;
+use crate::modeling::codec::{Reader, Writer};
+use alloc::vec;
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+/// The RFC 6733 Address type: a 2-octet IANA address family followed by the address.
+/// Only IPv4 and IPv6 addresses are supported.
+pub type Address = AvpData<IpAddr>;
+
+const FAMILY_IPV4: u16 = 1;
+const FAMILY_IPV6: u16 = 2;
+
+impl Address {
+    pub(super) fn encode_to<W: Writer>(&self, writer: &mut W) -> DiameterResult<()> {
+        match self.0 {
+            IpAddr::V4(ip) => {
+                writer.write_all(&FAMILY_IPV4.to_be_bytes())?;
+                writer.write_all(&ip.octets())?;
+            }
+            IpAddr::V6(ip) => {
+                writer.write_all(&FAMILY_IPV6.to_be_bytes())?;
+                writer.write_all(&ip.octets())?;
+            }
+        }
+        Ok(())
+    }
+
+    pub(super) fn decode_from<R: Reader>(
+        reader: &mut R,
+        length: usize,
+    ) -> DiameterResult<AvpData<IpAddr>> {
+        let mut buffer = vec![0u8; length];
+        reader.read_exact(&mut buffer)?;
+        let ip = match (buffer.first_chunk::<2>(), length) {
+            (Some(family), 6) if u16::from_be_bytes(*family) == FAMILY_IPV4 => {
+                let octets: [u8; 4] = buffer[2..].try_into().unwrap();
+                IpAddr::V4(Ipv4Addr::from(octets))
+            }
+            (Some(family), 18) if u16::from_be_bytes(*family) == FAMILY_IPV6 => {
+                let octets: [u8; 16] = buffer[2..].try_into().unwrap();
+                IpAddr::V6(Ipv6Addr::from(octets))
+            }
+            _ => Err(DecodeError("Unsupported Address family or length"))?,
+        };
+        Ok(Address::new(ip))
+    }
+
+    pub(super) fn len(&self) -> u32 {
+        match self.0 {
+            IpAddr::V4(_) => 6,
+            IpAddr::V6(_) => 18,
+        }
+    }
+}
+
+impl From<Address> for AvpValue {
+    fn from(value: Address) -> Self {
+        AvpValue::Address(value)
+    }
+}
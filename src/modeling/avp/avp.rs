@@ -29,6 +29,9 @@
 
 use crate::errors::DiameterResult;
 use crate::errors::Error::DecodeError;
+use crate::modeling::avp::address::Address;
+use crate::modeling::avp::custom::{AvpCodec, Custom};
+use crate::modeling::avp::diameter_uri::{DiameterURI, Uri};
 use crate::modeling::avp::enumerated::Enumerated;
 use crate::modeling::avp::float32::Float32;
 use crate::modeling::avp::float64::Float64;
@@ -37,24 +40,28 @@ use crate::modeling::avp::integer32::Integer32;
 use crate::modeling::avp::integer64::Integer64;
 use crate::modeling::avp::ipv4::IPv4;
 use crate::modeling::avp::ipv6::IPv6;
-use crate::modeling::avp::octet_string::DiameterURI;
 use crate::modeling::avp::octet_string::OctetString;
 use crate::modeling::avp::time::Time;
 use crate::modeling::avp::unsigned32::Unsigned32;
 use crate::modeling::avp::unsigned64::Unsigned64;
 use crate::modeling::avp::utf8_string::{Identity, UTF8String};
 use crate::modeling::message::dictionary::Dictionary;
-use std::fmt::Debug;
-use std::io::{Read, Write};
-use std::sync::Arc;
+use alloc::string::String;
+use alloc::vec;
+use chrono::{DateTime, Utc};
+use core::fmt::Debug;
+use crate::modeling::codec::{Reader, Writer};
+use crate::modeling::decode_policy::{DecodeContext, DecodePolicy, Deviation};
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use alloc::sync::Arc;
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Avp {
     header: AvpHeader,
     pub(super) value: AvpValue,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct AvpHeader {
     code: u32,
     flags: u8,
@@ -62,14 +69,15 @@ pub struct AvpHeader {
     vendor_id: Option<u32>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum AvpFlags {
     M, // Mandatory
     O, // Optional
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum AvpType {
+    Address,
     AddressIPv4,
     AddressIPv6,
     Identity,
@@ -85,11 +93,13 @@ pub enum AvpType {
     Unsigned32,
     Unsigned64,
     UTF8String,
+    Custom(String), // type name registered with `Dictionary::register_codec`
     Unknown,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum AvpValue {
+    Address(Address),
     AddressIPv4(IPv4),
     AddressIPv6(IPv6),
     Identity(Identity),
@@ -105,6 +115,7 @@ pub enum AvpValue {
     Unsigned32(Unsigned32),
     Unsigned64(Unsigned64),
     UTF8String(UTF8String),
+    Custom(Custom),
 }
 
 impl AvpFlags {
@@ -113,7 +124,7 @@ impl AvpFlags {
     fn value(&self) -> u8 {
         match self {
             Self::M => 0b01000000,
-            Self::O => 0b01000000,
+            Self::O => 0,
         }
     }
 
@@ -127,9 +138,9 @@ impl AvpFlags {
 }
 
 impl AvpHeader {
-    fn encode_to<W: Write>(&self, avp_length: u32, writer: &mut W) -> DiameterResult<()> {
+    fn encode_to<W: Writer>(&self, avp_length: u32, writer: &mut W) -> DiameterResult<()> {
         writer.write_all(&self.code.to_be_bytes())?;
-        writer.write(&[self.flags])?;
+        writer.write_all(&[self.flags])?;
         writer.write_all(&avp_length.to_be_bytes()[1..])?;
         match self.vendor_id {
             Some(vendor_id) => {
@@ -140,7 +151,7 @@ impl AvpHeader {
         }
     }
 
-    pub fn decode_from<R: Read>(reader: &mut R) -> DiameterResult<Self> {
+    pub fn decode_from<R: Reader>(reader: &mut R) -> DiameterResult<Self> {
         let mut b = [0u8; 8];
         reader.read_exact(&mut b)?;
         let command_code = u32::from_be_bytes([b[0], b[1], b[2], b[3]]);
@@ -186,72 +197,263 @@ impl Avp {
         }
     }
 
-    pub fn encode_to<W: Write>(&self, writer: &mut W) -> DiameterResult<()> {
+    pub fn encode_to<W: Writer>(&self, writer: &mut W) -> DiameterResult<()> {
         self.header.encode_to(self.get_length(), writer)?;
         self.value.encode(writer)?;
         self.add_padding(writer)?;
         Ok(())
     }
 
-    pub fn decode_from<R: Read>(reader: &mut R, dict: Arc<Dictionary>) -> DiameterResult<Self> {
-        let header = AvpHeader::decode_from(reader)?;
+    pub fn decode_from<R: Reader>(reader: &mut R, dict: Arc<Dictionary>) -> DiameterResult<Self> {
+        let mut context = DecodeContext::new(DecodePolicy::strict());
+        Self::decode_with(reader, &dict, usize::MAX, &mut context)
+    }
 
-        let avp_type = dict
-            .get_avp_type(header.code, header.vendor_id)
-            .unwrap_or(&AvpType::Unknown);
+    /// Decodes an AVP of at most `available` octets, padding included, applying the policy of
+    /// `context`.
+    pub(crate) fn decode_with<R: Reader>(
+        reader: &mut R,
+        dict: &Arc<Dictionary>,
+        available: usize,
+        context: &mut DecodeContext,
+    ) -> DiameterResult<Self> {
+        if available < 8 {
+            Err(DecodeError("AVP header exceeds the enclosing length"))?
+        }
+        let header = AvpHeader::decode_from(reader)?;
+        let (code, vendor_id) = (header.code, header.vendor_id);
+        let mandatory = header.flags & AvpFlags::M.value() != 0;
 
         let value_length = match header.vendor_id {
-            Some(_) => (header.length - 12) as usize,
-            None => (header.length - 8) as usize,
+            Some(_) => (header.length as usize).checked_sub(12),
+            None => (header.length as usize).checked_sub(8),
+        }
+        .ok_or(DecodeError("Invalid AVP length"))?;
+        if header.length as usize > available {
+            Err(DecodeError("AVP length exceeds the enclosing length"))?
+        }
+
+        let avp_type = match dict.get_avp(code, vendor_id) {
+            Some(definition) => {
+                if definition.m_flag && !mandatory {
+                    context.deviate(code, vendor_id, Deviation::MandatoryBitClear)?;
+                }
+                &definition.avp_type
+            }
+            None => {
+                if mandatory {
+                    context.deviate(code, vendor_id, Deviation::UnknownMandatoryAvp)?;
+                }
+                &AvpType::Unknown
+            }
         };
 
         let value: AvpValue = match avp_type {
+            AvpType::Address => Address::decode_from(reader, value_length)?.into(),
             AvpType::AddressIPv4 => IPv4::decode_from(reader)?.into(),
             AvpType::AddressIPv6 => IPv6::decode_from(reader)?.into(),
-            AvpType::Identity => Identity::decode_from(reader, value_length)?.into(),
+            AvpType::Identity | AvpType::UTF8String => UTF8String::decode_from(
+                reader,
+                value_length,
+                |deviation| context.deviate(code, vendor_id, deviation),
+            )?,
             AvpType::DiameterURI => DiameterURI::decode_from(reader, value_length)?.into(),
             AvpType::Enumerated => Enumerated::decode_from(reader)?.into(),
             AvpType::Float32 => Float32::decode_from(reader)?.into(),
             AvpType::Float64 => Float64::decode_from(reader)?.into(),
             AvpType::Grouped => {
-                Grouped::decode_from(reader, value_length, Arc::clone(&dict))?.into()
+                Grouped::decode_from(reader, value_length, dict, context)?.into()
             }
             AvpType::Integer32 => Integer32::decode_from(reader)?.into(),
             AvpType::Integer64 => Integer64::decode_from(reader)?.into(),
             AvpType::OctetString => OctetString::decode_from(reader, value_length)?.into(),
-            AvpType::Time => Time::decode_from(reader)?.into(),
+            AvpType::Time => Time::decode_from(reader, value_length)?.into(),
             AvpType::Unsigned32 => Unsigned32::decode_from(reader)?.into(),
             AvpType::Unsigned64 => Unsigned64::decode_from(reader)?.into(),
-            AvpType::UTF8String => UTF8String::decode_from(reader, value_length)?.into(),
-            AvpType::Unknown => Err(DecodeError("Unknown AVP type to be decoded"))?,
+            AvpType::Custom(type_name) => match dict.get_codec(type_name) {
+                Some(decoder) => Custom::decode_from(reader, value_length, decoder)?.into(),
+                None => OctetString::decode_from(reader, value_length)?.into(),
+            },
+            // Kept as octets, to be encoded again unchanged
+            AvpType::Unknown => OctetString::decode_from(reader, value_length)?.into(),
         };
         let avp = Self { header, value };
-        let mut vec = vec![0u8; avp.get_padding() as usize];
-        reader.read_exact(&mut vec)?;
+        let padding = avp.get_padding() as usize;
+        match available - avp.get_length() as usize {
+            0 if padding > 0 => context.deviate(code, vendor_id, Deviation::MissingPadding)?,
+            remaining if remaining < padding => {
+                Err(DecodeError("AVP padding exceeds the enclosing length"))?
+            }
+            _ => {
+                let mut vec = vec![0u8; padding];
+                reader.read_exact(&mut vec)?;
+            }
+        }
         Ok(avp)
     }
 
+    /// Replaces the flags, keeping the V bit consistent with the vendor id.
+    #[cfg(feature = "serde")]
+    pub(crate) fn with_raw_flags(mut self, flags: u8) -> Self {
+        let vendor_bit = match self.header.vendor_id {
+            Some(_) => AvpFlags::VENDOR_FLAG_BIT,
+            None => 0,
+        };
+        self.header.flags = (flags & !AvpFlags::VENDOR_FLAG_BIT) | vendor_bit;
+        self
+    }
+
+    pub fn code(&self) -> u32 {
+        self.header.code
+    }
+
+    pub fn vendor_id(&self) -> Option<u32> {
+        self.header.vendor_id
+    }
+
+    pub fn flags(&self) -> u8 {
+        self.header.flags
+    }
+
+    pub fn is_mandatory(&self) -> bool {
+        self.header.flags & AvpFlags::M.value() != 0
+    }
+
+    pub fn value(&self) -> &AvpValue {
+        &self.value
+    }
+
     pub fn get_length(&self) -> u32 {
         self.header.length
     }
 
     pub fn get_padding(&self) -> u32 {
-        let remainder = (self.header.length + self.value.len()) % 4;
-        if remainder != 0 { 4 - remainder + 1 } else { 0 }
+        (4 - self.header.length % 4) % 4
     }
 
-    fn add_padding<W: Write>(&self, writer: &mut W) -> DiameterResult<()> {
-        for _ in 0..self.get_padding() {
-            writer.write(&[0])?;
-        }
+    fn add_padding<W: Writer>(&self, writer: &mut W) -> DiameterResult<()> {
+        writer.write_all(&[0u8; 3][..self.get_padding() as usize])?;
         Ok(())
     }
 }
 
+impl AvpValue {
+    /// Returns UTF8String and DiameterIdentity values.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            AvpValue::UTF8String(value) | AvpValue::Identity(value) => Some(value.value()),
+            _ => None,
+        }
+    }
+
+    pub fn as_bytes(&self) -> Option<&[u8]> {
+        match self {
+            AvpValue::OctetString(value) => Some(value.value()),
+            _ => None,
+        }
+    }
+
+    /// Returns Integer32 and Enumerated values.
+    pub fn as_i32(&self) -> Option<i32> {
+        match self {
+            AvpValue::Integer32(value) | AvpValue::Enumerated(value) => Some(*value.value()),
+            _ => None,
+        }
+    }
+
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            AvpValue::Integer64(value) => Some(*value.value()),
+            _ => None,
+        }
+    }
+
+    pub fn as_u32(&self) -> Option<u32> {
+        match self {
+            AvpValue::Unsigned32(value) => Some(*value.value()),
+            _ => None,
+        }
+    }
+
+    pub fn as_u64(&self) -> Option<u64> {
+        match self {
+            AvpValue::Unsigned64(value) => Some(*value.value()),
+            _ => None,
+        }
+    }
+
+    pub fn as_f32(&self) -> Option<f32> {
+        match self {
+            AvpValue::Float32(value) => Some(*value.value()),
+            _ => None,
+        }
+    }
+
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            AvpValue::Float64(value) => Some(*value.value()),
+            _ => None,
+        }
+    }
+
+    pub fn as_uri(&self) -> Option<&Uri> {
+        match self {
+            AvpValue::DiameterURI(value) => Some(value.value()),
+            _ => None,
+        }
+    }
+
+    pub fn as_time(&self) -> Option<&DateTime<Utc>> {
+        match self {
+            AvpValue::Time(value) => Some(value.value()),
+            _ => None,
+        }
+    }
+
+    /// Returns Address, IPv4 and IPv6 values.
+    pub fn as_ip_addr(&self) -> Option<IpAddr> {
+        match self {
+            AvpValue::Address(value) => Some(*value.value()),
+            AvpValue::AddressIPv4(value) => Some(IpAddr::V4(*value.value())),
+            AvpValue::AddressIPv6(value) => Some(IpAddr::V6(*value.value())),
+            _ => None,
+        }
+    }
+
+    pub fn as_ipv4(&self) -> Option<Ipv4Addr> {
+        match self {
+            AvpValue::AddressIPv4(value) => Some(*value.value()),
+            _ => None,
+        }
+    }
+
+    pub fn as_ipv6(&self) -> Option<Ipv6Addr> {
+        match self {
+            AvpValue::AddressIPv6(value) => Some(*value.value()),
+            _ => None,
+        }
+    }
+
+    pub fn as_grouped(&self) -> Option<&[Avp]> {
+        match self {
+            AvpValue::Grouped(value) => Some(value.avps()),
+            _ => None,
+        }
+    }
+
+    /// Returns the value decoded by the codec `T`.
+    pub fn as_custom<T: AvpCodec>(&self) -> Option<&T> {
+        match self {
+            AvpValue::Custom(value) => value.downcast_ref(),
+            _ => None,
+        }
+    }
+}
+
 macro_rules! impl_encode_avp_value_for_enum_variants {
     ($enum_name:ident { $($variant:ident($inner_ty:ty)),* }) => {
         impl $enum_name {
-            fn encode<W: Write>(
+            pub(crate) fn encode<W: Writer>(
                 &self,
                 writer: &mut W
             ) -> DiameterResult<()> {
@@ -279,6 +481,7 @@ macro_rules! impl_encode_avp_value_for_enum_variants {
 }
 
 impl_encode_avp_value_for_enum_variants!(AvpValue {
+    Address(Address),
     AddressIPv4(IPv4),
     AddressIPv6(IPv6),
     Identity(Identity),
@@ -293,5 +496,6 @@ impl_encode_avp_value_for_enum_variants!(AvpValue {
     Time(Time),
     Unsigned32(Unsigned32),
     Unsigned64(Unsigned64),
-    UTF8String(UTF8String)
+    UTF8String(UTF8String),
+    Custom(Custom)
 });
@@ -0,0 +1,172 @@
+//! # Custom AVP Data Types
+//!
+//! Data types outside RFC 6733, e.g. TBCD-encoded strings, are implemented with [`AvpCodec`]
+//! and registered in the `Dictionary` under the type name used by the AVP definitions:
+//! ```
+//! # use diameter_interface::errors::DiameterResult;
+//! # use diameter_interface::modeling::avp::avp::{AvpFlags, AvpType};
+//! # use diameter_interface::modeling::avp::custom::{AvpCodec, Custom};
+//! # use diameter_interface::modeling::codec::Writer;
+//! # use diameter_interface::modeling::diameter::DiameterMessage;
+//! # use diameter_interface::modeling::message::application_id::ApplicationId;
+//! # use diameter_interface::modeling::message::command_code::CommandCode;
+//! # use diameter_interface::modeling::message::command_flags::CommandFlag;
+//! # use diameter_interface::modeling::message::dictionary::{AvpDefinition, Dictionary};
+//! # use std::sync::Arc;
+//! /// Digits packed two per octet, the first in the low nibble, padded with 0xF.
+//! #[derive(Debug, Clone)]
+//! struct Tbcd(String);
+//!
+//! impl AvpCodec for Tbcd {
+//!     fn encode_to(&self, writer: &mut dyn Writer) -> DiameterResult<()> {
+//!         let digits: Vec<u8> = self.0.bytes().map(|digit| digit - b'0').collect();
+//!         for pair in digits.chunks(2) {
+//!             writer.write_all(&[pair[0] | pair.get(1).unwrap_or(&0xf) << 4])?;
+//!         }
+//!         Ok(())
+//!     }
+//!
+//!     fn encoded_len(&self) -> u32 {
+//!         self.0.len().div_ceil(2) as u32
+//!     }
+//!
+//!     fn decode(data: &[u8]) -> DiameterResult<Self> {
+//!         let nibbles = data.iter().flat_map(|octet| [octet & 0xf, octet >> 4]);
+//!         Ok(Tbcd(nibbles.filter(|n| *n < 10).map(|n| (b'0' + n) as char).collect()))
+//!     }
+//! }
+//!
+//! let mut dict = Dictionary::empty();
+//! dict.register_codec::<Tbcd>("TBCDString");
+//! dict.add_avp(AvpDefinition {
+//!     code: 701,
+//!     vendor_id: Some(10415),
+//!     name: "MSISDN".to_string(),
+//!     avp_type: AvpType::Custom("TBCDString".to_string()),
+//!     m_flag: true,
+//!     enum_values: Default::default(),
+//! });
+//!
+//! let mut message = DiameterMessage::new(
+//!     CommandFlag::Request,
+//!     CommandCode::CreditControl,
+//!     ApplicationId::CreditControl,
+//!     1,
+//!     1,
+//! );
+//! message.add_avp(701, AvpFlags::M, Some(10415), Custom::from_codec(Tbcd("46701234567".into())));
+//!
+//! let mut encoded = vec![];
+//! message.encode_to(&mut encoded)?;
+//! let decoded = DiameterMessage::decode_from(&mut encoded.as_slice(), Arc::new(dict))?;
+//! let msisdn: Option<&Tbcd> = decoded.avps()[0].value().as_custom();
+//! assert_eq!(msisdn.unwrap().0, "46701234567");
+//! # Ok::<(), diameter_interface::errors::Error>(())
+//! ```
+//! AVPs whose dictionary type has no registered codec are decoded as OctetStrings.
+
+use crate::errors::DiameterResult;
+use crate::modeling::avp::AvpData;
+use crate::modeling::avp::avp::AvpValue;
+use alloc::boxed::Box;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::any::Any;
+use core::fmt::Debug;
+use crate::modeling::codec::{Reader, Writer};
+
+pub type Custom = AvpData<Box<dyn CustomValue>>;
+
+pub(crate) type CustomDecoder = fn(&[u8]) -> DiameterResult<Custom>;
+
+/// A user-defined AVP data type.
+pub trait AvpCodec: Debug + Clone + Send + Sync + 'static {
+    fn encode_to(&self, writer: &mut dyn Writer) -> DiameterResult<()>;
+
+    /// Length of the encoded data, without padding.
+    fn encoded_len(&self) -> u32;
+
+    /// Decodes the AVP data, `data` excludes the AVP header and padding.
+    fn decode(data: &[u8]) -> DiameterResult<Self>;
+}
+
+/// Object-safe view of an [`AvpCodec`] value, implemented for every codec.
+pub trait CustomValue: Debug + Send + Sync {
+    fn encode_to(&self, writer: &mut dyn Writer) -> DiameterResult<()>;
+
+    fn encoded_len(&self) -> u32;
+
+    fn clone_box(&self) -> Box<dyn CustomValue>;
+
+    fn as_any(&self) -> &dyn Any;
+}
+
+impl<T: AvpCodec> CustomValue for T {
+    fn encode_to(&self, writer: &mut dyn Writer) -> DiameterResult<()> {
+        AvpCodec::encode_to(self, writer)
+    }
+
+    fn encoded_len(&self) -> u32 {
+        AvpCodec::encoded_len(self)
+    }
+
+    fn clone_box(&self) -> Box<dyn CustomValue> {
+        Box::new(self.clone())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl Clone for Box<dyn CustomValue> {
+    fn clone(&self) -> Self {
+        self.clone_box()
+    }
+}
+
+/// Values are equal when they encode to the same octets.
+impl PartialEq for Box<dyn CustomValue> {
+    fn eq(&self, other: &Self) -> bool {
+        let (mut left, mut right) = (Vec::new(), Vec::new());
+        self.encode_to(&mut left).is_ok() && other.encode_to(&mut right).is_ok() && left == right
+    }
+}
+
+impl Custom {
+    pub fn from_codec<T: AvpCodec>(value: T) -> Self {
+        AvpData(Box::new(value))
+    }
+
+    pub fn downcast_ref<T: AvpCodec>(&self) -> Option<&T> {
+        self.0.as_any().downcast_ref()
+    }
+
+    pub(super) fn encode_to<W: Writer>(&self, writer: &mut W) -> DiameterResult<()> {
+        self.0.encode_to(writer)
+    }
+
+    pub(super) fn decode_from<R: Reader>(
+        reader: &mut R,
+        length: usize,
+        decoder: CustomDecoder,
+    ) -> DiameterResult<Custom> {
+        let mut buffer = vec![0u8; length];
+        reader.read_exact(&mut buffer)?;
+        decoder(&buffer)
+    }
+
+    pub(super) fn len(&self) -> u32 {
+        self.0.encoded_len()
+    }
+}
+
+impl From<Custom> for AvpValue {
+    fn from(value: Custom) -> Self {
+        AvpValue::Custom(value)
+    }
+}
+
+pub(crate) fn decoder<T: AvpCodec>() -> CustomDecoder {
+    |data| Ok(Custom::from_codec(T::decode(data)?))
+}
@@ -0,0 +1,304 @@
+//! # DiameterURI AVP Data Format
+//!
+//! Implements the `DiameterURI` derived data format defined in RFC 6733 section 4.3.1:
+//! ```text
+//!   "aaa://" FQDN [ port ] [ transport ] [ protocol ]
+//!   "aaas://" FQDN [ port ] [ transport ] [ protocol ]
+//!
+//!   port      = ":" 1*DIGIT
+//!   transport = ";transport=" ( "tcp" / "sctp" / "udp" )
+//!   protocol  = ";protocol=" ( "diameter" / "radius" / "tacacs+" )
+//! ```
+
+use crate::errors::Error::DecodeError;
+use crate::errors::{DiameterResult, Error};
+use crate::modeling::avp::AvpData;
+use crate::modeling::avp::avp::AvpValue;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use core::fmt;
+use core::fmt::{Display, Formatter};
+use crate::modeling::codec::{Reader, Writer};
+use core::str::FromStr;
+
+pub type DiameterURI = AvpData<Uri>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Uri {
+    scheme: UriScheme,
+    fqdn: String,
+    port: Option<u16>,
+    transport: Option<Transport>,
+    protocol: Option<Protocol>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UriScheme {
+    Aaa,  // aaa://
+    Aaas, // aaas:// (secured transport)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Transport {
+    Tcp,
+    Sctp,
+    Udp,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Protocol {
+    Diameter,
+    Radius,
+    TacacsPlus,
+}
+
+impl UriScheme {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UriScheme::Aaa => "aaa",
+            UriScheme::Aaas => "aaas",
+        }
+    }
+
+    pub fn default_port(&self) -> u16 {
+        match self {
+            UriScheme::Aaa => 3868,
+            UriScheme::Aaas => 5658,
+        }
+    }
+}
+
+impl Transport {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Transport::Tcp => "tcp",
+            Transport::Sctp => "sctp",
+            Transport::Udp => "udp",
+        }
+    }
+}
+
+impl FromStr for Transport {
+    type Err = Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_ascii_lowercase().as_str() {
+            "tcp" => Ok(Transport::Tcp),
+            "sctp" => Ok(Transport::Sctp),
+            "udp" => Ok(Transport::Udp),
+            _ => Err(DecodeError("Invalid DiameterURI transport")),
+        }
+    }
+}
+
+impl Protocol {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Protocol::Diameter => "diameter",
+            Protocol::Radius => "radius",
+            Protocol::TacacsPlus => "tacacs+",
+        }
+    }
+}
+
+impl FromStr for Protocol {
+    type Err = Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_ascii_lowercase().as_str() {
+            "diameter" => Ok(Protocol::Diameter),
+            "radius" => Ok(Protocol::Radius),
+            "tacacs+" => Ok(Protocol::TacacsPlus),
+            _ => Err(DecodeError("Invalid DiameterURI protocol")),
+        }
+    }
+}
+
+impl Uri {
+    pub fn new(scheme: UriScheme, fqdn: &str) -> Self {
+        Self {
+            scheme,
+            fqdn: fqdn.to_string(),
+            port: None,
+            transport: None,
+            protocol: None,
+        }
+    }
+
+    pub fn with_port(mut self, port: u16) -> Self {
+        self.port = Some(port);
+        self
+    }
+
+    pub fn with_transport(mut self, transport: Transport) -> Self {
+        self.transport = Some(transport);
+        self
+    }
+
+    pub fn with_protocol(mut self, protocol: Protocol) -> Self {
+        self.protocol = Some(protocol);
+        self
+    }
+
+    pub fn scheme(&self) -> UriScheme {
+        self.scheme
+    }
+
+    pub fn fqdn(&self) -> &str {
+        &self.fqdn
+    }
+
+    /// Returns the port, falling back to 3868 for `aaa` and 5658 for `aaas`.
+    pub fn port(&self) -> u16 {
+        self.port.unwrap_or(self.scheme.default_port())
+    }
+
+    /// Returns the transport, falling back to TCP when absent.
+    pub fn transport(&self) -> Transport {
+        self.transport.unwrap_or(Transport::Tcp)
+    }
+
+    /// Returns the protocol, falling back to Diameter when absent.
+    pub fn protocol(&self) -> Protocol {
+        self.protocol.unwrap_or(Protocol::Diameter)
+    }
+
+    pub fn is_secure(&self) -> bool {
+        self.scheme == UriScheme::Aaas
+    }
+}
+
+impl FromStr for Uri {
+    type Err = Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (scheme, rest) = value
+            .split_once("://")
+            .ok_or(DecodeError("DiameterURI is missing the scheme"))?;
+        let scheme = match scheme.to_ascii_lowercase().as_str() {
+            "aaa" => UriScheme::Aaa,
+            "aaas" => UriScheme::Aaas,
+            _ => Err(DecodeError("Invalid DiameterURI scheme"))?,
+        };
+
+        let mut parts = rest.split(';');
+        let authority = parts.next().unwrap_or_default();
+        let (fqdn, port) = split_authority(authority)?;
+        validate_fqdn(fqdn)?;
+
+        let mut uri = Uri::new(scheme, fqdn);
+        uri.port = port;
+        for param in parts {
+            let (key, value) = param
+                .split_once('=')
+                .ok_or(DecodeError("Invalid DiameterURI parameter"))?;
+            match key.to_ascii_lowercase().as_str() {
+                "transport" if uri.transport.is_none() => uri.transport = Some(value.parse()?),
+                "protocol" if uri.protocol.is_none() => uri.protocol = Some(value.parse()?),
+                _ => Err(DecodeError("Invalid DiameterURI parameter"))?,
+            }
+        }
+        Ok(uri)
+    }
+}
+
+fn split_authority(authority: &str) -> DiameterResult<(&str, Option<u16>)> {
+    // Bracketed IPv6 literals contain colons, so the port separator follows the closing bracket.
+    let (host, port) = match authority.strip_prefix('[') {
+        Some(literal) => {
+            let end = literal
+                .find(']')
+                .ok_or(DecodeError("Invalid DiameterURI host"))?;
+            let port = &literal[end + 1..];
+            match port.is_empty() {
+                true => (&authority[..end + 2], None),
+                false => (
+                    &authority[..end + 2],
+                    Some(
+                        port.strip_prefix(':')
+                            .ok_or(DecodeError("Invalid DiameterURI port"))?,
+                    ),
+                ),
+            }
+        }
+        None => match authority.split_once(':') {
+            Some((host, port)) => (host, Some(port)),
+            None => (authority, None),
+        },
+    };
+    let port = match port {
+        Some(port) => Some(
+            port.parse::<u16>()
+                .map_err(|_| DecodeError("Invalid DiameterURI port"))?,
+        ),
+        None => None,
+    };
+    Ok((host, port))
+}
+
+fn validate_fqdn(fqdn: &str) -> DiameterResult<()> {
+    let valid = match fqdn.strip_prefix('[').and_then(|h| h.strip_suffix(']')) {
+        Some(literal) => literal.parse::<core::net::Ipv6Addr>().is_ok(),
+        None => {
+            !fqdn.is_empty()
+                && fqdn.split('.').all(|label| {
+                    !label.is_empty()
+                        && label
+                            .chars()
+                            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+                })
+        }
+    };
+    match valid {
+        true => Ok(()),
+        false => Err(DecodeError("Invalid DiameterURI host")),
+    }
+}
+
+impl Display for Uri {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}://{}", self.scheme.as_str(), self.fqdn)?;
+        if let Some(port) = self.port {
+            write!(f, ":{}", port)?;
+        }
+        if let Some(transport) = self.transport {
+            write!(f, ";transport={}", transport.as_str())?;
+        }
+        if let Some(protocol) = self.protocol {
+            write!(f, ";protocol={}", protocol.as_str())?;
+        }
+        Ok(())
+    }
+}
+
+impl DiameterURI {
+    pub fn parse(value: &str) -> DiameterResult<Self> {
+        Ok(Self(value.parse()?))
+    }
+
+    pub(super) fn encode_to<W: Writer>(&self, writer: &mut W) -> DiameterResult<()> {
+        writer.write_all(self.0.to_string().as_bytes())?;
+        Ok(())
+    }
+
+    pub(super) fn decode_from<R: Reader>(
+        reader: &mut R,
+        length: usize,
+    ) -> DiameterResult<AvpData<Uri>> {
+        let mut buffer = vec![0u8; length];
+        reader.read_exact(&mut buffer)?;
+        let value =
+            String::from_utf8(buffer).map_err(|_| DecodeError("DiameterURI is not valid UTF-8"))?;
+        DiameterURI::parse(&value)
+    }
+
+    pub(super) fn len(&self) -> u32 {
+        self.0.to_string().len() as u32
+    }
+}
+
+impl From<DiameterURI> for AvpValue {
+    fn from(value: DiameterURI) -> Self {
+        AvpValue::DiameterURI(value)
+    }
+}
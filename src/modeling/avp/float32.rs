@@ -2,14 +2,14 @@ use crate::errors::DiameterResult;
 use crate::impl_avp_data_encode_to_numbers;
 use crate::modeling::avp::AvpData;
 use crate::modeling::avp::avp::AvpValue;
-use std::io::Read;
+use crate::modeling::codec::Reader;
 
 pub type Float32 = AvpData<f32>;
 
 impl Float32 {
     impl_avp_data_encode_to_numbers!(Float32, f32);
 
-    pub(super) fn decode_from<R: Read>(reader: &mut R) -> DiameterResult<AvpData<f32>> {
+    pub(super) fn decode_from<R: Reader>(reader: &mut R) -> DiameterResult<AvpData<f32>> {
         let mut buffer = [0u8; 4];
         reader.read_exact(&mut buffer)?;
         let num = f32::from_be_bytes(buffer);
@@ -1,37 +1,45 @@
 use crate::errors::DiameterResult;
 use crate::modeling::avp::avp::{Avp, AvpFlags, AvpValue};
 use crate::modeling::avp::AvpData;
+use crate::modeling::codec::{Reader, Writer};
+use crate::modeling::decode_policy::DecodeContext;
 use crate::modeling::message::dictionary::Dictionary;
-use std::io::{Read, Write};
-use std::sync::Arc;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
 
 pub type Grouped = AvpData<Vec<Avp>>;
 
 impl Grouped {
-    pub(super) fn encode_to<W: Write>(&self, writer: &mut W) -> DiameterResult<()> {
+    pub(super) fn encode_to<W: Writer>(&self, writer: &mut W) -> DiameterResult<()> {
         for avp in &self.0 {
             avp.encode_to(writer)?;
         }
         Ok(())
     }
 
-    pub(super) fn decode_from<R: Read>(
+    pub(super) fn decode_from<R: Reader>(
         reader: &mut R,
         length: usize,
-        dict: Arc<Dictionary>,
+        dict: &Arc<Dictionary>,
+        context: &mut DecodeContext,
     ) -> DiameterResult<AvpData<Vec<Avp>>> {
         let mut avps_length = length;
         let mut avps: Vec<Avp> = Vec::new();
         while avps_length > 0 {
-            let avp = Avp::decode_from(reader, Arc::clone(&dict))?;
-            avps_length -= avp.get_length() as usize;
+            let avp = Avp::decode_with(reader, dict, avps_length, context)?;
+            // Saturates when the padding of the last AVP is missing
+            avps_length =
+                avps_length.saturating_sub((avp.get_length() + avp.get_padding()) as usize);
             avps.push(avp);
         }
         Ok(AvpData(avps))
     }
 
     pub(super) fn len(&self) -> u32 {
-        self.0.iter().map(|avp| avp.get_length()).sum()
+        self.0
+            .iter()
+            .map(|avp| avp.get_length() + avp.get_padding())
+            .sum()
     }
 }
 
@@ -2,15 +2,15 @@ use crate::errors::DiameterResult;
 use crate::impl_avp_data_encode_to_address;
 use crate::modeling::avp::avp::AvpValue;
 use crate::modeling::avp::AvpData;
-use std::io::Read;
-use std::net::Ipv6Addr;
+use crate::modeling::codec::Reader;
+use core::net::Ipv6Addr;
 
 pub type IPv6 = AvpData<Ipv6Addr>;
 
 impl IPv6 {
     impl_avp_data_encode_to_address!(IPv6, Ipv6Addr);
 
-    pub(super) fn decode_from<R: Read>(reader: &mut R) -> DiameterResult<AvpData<Ipv6Addr>> {
+    pub(super) fn decode_from<R: Reader>(reader: &mut R) -> DiameterResult<AvpData<Ipv6Addr>> {
         let mut b = [0; 16];
         reader.read_exact(&mut b)?;
 
@@ -1,7 +1,7 @@
 #[macro_export]
 macro_rules! impl_avp_data_encode_to_numbers {
     ($ty:ty, $out:ty) => {
-        pub(super) fn encode_to<W: std::io::Write>(&self, writer: &mut W) -> DiameterResult<()> {
+        pub(super) fn encode_to<W: $crate::modeling::codec::Writer>(&self, writer: &mut W) -> DiameterResult<()> {
             writer.write_all(&self.0.to_be_bytes())?;
             Ok(())
         }
@@ -11,7 +11,7 @@ macro_rules! impl_avp_data_encode_to_numbers {
 #[macro_export]
 macro_rules! impl_avp_data_encode_to_address {
     ($ty:ty, $out:ty) => {
-        pub(super) fn encode_to<W: std::io::Write>(&self, writer: &mut W) -> DiameterResult<()> {
+        pub(super) fn encode_to<W: $crate::modeling::codec::Writer>(&self, writer: &mut W) -> DiameterResult<()> {
             writer.write_all(&self.0.octets())?;
             Ok(())
         }
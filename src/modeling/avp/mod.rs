@@ -1,4 +1,7 @@
+pub mod address;
 pub mod avp;
+pub mod custom;
+pub mod diameter_uri;
 pub mod enumerated;
 pub mod float32;
 pub mod float64;
@@ -16,7 +19,7 @@ pub mod utf8_string;
 #[macro_use]
 pub mod macros;
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct AvpData<T>(pub(super) T);
 
 impl<T> AvpData<T> {
@@ -1,18 +1,19 @@
 use crate::errors::DiameterResult;
 use crate::modeling::avp::avp::AvpValue;
 use crate::modeling::avp::AvpData;
-use std::io::{Read, Write};
+use crate::modeling::codec::{Reader, Writer};
+use alloc::vec;
+use alloc::vec::Vec;
 
 pub type OctetString = AvpData<Vec<u8>>;
-pub type DiameterURI = OctetString;
 
 impl OctetString {
-    pub(super) fn encode_to<W: Write>(&self, writer: &mut W) -> DiameterResult<()> {
-        writer.write(&self.0)?;
+    pub(super) fn encode_to<W: Writer>(&self, writer: &mut W) -> DiameterResult<()> {
+        writer.write_all(&self.0)?;
         Ok(())
     }
 
-    pub(super) fn decode_from<R: Read>(
+    pub(super) fn decode_from<R: Reader>(
         reader: &mut R,
         length: usize,
     ) -> DiameterResult<AvpData<Vec<u8>>> {
@@ -6,38 +6,57 @@ use crate::errors::Error::EncodeError;
 use crate::errors::{DiameterResult, Error};
 use crate::modeling::avp::avp::AvpValue;
 use crate::modeling::avp::AvpData;
-use chrono::{DateTime, TimeZone, Utc};
-use std::io::{Read, Write};
+pub use chrono::{DateTime, Utc};
 
+use chrono::TimeZone;
+use crate::modeling::codec::{Reader, Writer};
+
+/// The RFC 6733 Time type: seconds since 1900-01-01 00:00 UTC, as the first 32 bits of an NTP
+/// timestamp. The 32 bits wrap in February 2036, so following RFC 6733 §4.3.1 and RFC 5905,
+/// values with the high bit set are in era 0 (1968 to 2036) and values with the high bit clear
+/// in era 1 (2036 to 2104). Times outside those 136 years cannot be encoded.
+///
+/// The value is 4 octets of whole seconds, fractions of a second are dropped when encoding.
+/// Values of any other length, including the 8-octet NTP timestamp format, are rejected.
 pub type Time = AvpData<DateTime<Utc>>;
 
-const RFC868_OFFSET: u32 = 2208988800; // Diff. between 1970 and 1900 in seconds.
+const RFC868_OFFSET: i64 = 2208988800; // Diff. between 1970 and 1900 in seconds.
+const ERA_LENGTH: i64 = 1 << 32;
+const ERA_0_START: i64 = 1 << 31; // Era 0 values have the high bit set.
 
 impl Time {
-    pub(super) fn encode_to<W: Write>(&self, writer: &mut W) -> DiameterResult<()> {
-        let unix_timestamp = self.0.timestamp();
-        let diameter_timestamp = unix_timestamp + RFC868_OFFSET as i64;
-        if diameter_timestamp > u32::MAX as i64 {
-            Err(EncodeError(
-                "Time is too far in the future to fit into 32 bits",
-            ))?
-        }
-        let diameter_timestamp = diameter_timestamp as u32;
-        let encoded_data = Vec::from(diameter_timestamp.to_be_bytes());
-        writer.write(&encoded_data)?;
+    pub(super) fn encode_to<W: Writer>(&self, writer: &mut W) -> DiameterResult<()> {
+        let seconds = self.0.timestamp() + RFC868_OFFSET; // seconds since 1900
+        let diameter_timestamp = if (ERA_0_START..ERA_LENGTH).contains(&seconds) {
+            seconds as u32
+        } else if (ERA_LENGTH..ERA_LENGTH + ERA_0_START).contains(&seconds) {
+            (seconds - ERA_LENGTH) as u32
+        } else {
+            Err(EncodeError("Time is outside the range of 1968 to 2104"))?
+        };
+        writer.write_all(&diameter_timestamp.to_be_bytes())?;
         Ok(())
     }
 
-    pub(super) fn decode_from<R: Read>(reader: &mut R) -> DiameterResult<AvpData<DateTime<Utc>>> {
+    pub(super) fn decode_from<R: Reader>(
+        reader: &mut R,
+        length: usize,
+    ) -> DiameterResult<AvpData<DateTime<Utc>>> {
+        if length != 4 {
+            Err(Error::DecodeError("Invalid Time length"))?
+        }
         let mut b = [0; 4];
         reader.read_exact(&mut b)?;
 
-        let diameter_timestamp = u32::from_be_bytes(b); // seconds since 1900
-        let unix_timestamp = diameter_timestamp as i64 - RFC868_OFFSET as i64;
+        let diameter_timestamp = u32::from_be_bytes(b);
+        let seconds = match diameter_timestamp as i64 {
+            seconds if seconds >= ERA_0_START => seconds,
+            seconds => seconds + ERA_LENGTH,
+        };
         let timestamp = Utc
-            .timestamp_opt(unix_timestamp, 0)
+            .timestamp_opt(seconds - RFC868_OFFSET, 0)
             .single()
-            .ok_or_else(|| Error::DecodeError("Invalid time"))?;
+            .ok_or(Error::DecodeError("Invalid time"))?;
         Ok(Time::new(timestamp))
     }
 
@@ -1,7 +1,11 @@
 use crate::errors::DiameterResult;
 use crate::modeling::avp::avp::AvpValue;
 use crate::modeling::avp::AvpData;
-use std::io::{Read, Write};
+use crate::modeling::avp::octet_string::OctetString;
+use crate::modeling::codec::{Reader, Writer};
+use crate::modeling::decode_policy::Deviation;
+use alloc::string::{String, ToString};
+use alloc::vec;
 
 pub type UTF8String = AvpData<String>;
 pub type Identity = UTF8String;
@@ -13,19 +17,31 @@ impl UTF8String {
 }
 
 impl UTF8String {
-    pub(super) fn encode_to<W: Write>(&self, writer: &mut W) -> DiameterResult<()> {
-        writer.write(self.0.as_bytes())?;
+    pub(super) fn encode_to<W: Writer>(&self, writer: &mut W) -> DiameterResult<()> {
+        writer.write_all(self.0.as_bytes())?;
         Ok(())
     }
 
-    pub(super) fn decode_from<R: Read>(
+    /// Decodes the string, passing empty strings and invalid UTF-8 to `deviate`. Invalid UTF-8
+    /// tolerated by `deviate` is kept as an OctetString of the received octets, so that it is
+    /// encoded again unchanged.
+    pub(super) fn decode_from<R: Reader>(
         reader: &mut R,
         length: usize,
-    ) -> DiameterResult<AvpData<String>> {
+        mut deviate: impl FnMut(Deviation) -> DiameterResult<()>,
+    ) -> DiameterResult<AvpValue> {
         let mut buffer = vec![0u8; length];
         reader.read_exact(&mut buffer)?;
-        let string = String::from_utf8(buffer).unwrap();
-        Ok(UTF8String::new(string))
+        if buffer.is_empty() {
+            deviate(Deviation::EmptyString)?;
+        }
+        match String::from_utf8(buffer) {
+            Ok(string) => Ok(UTF8String::new(string).into()),
+            Err(e) => {
+                deviate(Deviation::InvalidUtf8)?;
+                Ok(OctetString::new(e.into_bytes()).into())
+            }
+        }
     }
 
     pub(super) fn len(&self) -> u32 {
@@ -0,0 +1,121 @@
+//! # Codec Reader and Writer
+//!
+//! The minimal byte reader and writer used to decode and encode messages and AVPs. With the
+//! `std` feature every `std::io::Read` is a `Reader` and every `std::io::Write` a `Writer`, so
+//! streams and buffers are used as before. Without it, byte slices are read and `Vec<u8>`s
+//! written:
+//! ```
+//! use diameter_interface::modeling::diameter::DiameterMessage;
+//! use diameter_interface::modeling::message::application_id::ApplicationId;
+//! use diameter_interface::modeling::message::command_code::CommandCode;
+//! use diameter_interface::modeling::message::command_flags::CommandFlag;
+//! use diameter_interface::modeling::message::dictionary::{self, Dictionary};
+//! use std::sync::Arc;
+//!
+//! let dict = Arc::new(Dictionary::new(&[&dictionary::DEFAULT_DICT_XML]));
+//! let message = DiameterMessage::new(
+//!     CommandFlag::Request, CommandCode::DeviceWatchdog, ApplicationId::Common, 1, 1,
+//! );
+//! let mut buffer: Vec<u8> = Vec::new();
+//! message.encode_to(&mut buffer)?;
+//! let decoded = DiameterMessage::decode_from(&mut &buffer[..], dict)?;
+//! assert_eq!(decoded.hop_by_hop(), 1);
+//!
+//! // Or into a buffer sized once
+//! let mut frame = vec![0; message.encoded_len()];
+//! assert_eq!(message.encode_into(&mut frame)?, buffer.len());
+//! # Ok::<(), diameter_interface::errors::Error>(())
+//! ```
+
+use crate::errors::DiameterResult;
+#[cfg(not(feature = "std"))]
+use crate::errors::Error::DecodeError;
+use crate::errors::Error::EncodeError;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+/// Reads the exact number of octets requested.
+pub trait Reader {
+    fn read_exact(&mut self, buffer: &mut [u8]) -> DiameterResult<()>;
+}
+
+/// Writes every octet given.
+pub trait Writer {
+    fn write_all(&mut self, data: &[u8]) -> DiameterResult<()>;
+}
+
+#[cfg(feature = "std")]
+impl<R: std::io::Read + ?Sized> Reader for R {
+    fn read_exact(&mut self, buffer: &mut [u8]) -> DiameterResult<()> {
+        std::io::Read::read_exact(self, buffer)?;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<W: std::io::Write + ?Sized> Writer for W {
+    fn write_all(&mut self, data: &[u8]) -> DiameterResult<()> {
+        std::io::Write::write_all(self, data)?;
+        Ok(())
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl Reader for &[u8] {
+    fn read_exact(&mut self, buffer: &mut [u8]) -> DiameterResult<()> {
+        let (data, rest) = self
+            .split_at_checked(buffer.len())
+            .ok_or(DecodeError("Unexpected end of data"))?;
+        buffer.copy_from_slice(data);
+        *self = rest;
+        Ok(())
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl Writer for Vec<u8> {
+    fn write_all(&mut self, data: &[u8]) -> DiameterResult<()> {
+        self.extend_from_slice(data);
+        Ok(())
+    }
+}
+
+/// Writes into a slice at an advancing position.
+pub(crate) struct SliceWriter<'a> {
+    buffer: &'a mut [u8],
+    position: usize,
+}
+
+impl<'a> SliceWriter<'a> {
+    pub(crate) fn new(buffer: &'a mut [u8]) -> Self {
+        Self {
+            buffer,
+            position: 0,
+        }
+    }
+}
+
+impl Writer for SliceWriter<'_> {
+    fn write_all(&mut self, data: &[u8]) -> DiameterResult<()> {
+        let end = self.position + data.len();
+        let target = self
+            .buffer
+            .get_mut(self.position..end)
+            .ok_or(EncodeError("Buffer is too small for the message"))?;
+        target.copy_from_slice(data);
+        self.position = end;
+        Ok(())
+    }
+}
+
+/// Appends to a `BytesMut`.
+#[cfg(feature = "bytes")]
+pub(crate) struct BytesWriter<'a>(pub(crate) &'a mut bytes::BytesMut);
+
+#[cfg(feature = "bytes")]
+impl Writer for BytesWriter<'_> {
+    fn write_all(&mut self, data: &[u8]) -> DiameterResult<()> {
+        self.0.extend_from_slice(data);
+        Ok(())
+    }
+}
@@ -0,0 +1,171 @@
+//! # Decode Policies
+//!
+//! A `DecodePolicy` selects the deviations from RFC 6733 tolerated when decoding a message.
+//! The strict policy, used by `DiameterMessage::decode_from`, rejects every deviation. The
+//! lenient policy tolerates all of them, and a custom policy tolerates those chosen:
+//! ```
+//! use diameter_interface::modeling::decode_policy::{DecodePolicy, Deviation};
+//! use diameter_interface::modeling::diameter::DiameterMessage;
+//! use diameter_interface::modeling::message::dictionary::{self, Dictionary};
+//! use std::sync::Arc;
+//!
+//! let dict = Arc::new(Dictionary::new(&[&dictionary::DEFAULT_DICT_XML]));
+//! // A Device-Watchdog-Request with an empty Origin-Host
+//! let stream: &[u8] = &[
+//!     1, 0, 0, 28, 0x80, 0, 1, 24, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1,
+//!     0, 0, 1, 8, 0x40, 0, 0, 8,
+//! ];
+//! assert!(DiameterMessage::decode_from(&mut &stream[..], Arc::clone(&dict)).is_err());
+//!
+//! let policy = DecodePolicy::strict()
+//!     .with_tolerated(Deviation::EmptyString)
+//!     .with_tolerated(Deviation::MissingPadding);
+//! let message = DiameterMessage::decode_with_policy(&mut &stream[..], dict, policy)?;
+//! for warning in message.warnings() {
+//!     eprintln!("{}", warning);
+//! }
+//! assert_eq!(message.warnings()[0].to_string(), "Empty UTF8String (AVP 264)");
+//! # Ok::<(), diameter_interface::errors::Error>(())
+//! ```
+//! Each tolerated deviation is recorded as a `DecodeWarning` on the decoded message. Policies
+//! are chosen per peer with `DiameterClient::with_decode_policy` and
+//! `FrameDecoder::with_decode_policy`.
+//!
+//! Unknown AVPs without the M bit are not a deviation, RFC 6733 §4.1 lets them be ignored, and
+//! are decoded as OctetStrings by every policy.
+
+use crate::errors::DiameterResult;
+use crate::errors::Error::DecodeError;
+use alloc::vec::Vec;
+use core::fmt;
+use core::fmt::{Display, Formatter};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Deviation {
+    MandatoryBitClear,   // M bit clear on an AVP the dictionary marks mandatory
+    UnknownMandatoryAvp, // M bit set on an AVP unknown to the dictionary, decoded as OctetString
+    EmptyString,         // zero-length UTF8String or DiameterIdentity
+    InvalidUtf8,         // UTF8String or DiameterIdentity not valid UTF-8, kept as OctetString
+    MissingPadding,      // no padding after the last AVP of a message or Grouped AVP
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DecodePolicy {
+    tolerated: u8, // one bit per Deviation
+}
+
+/// A deviation tolerated while decoding, and the AVP it was found in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodeWarning {
+    pub code: u32,
+    pub vendor_id: Option<u32>,
+    pub deviation: Deviation,
+}
+
+/// The policy and the warnings of a message being decoded.
+pub(crate) struct DecodeContext {
+    policy: DecodePolicy,
+    pub(crate) warnings: Vec<DecodeWarning>,
+}
+
+impl Deviation {
+    pub const ALL: [Deviation; 5] = [
+        Deviation::MandatoryBitClear,
+        Deviation::UnknownMandatoryAvp,
+        Deviation::EmptyString,
+        Deviation::InvalidUtf8,
+        Deviation::MissingPadding,
+    ];
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            Deviation::MandatoryBitClear => "Mandatory AVP without the M bit",
+            Deviation::UnknownMandatoryAvp => "Unknown AVP with the M bit",
+            Deviation::EmptyString => "Empty UTF8String",
+            Deviation::InvalidUtf8 => "UTF8String is not valid UTF-8",
+            Deviation::MissingPadding => "AVP padding is missing",
+        }
+    }
+
+    fn bit(&self) -> u8 {
+        1 << *self as u8
+    }
+}
+
+impl DecodePolicy {
+    /// Rejects every deviation from RFC 6733.
+    pub fn strict() -> Self {
+        Self { tolerated: 0 }
+    }
+
+    /// Tolerates every deviation, recording each as a warning.
+    pub fn lenient() -> Self {
+        Deviation::ALL
+            .iter()
+            .fold(Self::strict(), |policy, deviation| {
+                policy.with_tolerated(*deviation)
+            })
+    }
+
+    pub fn with_tolerated(mut self, deviation: Deviation) -> Self {
+        self.tolerated |= deviation.bit();
+        self
+    }
+
+    pub fn without_tolerated(mut self, deviation: Deviation) -> Self {
+        self.tolerated &= !deviation.bit();
+        self
+    }
+
+    pub fn tolerates(&self, deviation: Deviation) -> bool {
+        self.tolerated & deviation.bit() != 0
+    }
+}
+
+impl Default for DecodePolicy {
+    fn default() -> Self {
+        Self::strict()
+    }
+}
+
+impl Display for DecodeWarning {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self.vendor_id {
+            Some(vendor_id) => write!(
+                f,
+                "{} (AVP {}, vendor {})",
+                self.deviation.description(),
+                self.code,
+                vendor_id
+            ),
+            None => write!(f, "{} (AVP {})", self.deviation.description(), self.code),
+        }
+    }
+}
+
+impl DecodeContext {
+    pub(crate) fn new(policy: DecodePolicy) -> Self {
+        Self {
+            policy,
+            warnings: Vec::new(),
+        }
+    }
+
+    /// Records `deviation` as a warning when the policy tolerates it, and fails otherwise.
+    pub(crate) fn deviate(
+        &mut self,
+        code: u32,
+        vendor_id: Option<u32>,
+        deviation: Deviation,
+    ) -> DiameterResult<()> {
+        if !self.policy.tolerates(deviation) {
+            Err(DecodeError(deviation.description()))?
+        }
+        self.warnings.push(DecodeWarning {
+            code,
+            vendor_id,
+            deviation,
+        });
+        Ok(())
+    }
+}
@@ -31,21 +31,44 @@
 //! ```
 
 use crate::errors::DiameterResult;
+use crate::errors::Error::{DecodeError, EncodeError};
 use crate::modeling::avp::avp::{Avp, AvpFlags, AvpValue};
+use crate::modeling::avp::group::Grouped;
+use crate::modeling::avp::unsigned32::Unsigned32;
+use crate::modeling::avp::utf8_string::Identity;
+#[cfg(feature = "bytes")]
+use crate::modeling::codec::BytesWriter;
+use crate::modeling::codec::{Reader, SliceWriter, Writer};
+use crate::modeling::decode_policy::{DecodeContext, DecodePolicy, DecodeWarning};
 use crate::modeling::message::application_id::ApplicationId;
 use crate::modeling::message::command_code::CommandCode;
 use crate::modeling::message::command_flags::CommandFlag;
 use crate::modeling::message::dictionary::Dictionary;
-use std::io::{Read, Write};
-use std::sync::Arc;
+use crate::modeling::message::result_code::ResultCode;
+use alloc::string::ToString;
+use alloc::sync::Arc;
+use alloc::vec;
+use alloc::vec::Vec;
+#[cfg(feature = "bytes")]
+use bytes::BytesMut;
 
-#[derive(Debug)]
+const HEADER_LENGTH: usize = 20;
+
+const SESSION_ID: u32 = 263;
+const ORIGIN_HOST: u32 = 264;
+const SUPPORTED_VENDOR_ID: u32 = 265;
+const FAILED_AVP: u32 = 279;
+const PROXY_INFO: u32 = 284;
+const ORIGIN_REALM: u32 = 296;
+
+#[derive(Debug, Clone)]
 pub struct DiameterMessage {
     header: DiameterHeader,
     avps: Vec<Avp>,
+    warnings: Vec<DecodeWarning>, // deviations tolerated when decoding
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct DiameterHeader {
     version: u8,
     message_length: u32, // 24 bits
@@ -67,7 +90,7 @@ impl DiameterMessage {
         Self {
             header: DiameterHeader {
                 version: 1,
-                message_length: 20,
+                message_length: HEADER_LENGTH as u32,
                 command_flag: command_flag.value(),
                 command_code,
                 application_id,
@@ -75,6 +98,108 @@ impl DiameterMessage {
                 end_to_end,
             },
             avps: vec![],
+            warnings: vec![],
+        }
+    }
+
+    /// Creates the answer to `request` as described in RFC 6733 §6.2: the command code,
+    /// application id, P bit and end-to-end/hop-by-hop identifiers are copied, the R bit is
+    /// cleared, and the Session-Id and every Proxy-Info AVP are copied in their original order.
+    ///
+    /// The remaining AVPs, including Origin-Host, Origin-Realm and Result-Code, are left to the
+    /// caller.
+    pub fn answer_for(request: &DiameterMessage) -> Self {
+        let mut answer = Self::empty_answer_for(request);
+        if let Some(session_id) = request.get_avp(SESSION_ID, None) {
+            answer.add(session_id.clone());
+        }
+        for proxy_info in request.proxy_infos() {
+            answer.add(proxy_info.clone());
+        }
+        answer
+    }
+
+    /// Creates an error answer to `request`, laid out as the `answer-message` of RFC 6733 §7.2:
+    /// Session-Id, Origin-Host, Origin-Realm, Result-Code (Experimental-Result for an
+    /// experimental code), Failed-AVP and the request's Proxy-Info AVPs in their original order.
+    ///
+    /// The E bit is only set for protocol errors (3xxx), other failures are answered without it
+    /// as RFC 6733 §7.1 requires. `failed_avps` are the offending AVPs wrapped into a single
+    /// Failed-AVP, which is omitted when empty.
+    pub fn error_answer_for(
+        request: &DiameterMessage,
+        origin_host: &str,
+        origin_realm: &str,
+        result_code: ResultCode,
+        failed_avps: Vec<Avp>,
+    ) -> Self {
+        let mut answer = Self::empty_answer_for(request);
+        if result_code.is_protocol_error() {
+            answer.set_flag(CommandFlag::Error);
+        }
+        if let Some(session_id) = request.get_avp(SESSION_ID, None) {
+            answer.add(session_id.clone());
+        }
+        answer.add_avp(
+            ORIGIN_HOST,
+            AvpFlags::M,
+            None,
+            Identity::new(origin_host.to_string()),
+        );
+        answer.add_avp(
+            ORIGIN_REALM,
+            AvpFlags::M,
+            None,
+            Identity::new(origin_realm.to_string()),
+        );
+        answer.add(result_code.to_avp());
+        if !failed_avps.is_empty() {
+            answer.add_avp(FAILED_AVP, AvpFlags::M, None, Grouped::new(failed_avps));
+        }
+        for proxy_info in request.proxy_infos() {
+            answer.add(proxy_info.clone());
+        }
+        answer
+    }
+
+    fn empty_answer_for(request: &DiameterMessage) -> Self {
+        Self::from_header(
+            1,
+            request.header.command_flag & CommandFlag::Proxyable.value(),
+            request.header.command_code,
+            request.header.application_id,
+            request.header.hop_by_hop,
+            request.header.end_to_end,
+        )
+    }
+
+    fn proxy_infos(&self) -> impl Iterator<Item = &Avp> {
+        self.avps
+            .iter()
+            .filter(|avp| avp.code() == PROXY_INFO && avp.vendor_id().is_none())
+    }
+
+    /// A message without AVPs with the given raw header fields.
+    pub(crate) fn from_header(
+        version: u8,
+        command_flag: u8,
+        command_code: CommandCode,
+        application_id: ApplicationId,
+        hop_by_hop: u32,
+        end_to_end: u32,
+    ) -> Self {
+        Self {
+            header: DiameterHeader {
+                version,
+                message_length: HEADER_LENGTH as u32,
+                command_flag,
+                command_code,
+                application_id,
+                hop_by_hop,
+                end_to_end,
+            },
+            avps: vec![],
+            warnings: vec![],
         }
     }
 
@@ -94,56 +219,215 @@ impl DiameterMessage {
         self.add(avp);
     }
 
-    pub fn encode_to<W: Write>(&mut self, writer: &mut W) -> DiameterResult<()> {
-        writer.write(&self.header.version.to_be_bytes())?;
-        writer.write(&self.header.message_length.to_be_bytes()[1..])?;
-        writer.write(&self.header.command_flag.to_be_bytes())?;
-        writer.write(&self.header.command_code.get_code().to_be_bytes()[1..])?;
-        writer.write(&self.header.application_id.value().to_be_bytes())?;
-        writer.write(&self.header.hop_by_hop.to_be_bytes())?;
-        writer.write(&self.header.end_to_end.to_be_bytes())?;
-        for avp in self.avps.iter_mut() {
+    pub fn version(&self) -> u8 {
+        self.header.version
+    }
+
+    pub fn command_flags(&self) -> u8 {
+        self.header.command_flag
+    }
+
+    pub fn has_flag(&self, flag: CommandFlag) -> bool {
+        self.header.command_flag & flag.value() != 0
+    }
+
+    pub fn set_flag(&mut self, flag: CommandFlag) {
+        self.header.command_flag |= flag.value();
+    }
+
+    pub fn clear_flag(&mut self, flag: CommandFlag) {
+        self.header.command_flag &= !flag.value();
+    }
+
+    pub fn is_request(&self) -> bool {
+        self.has_flag(CommandFlag::Request)
+    }
+
+    pub fn command_code(&self) -> CommandCode {
+        self.header.command_code
+    }
+
+    pub fn application_id(&self) -> ApplicationId {
+        self.header.application_id
+    }
+
+    pub fn set_application_id(&mut self, application_id: ApplicationId) {
+        self.header.application_id = application_id;
+    }
+
+    pub fn hop_by_hop(&self) -> u32 {
+        self.header.hop_by_hop
+    }
+
+    pub fn end_to_end(&self) -> u32 {
+        self.header.end_to_end
+    }
+
+    pub fn set_hop_by_hop(&mut self, hop_by_hop: u32) {
+        self.header.hop_by_hop = hop_by_hop;
+    }
+
+    pub fn set_end_to_end(&mut self, end_to_end: u32) {
+        self.header.end_to_end = end_to_end;
+    }
+
+    /// The deviations from RFC 6733 tolerated when the message was decoded.
+    pub fn warnings(&self) -> &[DecodeWarning] {
+        &self.warnings
+    }
+
+    pub fn avps(&self) -> &[Avp] {
+        &self.avps
+    }
+
+    /// The outcome of an answer, from its Result-Code or Experimental-Result AVP.
+    pub fn result_code(&self) -> Option<ResultCode> {
+        ResultCode::from_avps(&self.avps)
+    }
+
+    /// Replaces the first top-level AVP with the same code and vendor as `avp`, or adds `avp`
+    /// when there is none.
+    pub fn set_avp(&mut self, avp: Avp) {
+        let existing = self.avps.iter_mut().find(|existing| {
+            existing.code() == avp.code() && existing.vendor_id() == avp.vendor_id()
+        });
+        match existing {
+            Some(existing) => {
+                self.header.message_length -= existing.get_length() + existing.get_padding();
+                self.header.message_length += avp.get_length() + avp.get_padding();
+                *existing = avp;
+            }
+            None => self.add(avp),
+        }
+    }
+
+    /// Removes every top-level AVP with the given code and vendor and returns them in their
+    /// original order.
+    pub fn remove_avps(&mut self, code: u32, vendor_id: Option<u32>) -> Vec<Avp> {
+        let (removed, kept) = core::mem::take(&mut self.avps)
+            .into_iter()
+            .partition(|avp: &Avp| avp.code() == code && avp.vendor_id() == vendor_id);
+        self.avps = kept;
+        for avp in &removed {
+            self.header.message_length -= avp.get_length() + avp.get_padding();
+        }
+        removed
+    }
+
+    /// Returns the first top-level AVP with the given code and vendor.
+    pub fn get_avp(&self, code: u32, vendor_id: Option<u32>) -> Option<&Avp> {
+        self.avps
+            .iter()
+            .find(|avp| avp.code() == code && avp.vendor_id() == vendor_id)
+    }
+
+    /// Adds a Supported-Vendor-Id AVP for every vendor known to the dictionary, as sent in
+    /// CER/CEA to advertise vendor-specific AVP support.
+    pub fn add_supported_vendor_ids(&mut self, dict: &Dictionary) {
+        for (vendor_id, _) in dict.vendors() {
+            self.add_avp(
+                SUPPORTED_VENDOR_ID,
+                AvpFlags::M,
+                None,
+                Unsigned32::new(vendor_id),
+            );
+        }
+    }
+
+    /// The number of octets of the encoded message, known without encoding it.
+    pub fn encoded_len(&self) -> usize {
+        self.header.message_length as usize
+    }
+
+    pub fn encode_to<W: Writer>(&self, writer: &mut W) -> DiameterResult<()> {
+        let header = &self.header;
+        let mut b = [0u8; HEADER_LENGTH];
+        b[0] = header.version;
+        b[1..4].copy_from_slice(&header.message_length.to_be_bytes()[1..]);
+        b[4] = header.command_flag;
+        b[5..8].copy_from_slice(&header.command_code.get_code().to_be_bytes()[1..]);
+        b[8..12].copy_from_slice(&header.application_id.value().to_be_bytes());
+        b[12..16].copy_from_slice(&header.hop_by_hop.to_be_bytes());
+        b[16..20].copy_from_slice(&header.end_to_end.to_be_bytes());
+        writer.write_all(&b)?;
+        for avp in &self.avps {
             avp.encode_to(writer)?;
         }
         Ok(())
     }
 
-    pub fn decode_from<R: Read>(
+    /// Encodes the message at the start of `buffer` in one pass, returning the number of octets
+    /// written. `buffer` must hold at least `encoded_len()` octets.
+    pub fn encode_into(&self, buffer: &mut [u8]) -> DiameterResult<usize> {
+        let length = self.encoded_len();
+        let buffer = buffer
+            .get_mut(..length)
+            .ok_or(EncodeError("Buffer is too small for the message"))?;
+        self.encode_to(&mut SliceWriter::new(buffer))?;
+        Ok(length)
+    }
+
+    /// Appends the encoded message to `buffer`, reserving its length once.
+    #[cfg(feature = "bytes")]
+    pub fn encode_into_bytes(&self, buffer: &mut BytesMut) -> DiameterResult<()> {
+        buffer.reserve(self.encoded_len());
+        self.encode_to(&mut BytesWriter(buffer))
+    }
+
+    /// Decodes a message following RFC 6733 strictly, as `DecodePolicy::strict`.
+    pub fn decode_from<R: Reader>(
+        reader: &mut R,
+        dict: Arc<Dictionary>,
+    ) -> DiameterResult<DiameterMessage> {
+        Self::decode_with_policy(reader, dict, DecodePolicy::strict())
+    }
+
+    /// Decodes a message, tolerating the deviations allowed by `policy` and recording them as
+    /// the warnings of the message.
+    pub fn decode_with_policy<R: Reader>(
         reader: &mut R,
         dict: Arc<Dictionary>,
+        policy: DecodePolicy,
     ) -> DiameterResult<DiameterMessage> {
-        let mut b = [0u8; 20];
+        let mut b = [0u8; HEADER_LENGTH];
         reader.read_exact(&mut b)?;
 
         let version = b[0];
-        let mut message_length = u32::from_be_bytes([0, b[1], b[2], b[3]]);
+        let message_length = u32::from_be_bytes([0, b[1], b[2], b[3]]);
         let command_flag = b[4];
-        let command_code = u32::from_be_bytes([b[4], b[5], b[6], b[7]]);
+        let command_code = u32::from_be_bytes([0, b[5], b[6], b[7]]);
         let application_id = u32::from_be_bytes([b[8], b[9], b[10], b[11]]);
         let hop_by_hop = u32::from_be_bytes([b[12], b[13], b[14], b[15]]);
         let end_to_end = u32::from_be_bytes([b[16], b[17], b[18], b[19]]);
 
-        let header = DiameterHeader {
+        let mut message = DiameterMessage::from_header(
             version,
-            message_length,
             command_flag,
-            application_id: ApplicationId::try_from(application_id)?,
-            command_code: CommandCode::try_from(command_code)?,
+            CommandCode::try_from(command_code)?,
+            ApplicationId::try_from(application_id)?,
             hop_by_hop,
             end_to_end,
-        };
+        );
 
-        let mut message = DiameterMessage {
-            header,
-            avps: vec![],
-        };
-
-        message_length -= 20;
-        while message_length > 0 {
-            let avp = Avp::decode_from(reader, Arc::clone(&dict))?;
-            message_length = message_length - avp.get_length() - avp.get_padding();
+        let mut context = DecodeContext::new(policy);
+        let mut avps_length = (message_length as usize)
+            .checked_sub(HEADER_LENGTH)
+            .ok_or(DecodeError("Invalid message length"))?;
+        while avps_length > 0 {
+            let avp = Avp::decode_with(reader, &dict, avps_length, &mut context)?;
+            // Saturates when the padding of the last AVP is missing
+            avps_length =
+                avps_length.saturating_sub((avp.get_length() + avp.get_padding()) as usize);
             message.add(avp);
         }
+        message.warnings = context.warnings;
         Ok(message)
     }
 }
+
+/// Messages are equal when their headers and AVPs are, the warnings of decoding aside.
+impl PartialEq for DiameterMessage {
+    fn eq(&self, other: &Self) -> bool {
+        self.header == other.header && self.avps == other.avps
+    }
+}
@@ -0,0 +1,334 @@
+//! # Message Diff
+//!
+//! Compares two messages and reports the header fields that differ and the AVPs added,
+//! removed or changed, by their path of dictionary names:
+//! ```
+//! use diameter_interface::modeling::avp::avp::{Avp, AvpFlags};
+//! use diameter_interface::modeling::avp::group::Grouped;
+//! use diameter_interface::modeling::avp::unsigned32::Unsigned32;
+//! use diameter_interface::modeling::avp::utf8_string::UTF8String;
+//! use diameter_interface::modeling::diameter::DiameterMessage;
+//! use diameter_interface::modeling::diff::HeaderField;
+//! use diameter_interface::modeling::message::application_id::ApplicationId;
+//! use diameter_interface::modeling::message::command_code::CommandCode;
+//! use diameter_interface::modeling::message::command_flags::CommandFlag;
+//! use diameter_interface::modeling::message::dictionary::{self, Dictionary};
+//!
+//! let dict = Dictionary::new(&[&dictionary::DEFAULT_DICT_XML]);
+//! let cca = |hop_by_hop, session_id: &str, result_code, granted: Vec<Avp>| {
+//!     let mut cca = DiameterMessage::new(
+//!         CommandFlag::Proxyable, CommandCode::CreditControl, ApplicationId::CreditControl,
+//!         hop_by_hop, 1,
+//!     );
+//!     cca.add_avp(263, AvpFlags::M, None, UTF8String::new(session_id.to_string()));
+//!     cca.add_avp(268, AvpFlags::M, None, Unsigned32::new(result_code));
+//!     cca.add_avp(456, AvpFlags::M, None, Grouped::new(granted));
+//!     cca
+//! };
+//! let cc_time = Avp::new(420, AvpFlags::M, None, Unsigned32::new(60));
+//! let granted = Avp::new(431, AvpFlags::M, None, Grouped::new(vec![cc_time]));
+//! let expected = cca(1, "host;1;1", 2001, vec![granted]);
+//! let answer = cca(2, "host;1;2", 5031, vec![]);
+//!
+//! let diff = expected
+//!     .diff(&answer, &dict)
+//!     .ignore_avp_name("Session-Id")
+//!     .ignore_avp_name("Event-Timestamp")
+//!     .ignore_field(HeaderField::HopByHop);
+//! assert!(!diff.is_empty());
+//! println!("{}", diff);
+//! # assert_eq!(diff.to_string(), "changed Result-Code: Result-Code=2001 -> Result-Code=5031
+//! # removed Multiple-Services-Credit-Control/Granted-Service-Unit: Granted-Service-Unit={CC-Time=60}");
+//! ```
+//! prints:
+//! ```text
+//! changed Result-Code: Result-Code=2001 -> Result-Code=5031
+//! removed Multiple-Services-Credit-Control/Granted-Service-Unit: Granted-Service-Unit={CC-Time=60}
+//! ```
+//! AVPs are matched by code and vendor, in order of occurrence, and the AVPs of Grouped AVPs
+//! are compared one by one. Repeated AVPs are indexed in paths, as in `Subscription-Id[1]`.
+//! Matched AVPs in a different order are reported as reordered. With `unordered`, the order is
+//! not compared and repeated AVPs are matched to their equal counterparts wherever they are.
+//!
+//! Ignored AVPs are left out at every depth.
+
+use crate::modeling::avp::avp::{Avp, AvpValue};
+use crate::modeling::diameter::DiameterMessage;
+use crate::modeling::message::dictionary::Dictionary;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+use core::fmt::{Display, Formatter};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HeaderField {
+    Version,
+    CommandFlags,
+    CommandCode,
+    ApplicationId,
+    HopByHop,
+    EndToEnd,
+}
+
+/// A difference from the first message compared to the second.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Difference {
+    Header {
+        field: HeaderField,
+        old: u32,
+        new: u32,
+    },
+    Added {
+        path: String,
+        avp: Avp,
+    },
+    Removed {
+        path: String,
+        avp: Avp,
+    },
+    Changed {
+        path: String,
+        old: Avp,
+        new: Avp,
+    },
+    Reordered {
+        path: String, // of the Grouped AVP, empty for the top-level AVPs
+    },
+}
+
+/// Compares two messages with the names of a `Dictionary`, see `DiameterMessage::diff`.
+pub struct MessageDiff<'a> {
+    old: &'a DiameterMessage,
+    new: &'a DiameterMessage,
+    dict: &'a Dictionary,
+    ignored_avps: Vec<(u32, Option<u32>)>,
+    ignored_fields: Vec<HeaderField>,
+    unordered: bool,
+}
+
+impl DiameterMessage {
+    /// Compares the message with `other`, naming AVPs with `dict`.
+    pub fn diff<'a>(&'a self, other: &'a DiameterMessage, dict: &'a Dictionary) -> MessageDiff<'a> {
+        MessageDiff {
+            old: self,
+            new: other,
+            dict,
+            ignored_avps: vec![],
+            ignored_fields: vec![],
+            unordered: false,
+        }
+    }
+}
+
+impl HeaderField {
+    const ALL: [HeaderField; 6] = [
+        HeaderField::Version,
+        HeaderField::CommandFlags,
+        HeaderField::CommandCode,
+        HeaderField::ApplicationId,
+        HeaderField::HopByHop,
+        HeaderField::EndToEnd,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            HeaderField::Version => "Version",
+            HeaderField::CommandFlags => "Flags",
+            HeaderField::CommandCode => "Command Code",
+            HeaderField::ApplicationId => "Application Id",
+            HeaderField::HopByHop => "Hop-by-Hop Identifier",
+            HeaderField::EndToEnd => "End-to-End Identifier",
+        }
+    }
+
+    fn value(&self, message: &DiameterMessage) -> u32 {
+        match self {
+            HeaderField::Version => message.version() as u32,
+            HeaderField::CommandFlags => message.command_flags() as u32,
+            HeaderField::CommandCode => message.command_code().get_code(),
+            HeaderField::ApplicationId => message.application_id().get_code(),
+            HeaderField::HopByHop => message.hop_by_hop(),
+            HeaderField::EndToEnd => message.end_to_end(),
+        }
+    }
+}
+
+impl<'a> MessageDiff<'a> {
+    /// Leaves out the AVPs with the given code and vendor.
+    pub fn ignore_avp(mut self, code: u32, vendor_id: Option<u32>) -> Self {
+        self.ignored_avps.push((code, vendor_id));
+        self
+    }
+
+    /// Leaves out the AVPs with the given dictionary name, of every vendor defining it. Names
+    /// missing from the dictionary are ignored.
+    pub fn ignore_avp_name(mut self, name: &str) -> Self {
+        for definition in self.dict.get_avps_by_name(name) {
+            self.ignored_avps.push((definition.code, definition.vendor_id));
+        }
+        self
+    }
+
+    pub fn ignore_field(mut self, field: HeaderField) -> Self {
+        self.ignored_fields.push(field);
+        self
+    }
+
+    /// Matches repeated AVPs regardless of their position.
+    pub fn unordered(mut self) -> Self {
+        self.unordered = true;
+        self
+    }
+
+    pub fn differences(&self) -> Vec<Difference> {
+        let mut differences = vec![];
+        for field in HeaderField::ALL {
+            let (old, new) = (field.value(self.old), field.value(self.new));
+            if old != new && !self.ignored_fields.contains(&field) {
+                differences.push(Difference::Header { field, old, new });
+            }
+        }
+        self.diff_avps("", self.old.avps(), self.new.avps(), &mut differences);
+        differences
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.differences().is_empty()
+    }
+
+    fn diff_avps(&self, path: &str, old: &[Avp], new: &[Avp], differences: &mut Vec<Difference>) {
+        let old: Vec<&Avp> = old.iter().filter(|avp| !self.is_ignored(avp)).collect();
+        let new: Vec<&Avp> = new.iter().filter(|avp| !self.is_ignored(avp)).collect();
+
+        // The index in `new` matched to each AVP of `old`
+        let mut matches: Vec<Option<usize>> = vec![None; old.len()];
+        let mut matched = vec![false; new.len()];
+        if self.unordered {
+            for (i, avp) in old.iter().enumerate() {
+                let equal = (0..new.len()).find(|&j| {
+                    !matched[j] && same_key(avp, new[j]) && self.is_equal(path, avp, new[j])
+                });
+                if let Some(j) = equal {
+                    matches[i] = Some(j);
+                    matched[j] = true;
+                }
+            }
+        }
+        for (i, avp) in old.iter().enumerate() {
+            if matches[i].is_none() {
+                let next = (0..new.len()).find(|&j| !matched[j] && same_key(avp, new[j]));
+                if let Some(j) = next {
+                    matches[i] = Some(j);
+                    matched[j] = true;
+                }
+            }
+        }
+
+        for (i, avp) in old.iter().enumerate() {
+            let avp_path = self.path(path, &old, &new, &old[..i], avp);
+            match matches[i] {
+                Some(j) => self.diff_avp(avp_path, avp, new[j], differences),
+                None => differences.push(Difference::Removed {
+                    path: avp_path,
+                    avp: (*avp).clone(),
+                }),
+            }
+        }
+        for (j, avp) in new.iter().enumerate() {
+            if !matched[j] {
+                differences.push(Difference::Added {
+                    path: self.path(path, &old, &new, &new[..j], avp),
+                    avp: (*avp).clone(),
+                });
+            }
+        }
+
+        let order: Vec<usize> = matches.iter().flatten().copied().collect();
+        if !self.unordered && !order.is_sorted() {
+            differences.push(Difference::Reordered {
+                path: path.to_string(),
+            });
+        }
+    }
+
+    fn diff_avp(&self, path: String, old: &Avp, new: &Avp, differences: &mut Vec<Difference>) {
+        match (old.value(), new.value()) {
+            (AvpValue::Grouped(old_group), AvpValue::Grouped(new_group))
+                if old.flags() == new.flags() =>
+            {
+                self.diff_avps(&path, old_group.avps(), new_group.avps(), differences)
+            }
+            _ if old == new => {}
+            _ => differences.push(Difference::Changed {
+                path,
+                old: old.clone(),
+                new: new.clone(),
+            }),
+        }
+    }
+
+    fn is_equal(&self, path: &str, old: &Avp, new: &Avp) -> bool {
+        let mut differences = vec![];
+        self.diff_avp(path.to_string(), old, new, &mut differences);
+        differences.is_empty()
+    }
+
+    fn is_ignored(&self, avp: &Avp) -> bool {
+        self.ignored_avps.contains(&(avp.code(), avp.vendor_id()))
+    }
+
+    /// The path of `avp`, indexed by the AVPs with the same code and vendor in `before` when
+    /// either message repeats it.
+    fn path(&self, parent: &str, old: &[&Avp], new: &[&Avp], before: &[&Avp], avp: &Avp) -> String {
+        let count = |avps: &[&Avp]| avps.iter().filter(|other| same_key(avp, other)).count();
+        let name = match self.dict.get_avp_name(avp.code(), avp.vendor_id()) {
+            Some(name) => name.to_string(),
+            None => avp.code().to_string(),
+        };
+        let name = match count(old) > 1 || count(new) > 1 {
+            true => format!("{}[{}]", name, count(before)),
+            false => name,
+        };
+        match parent.is_empty() {
+            true => name,
+            false => format!("{}/{}", parent, name),
+        }
+    }
+}
+
+impl Display for MessageDiff<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        for (i, difference) in self.differences().iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            match difference {
+                Difference::Header { field, old, new } => {
+                    write!(f, "changed {}: {} -> {}", field.name(), old, new)?
+                }
+                Difference::Added { path, avp } => {
+                    write!(f, "added {}: {}", path, avp.display(self.dict).compact())?
+                }
+                Difference::Removed { path, avp } => {
+                    write!(f, "removed {}: {}", path, avp.display(self.dict).compact())?
+                }
+                Difference::Changed { path, old, new } => write!(
+                    f,
+                    "changed {}: {} -> {}",
+                    path,
+                    old.display(self.dict).compact(),
+                    new.display(self.dict).compact()
+                )?,
+                Difference::Reordered { path } if path.is_empty() => write!(f, "reordered AVPs")?,
+                Difference::Reordered { path } => write!(f, "reordered AVPs of {}", path)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+fn same_key(avp: &Avp, other: &Avp) -> bool {
+    avp.code() == other.code() && avp.vendor_id() == other.vendor_id()
+}
@@ -0,0 +1,305 @@
+//! # Message Display
+//!
+//! Human-readable output of messages and AVPs, with names, vendors and enumeration values
+//! taken from a `Dictionary`:
+//! ```
+//! use diameter_interface::modeling::avp::avp::AvpFlags;
+//! use diameter_interface::modeling::avp::enumerated::Enumerated;
+//! use diameter_interface::modeling::diameter::DiameterMessage;
+//! use diameter_interface::modeling::message::application_id::ApplicationId;
+//! use diameter_interface::modeling::message::command_code::CommandCode;
+//! use diameter_interface::modeling::message::command_flags::CommandFlag;
+//! use diameter_interface::modeling::message::dictionary::{self, Dictionary};
+//!
+//! let dict = Dictionary::new(&[&dictionary::DEFAULT_DICT_XML]);
+//! let mut message = DiameterMessage::new(
+//!     CommandFlag::Request, CommandCode::CreditControl, ApplicationId::CreditControl, 1, 2,
+//! );
+//! message.add_avp(416, AvpFlags::M, None, Enumerated::new(1));
+//!
+//! println!("{}", message.display(&dict));
+//! assert!(message.display(&dict).to_string().ends_with("val=INITIAL_REQUEST (1)"));
+//! ```
+//! prints an indented tree in the style of Wireshark:
+//! ```text
+//! Version: 1
+//! Flags: 0xc0 (Request, Proxyable)
+//! Command Code: 272 Credit-Control Request
+//! Application Id: Charging Control (4)
+//! Hop-by-Hop Identifier: 0x00000001
+//! End-to-End Identifier: 0x00000002
+//! AVP: Session-Id(263) l=16 f=-M- val=host;1;1
+//! AVP: CC-Request-Type(416) l=12 f=-M- val=INITIAL_REQUEST (1)
+//! AVP: Subscription-Id(443) l=44 f=-M-
+//!     AVP: Subscription-Id-Type(450) l=12 f=-M- val=END_USER_IMSI (1)
+//!     AVP: Subscription-Id-Data(444) l=23 f=-M- val=001010123456789
+//! ```
+//! and `message.display(&dict).compact()` a single line for logs:
+//! ```text
+//! Credit-Control-Request hbh=0x00000001 e2e=0x00000002 Session-Id="host;1;1" CC-Request-Type=INITIAL_REQUEST Subscription-Id={Subscription-Id-Type=END_USER_IMSI Subscription-Id-Data="001010123456789"}
+//! ```
+//! AVPs missing from the dictionary are shown as `Unknown`, with their code. Integer values are
+//! shown with the name of the matching dictionary enumeration item.
+
+use crate::modeling::avp::avp::{Avp, AvpValue};
+use crate::modeling::diameter::DiameterMessage;
+use crate::modeling::message::command_flags::CommandFlag;
+use crate::modeling::message::dictionary::Dictionary;
+use alloc::vec::Vec;
+use core::fmt;
+use core::fmt::{Display, Formatter};
+
+const INDENT: &str = "    ";
+
+const AVP_FLAG_VENDOR: u8 = 0x80;
+const AVP_FLAG_MANDATORY: u8 = 0x40;
+const AVP_FLAG_PROTECTED: u8 = 0x20;
+
+/// Formats a message with the names of a `Dictionary`, see `DiameterMessage::display`.
+pub struct MessageDisplay<'a> {
+    message: &'a DiameterMessage,
+    dict: &'a Dictionary,
+    compact: bool,
+}
+
+/// Formats an AVP with the names of a `Dictionary`, see `Avp::display`.
+pub struct AvpDisplay<'a> {
+    avp: &'a Avp,
+    dict: &'a Dictionary,
+    compact: bool,
+}
+
+impl DiameterMessage {
+    /// Formats the message as an indented tree with the names of `dict`.
+    pub fn display<'a>(&'a self, dict: &'a Dictionary) -> MessageDisplay<'a> {
+        MessageDisplay {
+            message: self,
+            dict,
+            compact: false,
+        }
+    }
+}
+
+impl Avp {
+    /// Formats the AVP, and the AVPs it groups, with the names of `dict`.
+    pub fn display<'a>(&'a self, dict: &'a Dictionary) -> AvpDisplay<'a> {
+        AvpDisplay {
+            avp: self,
+            dict,
+            compact: false,
+        }
+    }
+}
+
+impl MessageDisplay<'_> {
+    /// Formats the message on a single line.
+    pub fn compact(mut self) -> Self {
+        self.compact = true;
+        self
+    }
+}
+
+impl AvpDisplay<'_> {
+    /// Formats the AVP on a single line.
+    pub fn compact(mut self) -> Self {
+        self.compact = true;
+        self
+    }
+}
+
+impl Display for MessageDisplay<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let message = self.message;
+        let command_code = message.command_code();
+        let command_name = self
+            .dict
+            .commands()
+            .find(|(_, code)| **code == command_code)
+            .map(|(name, _)| name)
+            .unwrap_or("Unknown");
+        let kind = if message.is_request() {
+            "Request"
+        } else {
+            "Answer"
+        };
+
+        if self.compact {
+            write!(
+                f,
+                "{}-{} hbh={:#010x} e2e={:#010x}",
+                command_name,
+                kind,
+                message.hop_by_hop(),
+                message.end_to_end()
+            )?;
+            if message.has_flag(CommandFlag::Error) {
+                write!(f, " error")?;
+            }
+            for avp in message.avps() {
+                write!(f, " ")?;
+                write_compact(f, avp, self.dict)?;
+            }
+            return Ok(());
+        }
+
+        let flags: Vec<&str> = [
+            (CommandFlag::Request, "Request"),
+            (CommandFlag::Proxyable, "Proxyable"),
+            (CommandFlag::Error, "Error"),
+            (CommandFlag::Retransmit, "Retransmit"),
+        ]
+        .iter()
+        .filter(|(flag, _)| message.has_flag(*flag))
+        .map(|(_, name)| *name)
+        .collect();
+        let application_id = message.application_id();
+        let application_name = self
+            .dict
+            .applications()
+            .find(|(_, id)| **id == application_id)
+            .map(|(name, _)| name)
+            .unwrap_or("Unknown");
+
+        writeln!(f, "Version: {}", message.version())?;
+        writeln!(
+            f,
+            "Flags: {:#04x} ({})",
+            message.command_flags(),
+            flags.join(", ")
+        )?;
+        writeln!(
+            f,
+            "Command Code: {} {} {}",
+            command_code.get_code(),
+            command_name,
+            kind
+        )?;
+        writeln!(
+            f,
+            "Application Id: {} ({})",
+            application_name,
+            application_id.value()
+        )?;
+        writeln!(f, "Hop-by-Hop Identifier: {:#010x}", message.hop_by_hop())?;
+        write!(f, "End-to-End Identifier: {:#010x}", message.end_to_end())?;
+        for avp in message.avps() {
+            writeln!(f)?;
+            write_tree(f, avp, self.dict, 0)?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for AvpDisplay<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if self.compact {
+            write_compact(f, self.avp, self.dict)
+        } else {
+            write_tree(f, self.avp, self.dict, 0)
+        }
+    }
+}
+
+fn write_tree(f: &mut Formatter, avp: &Avp, dict: &Dictionary, depth: usize) -> fmt::Result {
+    let flags = avp.flags();
+    let flag = |bit: u8, c: char| if flags & bit != 0 { c } else { '-' };
+    write!(
+        f,
+        "{}AVP: {}({}) l={} f={}{}{}",
+        INDENT.repeat(depth),
+        avp_name(avp, dict),
+        avp.code(),
+        avp.get_length(),
+        flag(AVP_FLAG_VENDOR, 'V'),
+        flag(AVP_FLAG_MANDATORY, 'M'),
+        flag(AVP_FLAG_PROTECTED, 'P'),
+    )?;
+    if let Some(vendor_id) = avp.vendor_id() {
+        match dict.get_vendor_name(vendor_id) {
+            Some(name) => write!(f, " vnd={}", name)?,
+            None => write!(f, " vnd={}", vendor_id)?,
+        }
+    }
+    match avp.value() {
+        AvpValue::Grouped(grouped) => {
+            for child in grouped.avps() {
+                writeln!(f)?;
+                write_tree(f, child, dict, depth + 1)?;
+            }
+            Ok(())
+        }
+        AvpValue::Integer32(value) | AvpValue::Enumerated(value) => {
+            let value = *value.value();
+            match dict.get_enum_name(avp.code(), avp.vendor_id(), value) {
+                Some(name) => write!(f, " val={} ({})", name, value),
+                None => write!(f, " val={}", value),
+            }
+        }
+        value => {
+            write!(f, " val=")?;
+            write_value(f, value, false)
+        }
+    }
+}
+
+fn write_compact(f: &mut Formatter, avp: &Avp, dict: &Dictionary) -> fmt::Result {
+    match dict.get_avp_name(avp.code(), avp.vendor_id()) {
+        Some(name) => write!(f, "{}=", name)?,
+        None => write!(f, "{}=", avp.code())?,
+    }
+    match avp.value() {
+        AvpValue::Grouped(grouped) => {
+            write!(f, "{{")?;
+            for (i, child) in grouped.avps().iter().enumerate() {
+                if i > 0 {
+                    write!(f, " ")?;
+                }
+                write_compact(f, child, dict)?;
+            }
+            write!(f, "}}")
+        }
+        AvpValue::Integer32(value) | AvpValue::Enumerated(value) => {
+            let value = *value.value();
+            match dict.get_enum_name(avp.code(), avp.vendor_id(), value) {
+                Some(name) => write!(f, "{}", name),
+                None => write!(f, "{}", value),
+            }
+        }
+        value => write_value(f, value, true),
+    }
+}
+
+fn avp_name<'a>(avp: &Avp, dict: &'a Dictionary) -> &'a str {
+    dict.get_avp_name(avp.code(), avp.vendor_id())
+        .unwrap_or("Unknown")
+}
+
+/// Writes a value without dictionary names, strings are quoted when `quote`.
+fn write_value(f: &mut Formatter, value: &AvpValue, quote: bool) -> fmt::Result {
+    match value {
+        AvpValue::UTF8String(value) | AvpValue::Identity(value) if quote => {
+            write!(f, "{:?}", value.value())
+        }
+        AvpValue::UTF8String(value) | AvpValue::Identity(value) => write!(f, "{}", value.value()),
+        AvpValue::OctetString(value) => {
+            write!(f, "0x")?;
+            for byte in value.value() {
+                write!(f, "{:02x}", byte)?;
+            }
+            Ok(())
+        }
+        AvpValue::Address(value) => write!(f, "{}", value.value()),
+        AvpValue::AddressIPv4(value) => write!(f, "{}", value.value()),
+        AvpValue::AddressIPv6(value) => write!(f, "{}", value.value()),
+        AvpValue::DiameterURI(value) => write!(f, "{}", value.value()),
+        AvpValue::Time(value) => write!(f, "{}", value.value().to_rfc3339()),
+        AvpValue::Integer32(value) | AvpValue::Enumerated(value) => write!(f, "{}", value.value()),
+        AvpValue::Integer64(value) => write!(f, "{}", value.value()),
+        AvpValue::Unsigned32(value) => write!(f, "{}", value.value()),
+        AvpValue::Unsigned64(value) => write!(f, "{}", value.value()),
+        AvpValue::Float32(value) => write!(f, "{}", value.value()),
+        AvpValue::Float64(value) => write!(f, "{}", value.value()),
+        AvpValue::Grouped(value) => write!(f, "{} AVPs", value.avps().len()),
+        AvpValue::Custom(value) => write!(f, "{:?}", value.value()),
+    }
+}
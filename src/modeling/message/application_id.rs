@@ -1,7 +1,7 @@
 use crate::errors::Error;
 use crate::errors::Error::DecodeError;
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ApplicationId {
     Common,
     Accounting,
@@ -0,0 +1,764 @@
+//! # Base Protocol Messages
+//!
+//! Typed RFC 6733 requests and answers, converted with [`TypedMessage`]:
+//! ```
+//! use diameter_interface::modeling::message::base::{
+//!     CapabilitiesExchangeAnswer, CapabilitiesExchangeRequest,
+//! };
+//! use diameter_interface::modeling::message::result_code::ResultCode;
+//! use diameter_interface::modeling::typed::TypedMessage;
+//! use std::net::{IpAddr, Ipv4Addr};
+//!
+//! let ip = IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1));
+//! let cer = CapabilitiesExchangeRequest::new("client.example.com", "example.com", ip, 0, "client");
+//! let message = cer.to_message(1, 1);
+//! assert!(message.is_request());
+//!
+//! let answer = CapabilitiesExchangeAnswer::new("server.example.com", "example.com", ip, 0, "server")
+//!     .to_message(1, 1);
+//! let cea = CapabilitiesExchangeAnswer::from_message(&answer)?;
+//! assert_eq!(ResultCode::from(cea.result_code), ResultCode::SUCCESS);
+//! assert_eq!(cea.origin_host, "server.example.com");
+//! # Ok::<(), diameter_interface::errors::Error>(())
+//! ```
+//! Result-Code fields hold the plain `u32` value, convert it with `ResultCode::from`. Vendor
+//! codes travel in [`ExperimentalResult`], built from a `ResultCode` with
+//! `ExperimentalResult::from_result_code`.
+//!
+//! Decoding fails with `Error::MissingAvp` when a required AVP is absent. AVPs without a field
+//! are kept in `other` so a decoded message re-encodes without loss.
+//!
+//! Session messages (RAR/RAA, ASR/ASA, STR/STA) take the header Application-Id from their
+//! Auth-Application-Id. It is optional in answers, which are built with `ApplicationId::Common`
+//! without it, so set `auth_application_id` to the one of the request.
+
+use crate::avp_enum;
+use crate::modeling::avp::avp::Avp;
+use crate::modeling::avp::diameter_uri::Uri;
+use crate::modeling::message::dictionary::Dictionary;
+use crate::modeling::message::result_code::ResultCode;
+use crate::modeling::typed::{DiameterAvp, DiameterMessage};
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::net::IpAddr;
+
+avp_enum! {
+    pub enum DisconnectCause {
+        Rebooting = 0,
+        Busy = 1,
+        DoNotWantToTalkToYou = 2,
+    }
+}
+
+avp_enum! {
+    pub enum ReAuthRequestType {
+        AuthorizeOnly = 0,
+        AuthorizeAuthenticate = 1,
+    }
+}
+
+avp_enum! {
+    pub enum TerminationCause {
+        DiameterLogout = 1,
+        DiameterServiceNotProvided = 2,
+        DiameterBadAnswer = 3,
+        DiameterAdministrative = 4,
+        DiameterLinkBroken = 5,
+        DiameterAuthExpired = 6,
+        DiameterUserMoved = 7,
+        DiameterSessionTimeout = 8,
+    }
+}
+
+avp_enum! {
+    pub enum RedirectHostUsage {
+        DontCache = 0,
+        AllSession = 1,
+        AllRealm = 2,
+        RealmAndApplication = 3,
+        AllApplication = 4,
+        AllHost = 5,
+        AllUser = 6,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, DiameterAvp)]
+pub struct VendorSpecificApplicationId {
+    #[avp(code = 266, mandatory)]
+    pub vendor_id: u32,
+    #[avp(code = 258, mandatory)]
+    pub auth_application_id: Option<u32>,
+    #[avp(code = 259, mandatory)]
+    pub acct_application_id: Option<u32>,
+}
+
+#[derive(Debug, Clone, DiameterAvp)]
+pub struct ProxyInfo {
+    #[avp(code = 280, mandatory)]
+    pub proxy_host: String,
+    #[avp(code = 33, mandatory)]
+    pub proxy_state: Vec<u8>,
+    #[avp(rest)]
+    pub other: Vec<Avp>,
+}
+
+#[derive(Debug, Clone, PartialEq, DiameterAvp)]
+pub struct ExperimentalResult {
+    #[avp(code = 266, mandatory)]
+    pub vendor_id: u32,
+    #[avp(code = 298, mandatory)]
+    pub experimental_result_code: u32,
+}
+
+impl ExperimentalResult {
+    pub fn new(vendor_id: u32, experimental_result_code: u32) -> Self {
+        Self {
+            vendor_id,
+            experimental_result_code,
+        }
+    }
+
+    /// The Experimental-Result of an experimental code, `None` for a Result-Code value.
+    pub fn from_result_code(result_code: ResultCode) -> Option<Self> {
+        let vendor_id = result_code.vendor_id()?;
+        Some(Self::new(vendor_id, result_code.value()))
+    }
+
+    pub fn result_code(&self) -> ResultCode {
+        ResultCode::experimental(self.vendor_id, self.experimental_result_code)
+    }
+}
+
+/// The offending AVPs of a failed request.
+#[derive(Debug, Clone, DiameterAvp)]
+pub struct FailedAvp {
+    #[avp(rest)]
+    pub avps: Vec<Avp>,
+}
+
+#[derive(Debug, Clone, DiameterMessage)]
+#[diameter(command = CapabilitiesExchange, application = Common, request)]
+pub struct CapabilitiesExchangeRequest {
+    #[avp(code = 264, mandatory)]
+    pub origin_host: String,
+    #[avp(code = 296, mandatory)]
+    pub origin_realm: String,
+    #[avp(code = 257, mandatory, min = 1)]
+    pub host_ip_address: Vec<IpAddr>,
+    #[avp(code = 266, mandatory)]
+    pub vendor_id: u32,
+    #[avp(code = 269)]
+    pub product_name: String,
+    #[avp(code = 278, mandatory)]
+    pub origin_state_id: Option<u32>,
+    #[avp(code = 265, mandatory)]
+    pub supported_vendor_id: Vec<u32>,
+    #[avp(code = 258, mandatory)]
+    pub auth_application_id: Vec<u32>,
+    #[avp(code = 299, mandatory)]
+    pub inband_security_id: Vec<u32>,
+    #[avp(code = 259, mandatory)]
+    pub acct_application_id: Vec<u32>,
+    #[avp(code = 260, mandatory)]
+    pub vendor_specific_application_id: Vec<VendorSpecificApplicationId>,
+    #[avp(code = 267)]
+    pub firmware_revision: Option<u32>,
+    #[avp(rest)]
+    pub other: Vec<Avp>,
+}
+
+#[derive(Debug, Clone, DiameterMessage)]
+#[diameter(command = CapabilitiesExchange, application = Common)]
+pub struct CapabilitiesExchangeAnswer {
+    #[avp(code = 268, mandatory)]
+    pub result_code: u32,
+    #[avp(code = 264, mandatory)]
+    pub origin_host: String,
+    #[avp(code = 296, mandatory)]
+    pub origin_realm: String,
+    #[avp(code = 257, mandatory, min = 1)]
+    pub host_ip_address: Vec<IpAddr>,
+    #[avp(code = 266, mandatory)]
+    pub vendor_id: u32,
+    #[avp(code = 269)]
+    pub product_name: String,
+    #[avp(code = 278, mandatory)]
+    pub origin_state_id: Option<u32>,
+    #[avp(code = 281)]
+    pub error_message: Option<String>,
+    #[avp(code = 279, mandatory)]
+    pub failed_avp: Option<FailedAvp>,
+    #[avp(code = 265, mandatory)]
+    pub supported_vendor_id: Vec<u32>,
+    #[avp(code = 258, mandatory)]
+    pub auth_application_id: Vec<u32>,
+    #[avp(code = 299, mandatory)]
+    pub inband_security_id: Vec<u32>,
+    #[avp(code = 259, mandatory)]
+    pub acct_application_id: Vec<u32>,
+    #[avp(code = 260, mandatory)]
+    pub vendor_specific_application_id: Vec<VendorSpecificApplicationId>,
+    #[avp(code = 267)]
+    pub firmware_revision: Option<u32>,
+    #[avp(rest)]
+    pub other: Vec<Avp>,
+}
+
+#[derive(Debug, Clone, DiameterMessage)]
+#[diameter(command = DeviceWatchdog, application = Common, request)]
+pub struct DeviceWatchdogRequest {
+    #[avp(code = 264, mandatory)]
+    pub origin_host: String,
+    #[avp(code = 296, mandatory)]
+    pub origin_realm: String,
+    #[avp(code = 278, mandatory)]
+    pub origin_state_id: Option<u32>,
+    #[avp(rest)]
+    pub other: Vec<Avp>,
+}
+
+#[derive(Debug, Clone, DiameterMessage)]
+#[diameter(command = DeviceWatchdog, application = Common)]
+pub struct DeviceWatchdogAnswer {
+    #[avp(code = 268, mandatory)]
+    pub result_code: u32,
+    #[avp(code = 264, mandatory)]
+    pub origin_host: String,
+    #[avp(code = 296, mandatory)]
+    pub origin_realm: String,
+    #[avp(code = 281)]
+    pub error_message: Option<String>,
+    #[avp(code = 279, mandatory)]
+    pub failed_avp: Option<FailedAvp>,
+    #[avp(code = 278, mandatory)]
+    pub origin_state_id: Option<u32>,
+    #[avp(rest)]
+    pub other: Vec<Avp>,
+}
+
+#[derive(Debug, Clone, DiameterMessage)]
+#[diameter(command = DisconnectPeer, application = Common, request)]
+pub struct DisconnectPeerRequest {
+    #[avp(code = 264, mandatory)]
+    pub origin_host: String,
+    #[avp(code = 296, mandatory)]
+    pub origin_realm: String,
+    #[avp(code = 273, mandatory)]
+    pub disconnect_cause: Option<DisconnectCause>,
+    #[avp(rest)]
+    pub other: Vec<Avp>,
+}
+
+#[derive(Debug, Clone, DiameterMessage)]
+#[diameter(command = DisconnectPeer, application = Common)]
+pub struct DisconnectPeerAnswer {
+    #[avp(code = 268, mandatory)]
+    pub result_code: u32,
+    #[avp(code = 264, mandatory)]
+    pub origin_host: String,
+    #[avp(code = 296, mandatory)]
+    pub origin_realm: String,
+    #[avp(code = 281)]
+    pub error_message: Option<String>,
+    #[avp(code = 279, mandatory)]
+    pub failed_avp: Option<FailedAvp>,
+    #[avp(rest)]
+    pub other: Vec<Avp>,
+}
+
+#[derive(Debug, Clone, DiameterMessage)]
+#[diameter(
+    command = ReAuth,
+    application = Common,
+    application_field = auth_application_id,
+    request,
+    proxyable
+)]
+pub struct ReAuthRequest {
+    #[avp(code = 263, mandatory)]
+    pub session_id: String,
+    #[avp(code = 264, mandatory)]
+    pub origin_host: String,
+    #[avp(code = 296, mandatory)]
+    pub origin_realm: String,
+    #[avp(code = 283, mandatory)]
+    pub destination_realm: String,
+    #[avp(code = 293, mandatory)]
+    pub destination_host: String,
+    #[avp(code = 258, mandatory)]
+    pub auth_application_id: u32,
+    #[avp(code = 285, mandatory)]
+    pub re_auth_request_type: ReAuthRequestType,
+    #[avp(code = 1, mandatory)]
+    pub user_name: Option<String>,
+    #[avp(code = 278, mandatory)]
+    pub origin_state_id: Option<u32>,
+    #[avp(code = 284, mandatory)]
+    pub proxy_info: Vec<ProxyInfo>,
+    #[avp(code = 282, mandatory)]
+    pub route_record: Vec<String>,
+    #[avp(rest)]
+    pub other: Vec<Avp>,
+}
+
+#[derive(Debug, Clone, DiameterMessage)]
+#[diameter(
+    command = ReAuth,
+    application = Common,
+    application_field = auth_application_id,
+    proxyable
+)]
+pub struct ReAuthAnswer {
+    #[avp(code = 263, mandatory)]
+    pub session_id: String,
+    #[avp(code = 268, mandatory)]
+    pub result_code: u32,
+    #[avp(code = 264, mandatory)]
+    pub origin_host: String,
+    #[avp(code = 296, mandatory)]
+    pub origin_realm: String,
+    #[avp(code = 258, mandatory)]
+    pub auth_application_id: Option<u32>,
+    #[avp(code = 1, mandatory)]
+    pub user_name: Option<String>,
+    #[avp(code = 278, mandatory)]
+    pub origin_state_id: Option<u32>,
+    #[avp(code = 281)]
+    pub error_message: Option<String>,
+    #[avp(code = 294)]
+    pub error_reporting_host: Option<String>,
+    #[avp(code = 279, mandatory)]
+    pub failed_avp: Option<FailedAvp>,
+    #[avp(code = 292, mandatory)]
+    pub redirect_host: Vec<Uri>,
+    #[avp(code = 261, mandatory)]
+    pub redirect_host_usage: Option<RedirectHostUsage>,
+    #[avp(code = 262, mandatory)]
+    pub redirect_max_cache_time: Option<u32>,
+    #[avp(code = 284, mandatory)]
+    pub proxy_info: Vec<ProxyInfo>,
+    #[avp(rest)]
+    pub other: Vec<Avp>,
+}
+
+#[derive(Debug, Clone, DiameterMessage)]
+#[diameter(
+    command = AbortSession,
+    application = Common,
+    application_field = auth_application_id,
+    request,
+    proxyable
+)]
+pub struct AbortSessionRequest {
+    #[avp(code = 263, mandatory)]
+    pub session_id: String,
+    #[avp(code = 264, mandatory)]
+    pub origin_host: String,
+    #[avp(code = 296, mandatory)]
+    pub origin_realm: String,
+    #[avp(code = 283, mandatory)]
+    pub destination_realm: String,
+    #[avp(code = 293, mandatory)]
+    pub destination_host: String,
+    #[avp(code = 258, mandatory)]
+    pub auth_application_id: u32,
+    #[avp(code = 1, mandatory)]
+    pub user_name: Option<String>,
+    #[avp(code = 278, mandatory)]
+    pub origin_state_id: Option<u32>,
+    #[avp(code = 284, mandatory)]
+    pub proxy_info: Vec<ProxyInfo>,
+    #[avp(code = 282, mandatory)]
+    pub route_record: Vec<String>,
+    #[avp(rest)]
+    pub other: Vec<Avp>,
+}
+
+#[derive(Debug, Clone, DiameterMessage)]
+#[diameter(
+    command = AbortSession,
+    application = Common,
+    application_field = auth_application_id,
+    proxyable
+)]
+pub struct AbortSessionAnswer {
+    #[avp(code = 263, mandatory)]
+    pub session_id: String,
+    #[avp(code = 268, mandatory)]
+    pub result_code: u32,
+    #[avp(code = 264, mandatory)]
+    pub origin_host: String,
+    #[avp(code = 296, mandatory)]
+    pub origin_realm: String,
+    #[avp(code = 258, mandatory)]
+    pub auth_application_id: Option<u32>,
+    #[avp(code = 1, mandatory)]
+    pub user_name: Option<String>,
+    #[avp(code = 278, mandatory)]
+    pub origin_state_id: Option<u32>,
+    #[avp(code = 281)]
+    pub error_message: Option<String>,
+    #[avp(code = 294)]
+    pub error_reporting_host: Option<String>,
+    #[avp(code = 279, mandatory)]
+    pub failed_avp: Option<FailedAvp>,
+    #[avp(code = 292, mandatory)]
+    pub redirect_host: Vec<Uri>,
+    #[avp(code = 261, mandatory)]
+    pub redirect_host_usage: Option<RedirectHostUsage>,
+    #[avp(code = 262, mandatory)]
+    pub redirect_max_cache_time: Option<u32>,
+    #[avp(code = 284, mandatory)]
+    pub proxy_info: Vec<ProxyInfo>,
+    #[avp(rest)]
+    pub other: Vec<Avp>,
+}
+
+#[derive(Debug, Clone, DiameterMessage)]
+#[diameter(
+    command = SessionTermination,
+    application = Common,
+    application_field = auth_application_id,
+    request,
+    proxyable
+)]
+pub struct SessionTerminationRequest {
+    #[avp(code = 263, mandatory)]
+    pub session_id: String,
+    #[avp(code = 264, mandatory)]
+    pub origin_host: String,
+    #[avp(code = 296, mandatory)]
+    pub origin_realm: String,
+    #[avp(code = 283, mandatory)]
+    pub destination_realm: String,
+    #[avp(code = 258, mandatory)]
+    pub auth_application_id: u32,
+    #[avp(code = 295, mandatory)]
+    pub termination_cause: TerminationCause,
+    #[avp(code = 1, mandatory)]
+    pub user_name: Option<String>,
+    #[avp(code = 293, mandatory)]
+    pub destination_host: Option<String>,
+    #[avp(code = 25, mandatory)]
+    pub class: Vec<Vec<u8>>,
+    #[avp(code = 278, mandatory)]
+    pub origin_state_id: Option<u32>,
+    #[avp(code = 284, mandatory)]
+    pub proxy_info: Vec<ProxyInfo>,
+    #[avp(code = 282, mandatory)]
+    pub route_record: Vec<String>,
+    #[avp(rest)]
+    pub other: Vec<Avp>,
+}
+
+#[derive(Debug, Clone, DiameterMessage)]
+#[diameter(
+    command = SessionTermination,
+    application = Common,
+    application_field = auth_application_id,
+    proxyable
+)]
+pub struct SessionTerminationAnswer {
+    #[avp(code = 263, mandatory)]
+    pub session_id: String,
+    #[avp(code = 268, mandatory)]
+    pub result_code: u32,
+    #[avp(code = 264, mandatory)]
+    pub origin_host: String,
+    #[avp(code = 296, mandatory)]
+    pub origin_realm: String,
+    #[avp(code = 258, mandatory)]
+    pub auth_application_id: Option<u32>,
+    #[avp(code = 1, mandatory)]
+    pub user_name: Option<String>,
+    #[avp(code = 25, mandatory)]
+    pub class: Vec<Vec<u8>>,
+    #[avp(code = 281)]
+    pub error_message: Option<String>,
+    #[avp(code = 294)]
+    pub error_reporting_host: Option<String>,
+    #[avp(code = 279, mandatory)]
+    pub failed_avp: Option<FailedAvp>,
+    #[avp(code = 278, mandatory)]
+    pub origin_state_id: Option<u32>,
+    #[avp(code = 292, mandatory)]
+    pub redirect_host: Vec<Uri>,
+    #[avp(code = 261, mandatory)]
+    pub redirect_host_usage: Option<RedirectHostUsage>,
+    #[avp(code = 262, mandatory)]
+    pub redirect_max_cache_time: Option<u32>,
+    #[avp(code = 284, mandatory)]
+    pub proxy_info: Vec<ProxyInfo>,
+    #[avp(rest)]
+    pub other: Vec<Avp>,
+}
+
+impl CapabilitiesExchangeRequest {
+    pub fn new(
+        origin_host: &str,
+        origin_realm: &str,
+        host_ip_address: IpAddr,
+        vendor_id: u32,
+        product_name: &str,
+    ) -> Self {
+        Self {
+            origin_host: origin_host.to_string(),
+            origin_realm: origin_realm.to_string(),
+            host_ip_address: vec![host_ip_address],
+            vendor_id,
+            product_name: product_name.to_string(),
+            origin_state_id: None,
+            supported_vendor_id: vec![],
+            auth_application_id: vec![],
+            inband_security_id: vec![],
+            acct_application_id: vec![],
+            vendor_specific_application_id: vec![],
+            firmware_revision: None,
+            other: vec![],
+        }
+    }
+
+    /// Advertises every vendor known to the dictionary in Supported-Vendor-Id.
+    pub fn with_supported_vendor_ids(mut self, dict: &Dictionary) -> Self {
+        self.supported_vendor_id = dict.vendors().map(|(vendor_id, _)| vendor_id).collect();
+        self
+    }
+}
+
+impl CapabilitiesExchangeAnswer {
+    /// A DIAMETER_SUCCESS answer.
+    pub fn new(
+        origin_host: &str,
+        origin_realm: &str,
+        host_ip_address: IpAddr,
+        vendor_id: u32,
+        product_name: &str,
+    ) -> Self {
+        Self {
+            result_code: ResultCode::SUCCESS.value(),
+            origin_host: origin_host.to_string(),
+            origin_realm: origin_realm.to_string(),
+            host_ip_address: vec![host_ip_address],
+            vendor_id,
+            product_name: product_name.to_string(),
+            origin_state_id: None,
+            error_message: None,
+            failed_avp: None,
+            supported_vendor_id: vec![],
+            auth_application_id: vec![],
+            inband_security_id: vec![],
+            acct_application_id: vec![],
+            vendor_specific_application_id: vec![],
+            firmware_revision: None,
+            other: vec![],
+        }
+    }
+
+    /// Advertises every vendor known to the dictionary in Supported-Vendor-Id.
+    pub fn with_supported_vendor_ids(mut self, dict: &Dictionary) -> Self {
+        self.supported_vendor_id = dict.vendors().map(|(vendor_id, _)| vendor_id).collect();
+        self
+    }
+}
+
+impl DeviceWatchdogRequest {
+    pub fn new(origin_host: &str, origin_realm: &str) -> Self {
+        Self {
+            origin_host: origin_host.to_string(),
+            origin_realm: origin_realm.to_string(),
+            origin_state_id: None,
+            other: vec![],
+        }
+    }
+}
+
+impl DeviceWatchdogAnswer {
+    /// A DIAMETER_SUCCESS answer.
+    pub fn new(origin_host: &str, origin_realm: &str) -> Self {
+        Self {
+            result_code: ResultCode::SUCCESS.value(),
+            origin_host: origin_host.to_string(),
+            origin_realm: origin_realm.to_string(),
+            error_message: None,
+            failed_avp: None,
+            origin_state_id: None,
+            other: vec![],
+        }
+    }
+}
+
+impl DisconnectPeerRequest {
+    /// A request with Disconnect-Cause REBOOTING.
+    pub fn new(origin_host: &str, origin_realm: &str) -> Self {
+        Self {
+            origin_host: origin_host.to_string(),
+            origin_realm: origin_realm.to_string(),
+            disconnect_cause: Some(DisconnectCause::Rebooting),
+            other: vec![],
+        }
+    }
+}
+
+impl DisconnectPeerAnswer {
+    /// A DIAMETER_SUCCESS answer.
+    pub fn new(origin_host: &str, origin_realm: &str) -> Self {
+        Self {
+            result_code: ResultCode::SUCCESS.value(),
+            origin_host: origin_host.to_string(),
+            origin_realm: origin_realm.to_string(),
+            error_message: None,
+            failed_avp: None,
+            other: vec![],
+        }
+    }
+}
+
+impl ReAuthRequest {
+    /// A request with Re-Auth-Request-Type AUTHORIZE_ONLY.
+    pub fn new(
+        session_id: &str,
+        origin_host: &str,
+        origin_realm: &str,
+        destination_host: &str,
+        destination_realm: &str,
+        auth_application_id: u32,
+    ) -> Self {
+        Self {
+            session_id: session_id.to_string(),
+            origin_host: origin_host.to_string(),
+            origin_realm: origin_realm.to_string(),
+            destination_realm: destination_realm.to_string(),
+            destination_host: destination_host.to_string(),
+            auth_application_id,
+            re_auth_request_type: ReAuthRequestType::AuthorizeOnly,
+            user_name: None,
+            origin_state_id: None,
+            proxy_info: vec![],
+            route_record: vec![],
+            other: vec![],
+        }
+    }
+}
+
+impl ReAuthAnswer {
+    /// A DIAMETER_SUCCESS answer.
+    pub fn new(session_id: &str, origin_host: &str, origin_realm: &str) -> Self {
+        Self {
+            session_id: session_id.to_string(),
+            result_code: ResultCode::SUCCESS.value(),
+            origin_host: origin_host.to_string(),
+            origin_realm: origin_realm.to_string(),
+            auth_application_id: None,
+            user_name: None,
+            origin_state_id: None,
+            error_message: None,
+            error_reporting_host: None,
+            failed_avp: None,
+            redirect_host: vec![],
+            redirect_host_usage: None,
+            redirect_max_cache_time: None,
+            proxy_info: vec![],
+            other: vec![],
+        }
+    }
+}
+
+impl AbortSessionRequest {
+    pub fn new(
+        session_id: &str,
+        origin_host: &str,
+        origin_realm: &str,
+        destination_host: &str,
+        destination_realm: &str,
+        auth_application_id: u32,
+    ) -> Self {
+        Self {
+            session_id: session_id.to_string(),
+            origin_host: origin_host.to_string(),
+            origin_realm: origin_realm.to_string(),
+            destination_realm: destination_realm.to_string(),
+            destination_host: destination_host.to_string(),
+            auth_application_id,
+            user_name: None,
+            origin_state_id: None,
+            proxy_info: vec![],
+            route_record: vec![],
+            other: vec![],
+        }
+    }
+}
+
+impl AbortSessionAnswer {
+    /// A DIAMETER_SUCCESS answer.
+    pub fn new(session_id: &str, origin_host: &str, origin_realm: &str) -> Self {
+        Self {
+            session_id: session_id.to_string(),
+            result_code: ResultCode::SUCCESS.value(),
+            origin_host: origin_host.to_string(),
+            origin_realm: origin_realm.to_string(),
+            auth_application_id: None,
+            user_name: None,
+            origin_state_id: None,
+            error_message: None,
+            error_reporting_host: None,
+            failed_avp: None,
+            redirect_host: vec![],
+            redirect_host_usage: None,
+            redirect_max_cache_time: None,
+            proxy_info: vec![],
+            other: vec![],
+        }
+    }
+}
+
+impl SessionTerminationRequest {
+    /// A request with Termination-Cause DIAMETER_LOGOUT.
+    pub fn new(
+        session_id: &str,
+        origin_host: &str,
+        origin_realm: &str,
+        destination_realm: &str,
+        auth_application_id: u32,
+    ) -> Self {
+        Self {
+            session_id: session_id.to_string(),
+            origin_host: origin_host.to_string(),
+            origin_realm: origin_realm.to_string(),
+            destination_realm: destination_realm.to_string(),
+            auth_application_id,
+            termination_cause: TerminationCause::DiameterLogout,
+            user_name: None,
+            destination_host: None,
+            class: vec![],
+            origin_state_id: None,
+            proxy_info: vec![],
+            route_record: vec![],
+            other: vec![],
+        }
+    }
+}
+
+impl SessionTerminationAnswer {
+    /// A DIAMETER_SUCCESS answer.
+    pub fn new(session_id: &str, origin_host: &str, origin_realm: &str) -> Self {
+        Self {
+            session_id: session_id.to_string(),
+            result_code: ResultCode::SUCCESS.value(),
+            origin_host: origin_host.to_string(),
+            origin_realm: origin_realm.to_string(),
+            auth_application_id: None,
+            user_name: None,
+            class: vec![],
+            error_message: None,
+            error_reporting_host: None,
+            failed_avp: None,
+            origin_state_id: None,
+            redirect_host: vec![],
+            redirect_host_usage: None,
+            redirect_max_cache_time: None,
+            proxy_info: vec![],
+            other: vec![],
+        }
+    }
+}
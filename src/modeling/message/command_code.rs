@@ -1,7 +1,7 @@
 use crate::errors::Error;
 use crate::errors::Error::DecodeError;
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum CommandCode {
     CapabilitiesExchange,
     ReAuth,
@@ -26,6 +26,20 @@ impl CommandCode {
             CommandCode::DisconnectPeer => 282,
         }
     }
+
+    /// The abbreviation of the command, completed by `R` or `A` as in `CCR` and `CCA`.
+    pub fn abbreviation(&self) -> &'static str {
+        match self {
+            CommandCode::CapabilitiesExchange => "CE",
+            CommandCode::ReAuth => "RA",
+            CommandCode::Accounting => "AC",
+            CommandCode::CreditControl => "CC",
+            CommandCode::AbortSession => "AS",
+            CommandCode::SessionTermination => "ST",
+            CommandCode::DeviceWatchdog => "DW",
+            CommandCode::DisconnectPeer => "DP",
+        }
+    }
 }
 
 impl TryFrom<u32> for CommandCode {
@@ -0,0 +1,630 @@
+//! # Credit-Control Messages
+//!
+//! Typed RFC 4006 Credit-Control-Request and Credit-Control-Answer:
+//! ```
+//! use diameter_interface::modeling::message::application_id::ApplicationId;
+//! use diameter_interface::modeling::message::credit_control::{
+//!     CcRequestType, CreditControlAnswer, CreditControlRequest, MultipleServicesCreditControl,
+//!     RequestedServiceUnit, SubscriptionId, SubscriptionIdType,
+//! };
+//! use diameter_interface::modeling::typed::TypedMessage;
+//!
+//! let mut ccr = CreditControlRequest::new(
+//!     "host.example.com;1;1", "host.example.com", "example.com", "ocs.example.com",
+//!     4, "32251@3gpp.org",
+//! );
+//! ccr.subscription_id.push(SubscriptionId::new(SubscriptionIdType::EndUserImsi, "001010123456789"));
+//! ccr.multiple_services_credit_control.push(MultipleServicesCreditControl::requesting(
+//!     RequestedServiceUnit::default(),
+//! ));
+//! let message = ccr.to_message(1, 1);
+//! assert_eq!(message.application_id(), ApplicationId::CreditControl);
+//!
+//! let mut cca = CreditControlAnswer::new(
+//!     &ccr.session_id, "ocs.example.com", "example.com", ccr.cc_request_type, ccr.cc_request_number,
+//! );
+//! cca.auth_application_id = Some(ccr.auth_application_id);
+//! let answer = cca.to_message(1, 1);
+//! assert_eq!(answer.application_id(), ApplicationId::CreditControl);
+//!
+//! let cca = CreditControlAnswer::from_message(&answer)?;
+//! let update = ccr.next_request(CcRequestType::UpdateRequest);
+//! assert_eq!(update.cc_request_number, cca.cc_request_number + 1);
+//! # Ok::<(), diameter_interface::errors::Error>(())
+//! ```
+//! Both messages take the header Application-Id from Auth-Application-Id and use
+//! Credit-Control (4) when it is absent or unknown. Enumerated AVPs keep values outside their
+//! enum as `Unknown`.
+//!
+//! Every struct keeps the AVPs it does not model in `other`, so decoding and re-encoding a
+//! message keeps all of its AVPs. Unmodelled AVPs are re-encoded after the modelled ones.
+
+use crate::avp_enum;
+use crate::modeling::avp::avp::Avp;
+use crate::modeling::avp::diameter_uri::Uri;
+use crate::modeling::message::base::{
+    ExperimentalResult, FailedAvp, ProxyInfo, RedirectHostUsage, TerminationCause,
+};
+use crate::modeling::message::result_code::ResultCode;
+use crate::modeling::typed::{DiameterAvp, DiameterMessage};
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use chrono::{DateTime, Utc};
+
+avp_enum! {
+    pub enum CcRequestType {
+        InitialRequest = 1,
+        UpdateRequest = 2,
+        TerminationRequest = 3,
+        EventRequest = 4,
+    }
+}
+
+avp_enum! {
+    pub enum SubscriptionIdType {
+        EndUserE164 = 0,
+        EndUserImsi = 1,
+        EndUserSipUri = 2,
+        EndUserNai = 3,
+        EndUserPrivate = 4,
+    }
+}
+
+avp_enum! {
+    pub enum RequestedAction {
+        DirectDebiting = 0,
+        RefundAccount = 1,
+        CheckBalance = 2,
+        PriceEnquiry = 3,
+    }
+}
+
+avp_enum! {
+    pub enum MultipleServicesIndicator {
+        MultipleServicesNotSupported = 0,
+        MultipleServicesSupported = 1,
+    }
+}
+
+avp_enum! {
+    pub enum FinalUnitAction {
+        Terminate = 0,
+        Redirect = 1,
+        RestrictAccess = 2,
+    }
+}
+
+avp_enum! {
+    pub enum RedirectAddressType {
+        Ipv4Address = 0,
+        Ipv6Address = 1,
+        Url = 2,
+        SipUri = 3,
+    }
+}
+
+avp_enum! {
+    pub enum TariffChangeUsage {
+        UnitBeforeTariffChange = 0,
+        UnitAfterTariffChange = 1,
+        UnitIndeterminate = 2,
+    }
+}
+
+avp_enum! {
+    pub enum CcUnitType {
+        Time = 0,
+        Money = 1,
+        TotalOctets = 2,
+        InputOctets = 3,
+        OutputOctets = 4,
+        ServiceSpecificUnits = 5,
+    }
+}
+
+avp_enum! {
+    pub enum CcSessionFailover {
+        FailoverNotSupported = 0,
+        FailoverSupported = 1,
+    }
+}
+
+avp_enum! {
+    pub enum CheckBalanceResult {
+        EnoughCredit = 0,
+        NoCredit = 1,
+    }
+}
+
+avp_enum! {
+    pub enum CreditControlFailureHandling {
+        Terminate = 0,
+        Continue = 1,
+        RetryAndTerminate = 2,
+    }
+}
+
+avp_enum! {
+    pub enum DirectDebitingFailureHandling {
+        TerminateOrBuffer = 0,
+        Continue = 1,
+    }
+}
+
+avp_enum! {
+    pub enum UserEquipmentInfoType {
+        Imeisv = 0,
+        Mac = 1,
+        Eui64 = 2,
+        ModifiedEui64 = 3,
+    }
+}
+
+#[derive(Debug, Clone, DiameterAvp)]
+pub struct SubscriptionId {
+    #[avp(code = 450, mandatory)]
+    pub subscription_id_type: SubscriptionIdType,
+    #[avp(code = 444, mandatory)]
+    pub subscription_id_data: String,
+    #[avp(rest)]
+    pub other: Vec<Avp>,
+}
+
+/// A decimal value of `value_digits * 10^exponent`.
+#[derive(Debug, Clone, DiameterAvp)]
+pub struct UnitValue {
+    #[avp(code = 447, mandatory)]
+    pub value_digits: i64,
+    #[avp(code = 429, mandatory)]
+    pub exponent: Option<i32>,
+    #[avp(rest)]
+    pub other: Vec<Avp>,
+}
+
+#[derive(Debug, Clone, DiameterAvp)]
+pub struct CcMoney {
+    #[avp(code = 445, mandatory)]
+    pub unit_value: UnitValue,
+    #[avp(code = 425, mandatory)]
+    pub currency_code: Option<u32>,
+    #[avp(rest)]
+    pub other: Vec<Avp>,
+}
+
+#[derive(Debug, Clone, Default, DiameterAvp)]
+pub struct RequestedServiceUnit {
+    #[avp(code = 420, mandatory)]
+    pub cc_time: Option<u32>,
+    #[avp(code = 413, mandatory)]
+    pub cc_money: Option<CcMoney>,
+    #[avp(code = 421, mandatory)]
+    pub cc_total_octets: Option<u64>,
+    #[avp(code = 412, mandatory)]
+    pub cc_input_octets: Option<u64>,
+    #[avp(code = 414, mandatory)]
+    pub cc_output_octets: Option<u64>,
+    #[avp(code = 417, mandatory)]
+    pub cc_service_specific_units: Option<u64>,
+    #[avp(rest)]
+    pub other: Vec<Avp>,
+}
+
+#[derive(Debug, Clone, Default, DiameterAvp)]
+pub struct GrantedServiceUnit {
+    #[avp(code = 451, mandatory)]
+    pub tariff_time_change: Option<DateTime<Utc>>,
+    #[avp(code = 420, mandatory)]
+    pub cc_time: Option<u32>,
+    #[avp(code = 413, mandatory)]
+    pub cc_money: Option<CcMoney>,
+    #[avp(code = 421, mandatory)]
+    pub cc_total_octets: Option<u64>,
+    #[avp(code = 412, mandatory)]
+    pub cc_input_octets: Option<u64>,
+    #[avp(code = 414, mandatory)]
+    pub cc_output_octets: Option<u64>,
+    #[avp(code = 417, mandatory)]
+    pub cc_service_specific_units: Option<u64>,
+    #[avp(rest)]
+    pub other: Vec<Avp>,
+}
+
+#[derive(Debug, Clone, Default, DiameterAvp)]
+pub struct UsedServiceUnit {
+    #[avp(code = 452, mandatory)]
+    pub tariff_change_usage: Option<TariffChangeUsage>,
+    #[avp(code = 420, mandatory)]
+    pub cc_time: Option<u32>,
+    #[avp(code = 413, mandatory)]
+    pub cc_money: Option<CcMoney>,
+    #[avp(code = 421, mandatory)]
+    pub cc_total_octets: Option<u64>,
+    #[avp(code = 412, mandatory)]
+    pub cc_input_octets: Option<u64>,
+    #[avp(code = 414, mandatory)]
+    pub cc_output_octets: Option<u64>,
+    #[avp(code = 417, mandatory)]
+    pub cc_service_specific_units: Option<u64>,
+    #[avp(rest)]
+    pub other: Vec<Avp>,
+}
+
+#[derive(Debug, Clone, DiameterAvp)]
+pub struct RedirectServer {
+    #[avp(code = 433, mandatory)]
+    pub redirect_address_type: RedirectAddressType,
+    #[avp(code = 435, mandatory)]
+    pub redirect_server_address: String,
+    #[avp(rest)]
+    pub other: Vec<Avp>,
+}
+
+#[derive(Debug, Clone, DiameterAvp)]
+pub struct FinalUnitIndication {
+    #[avp(code = 449, mandatory)]
+    pub final_unit_action: FinalUnitAction,
+    #[avp(code = 438, mandatory)]
+    pub restriction_filter_rule: Vec<Vec<u8>>,
+    #[avp(code = 11, mandatory)]
+    pub filter_id: Vec<String>,
+    #[avp(code = 434, mandatory)]
+    pub redirect_server: Option<RedirectServer>,
+    #[avp(rest)]
+    pub other: Vec<Avp>,
+}
+
+#[derive(Debug, Clone, DiameterAvp)]
+pub struct GsuPoolReference {
+    #[avp(code = 453, mandatory)]
+    pub g_s_u_pool_identifier: u32,
+    #[avp(code = 454, mandatory)]
+    pub cc_unit_type: CcUnitType,
+    #[avp(code = 445, mandatory)]
+    pub unit_value: UnitValue,
+    #[avp(rest)]
+    pub other: Vec<Avp>,
+}
+
+#[derive(Debug, Clone, Default, DiameterAvp)]
+pub struct MultipleServicesCreditControl {
+    #[avp(code = 431, mandatory)]
+    pub granted_service_unit: Option<GrantedServiceUnit>,
+    #[avp(code = 437, mandatory)]
+    pub requested_service_unit: Option<RequestedServiceUnit>,
+    #[avp(code = 446, mandatory)]
+    pub used_service_unit: Vec<UsedServiceUnit>,
+    #[avp(code = 452, mandatory)]
+    pub tariff_change_usage: Option<TariffChangeUsage>,
+    #[avp(code = 439, mandatory)]
+    pub service_identifier: Vec<u32>,
+    #[avp(code = 432, mandatory)]
+    pub rating_group: Option<u32>,
+    #[avp(code = 457, mandatory)]
+    pub g_s_u_pool_reference: Vec<GsuPoolReference>,
+    #[avp(code = 448, mandatory)]
+    pub validity_time: Option<u32>,
+    #[avp(code = 268, mandatory)]
+    pub result_code: Option<u32>,
+    #[avp(code = 430, mandatory)]
+    pub final_unit_indication: Option<FinalUnitIndication>,
+    #[avp(rest)]
+    pub other: Vec<Avp>,
+}
+
+#[derive(Debug, Clone, DiameterAvp)]
+pub struct CostInformation {
+    #[avp(code = 445, mandatory)]
+    pub unit_value: UnitValue,
+    #[avp(code = 425, mandatory)]
+    pub currency_code: u32,
+    #[avp(code = 424, mandatory)]
+    pub cost_unit: Option<String>,
+    #[avp(rest)]
+    pub other: Vec<Avp>,
+}
+
+#[derive(Debug, Clone, DiameterAvp)]
+pub struct ServiceParameterInfo {
+    #[avp(code = 441)]
+    pub service_parameter_type: u32,
+    #[avp(code = 442)]
+    pub service_parameter_value: Vec<u8>,
+    #[avp(rest)]
+    pub other: Vec<Avp>,
+}
+
+#[derive(Debug, Clone, DiameterAvp)]
+pub struct UserEquipmentInfo {
+    #[avp(code = 459)]
+    pub user_equipment_info_type: UserEquipmentInfoType,
+    #[avp(code = 460)]
+    pub user_equipment_info_value: Vec<u8>,
+    #[avp(rest)]
+    pub other: Vec<Avp>,
+}
+
+#[derive(Debug, Clone, DiameterMessage)]
+#[diameter(
+    command = CreditControl,
+    application = CreditControl,
+    application_field = auth_application_id,
+    request,
+    proxyable
+)]
+pub struct CreditControlRequest {
+    #[avp(code = 263, mandatory)]
+    pub session_id: String,
+    #[avp(code = 264, mandatory)]
+    pub origin_host: String,
+    #[avp(code = 296, mandatory)]
+    pub origin_realm: String,
+    #[avp(code = 283, mandatory)]
+    pub destination_realm: String,
+    #[avp(code = 258, mandatory)]
+    pub auth_application_id: u32,
+    #[avp(code = 461, mandatory)]
+    pub service_context_id: String,
+    #[avp(code = 416, mandatory)]
+    pub cc_request_type: CcRequestType,
+    #[avp(code = 415, mandatory)]
+    pub cc_request_number: u32,
+    #[avp(code = 293, mandatory)]
+    pub destination_host: Option<String>,
+    #[avp(code = 1, mandatory)]
+    pub user_name: Option<String>,
+    #[avp(code = 419, mandatory)]
+    pub cc_sub_session_id: Option<u64>,
+    #[avp(code = 50, mandatory)]
+    pub acct_multi_session_id: Option<String>,
+    #[avp(code = 278, mandatory)]
+    pub origin_state_id: Option<u32>,
+    #[avp(code = 55, mandatory)]
+    pub event_timestamp: Option<DateTime<Utc>>,
+    #[avp(code = 443, mandatory)]
+    pub subscription_id: Vec<SubscriptionId>,
+    #[avp(code = 439, mandatory)]
+    pub service_identifier: Option<u32>,
+    #[avp(code = 295, mandatory)]
+    pub termination_cause: Option<TerminationCause>,
+    #[avp(code = 437, mandatory)]
+    pub requested_service_unit: Option<RequestedServiceUnit>,
+    #[avp(code = 436, mandatory)]
+    pub requested_action: Option<RequestedAction>,
+    #[avp(code = 446, mandatory)]
+    pub used_service_unit: Vec<UsedServiceUnit>,
+    #[avp(code = 455, mandatory)]
+    pub multiple_services_indicator: Option<MultipleServicesIndicator>,
+    #[avp(code = 456, mandatory)]
+    pub multiple_services_credit_control: Vec<MultipleServicesCreditControl>,
+    #[avp(code = 440)]
+    pub service_parameter_info: Vec<ServiceParameterInfo>,
+    #[avp(code = 411)]
+    pub cc_correlation_id: Option<Vec<u8>>,
+    #[avp(code = 458)]
+    pub user_equipment_info: Option<UserEquipmentInfo>,
+    #[avp(code = 284, mandatory)]
+    pub proxy_info: Vec<ProxyInfo>,
+    #[avp(code = 282, mandatory)]
+    pub route_record: Vec<String>,
+    #[avp(rest)]
+    pub other: Vec<Avp>,
+}
+
+#[derive(Debug, Clone, DiameterMessage)]
+#[diameter(
+    command = CreditControl,
+    application = CreditControl,
+    application_field = auth_application_id,
+    proxyable
+)]
+pub struct CreditControlAnswer {
+    #[avp(code = 263, mandatory)]
+    pub session_id: String,
+    #[avp(code = 268, mandatory)]
+    pub result_code: Option<u32>,
+    #[avp(code = 297, mandatory)]
+    pub experimental_result: Option<ExperimentalResult>,
+    #[avp(code = 264, mandatory)]
+    pub origin_host: String,
+    #[avp(code = 296, mandatory)]
+    pub origin_realm: String,
+    #[avp(code = 258, mandatory)]
+    pub auth_application_id: Option<u32>,
+    #[avp(code = 416, mandatory)]
+    pub cc_request_type: CcRequestType,
+    #[avp(code = 415, mandatory)]
+    pub cc_request_number: u32,
+    #[avp(code = 1, mandatory)]
+    pub user_name: Option<String>,
+    #[avp(code = 418, mandatory)]
+    pub cc_session_failover: Option<CcSessionFailover>,
+    #[avp(code = 419, mandatory)]
+    pub cc_sub_session_id: Option<u64>,
+    #[avp(code = 50, mandatory)]
+    pub acct_multi_session_id: Option<String>,
+    #[avp(code = 278, mandatory)]
+    pub origin_state_id: Option<u32>,
+    #[avp(code = 55, mandatory)]
+    pub event_timestamp: Option<DateTime<Utc>>,
+    #[avp(code = 431, mandatory)]
+    pub granted_service_unit: Option<GrantedServiceUnit>,
+    #[avp(code = 456, mandatory)]
+    pub multiple_services_credit_control: Vec<MultipleServicesCreditControl>,
+    #[avp(code = 423, mandatory)]
+    pub cost_information: Option<CostInformation>,
+    #[avp(code = 430, mandatory)]
+    pub final_unit_indication: Option<FinalUnitIndication>,
+    #[avp(code = 422, mandatory)]
+    pub check_balance_result: Option<CheckBalanceResult>,
+    #[avp(code = 427, mandatory)]
+    pub credit_control_failure_handling: Option<CreditControlFailureHandling>,
+    #[avp(code = 428, mandatory)]
+    pub direct_debiting_failure_handling: Option<DirectDebitingFailureHandling>,
+    #[avp(code = 448, mandatory)]
+    pub validity_time: Option<u32>,
+    #[avp(code = 292, mandatory)]
+    pub redirect_host: Vec<Uri>,
+    #[avp(code = 261, mandatory)]
+    pub redirect_host_usage: Option<RedirectHostUsage>,
+    #[avp(code = 262, mandatory)]
+    pub redirect_max_cache_time: Option<u32>,
+    #[avp(code = 284, mandatory)]
+    pub proxy_info: Vec<ProxyInfo>,
+    #[avp(code = 282, mandatory)]
+    pub route_record: Vec<String>,
+    #[avp(code = 279, mandatory)]
+    pub failed_avp: Option<FailedAvp>,
+    #[avp(rest)]
+    pub other: Vec<Avp>,
+}
+
+impl SubscriptionId {
+    pub fn new(subscription_id_type: SubscriptionIdType, subscription_id_data: &str) -> Self {
+        Self {
+            subscription_id_type,
+            subscription_id_data: subscription_id_data.to_string(),
+            other: vec![],
+        }
+    }
+}
+
+impl UnitValue {
+    pub fn new(value_digits: i64, exponent: Option<i32>) -> Self {
+        Self {
+            value_digits,
+            exponent,
+            other: vec![],
+        }
+    }
+}
+
+impl MultipleServicesCreditControl {
+    /// An MSCC asking for `requested_service_unit`, as sent in initial and update requests.
+    pub fn requesting(requested_service_unit: RequestedServiceUnit) -> Self {
+        Self {
+            requested_service_unit: Some(requested_service_unit),
+            ..Default::default()
+        }
+    }
+}
+
+impl CreditControlRequest {
+    /// An INITIAL_REQUEST with CC-Request-Number 0.
+    pub fn new(
+        session_id: &str,
+        origin_host: &str,
+        origin_realm: &str,
+        destination_realm: &str,
+        auth_application_id: u32,
+        service_context_id: &str,
+    ) -> Self {
+        Self {
+            session_id: session_id.to_string(),
+            origin_host: origin_host.to_string(),
+            origin_realm: origin_realm.to_string(),
+            destination_realm: destination_realm.to_string(),
+            auth_application_id,
+            service_context_id: service_context_id.to_string(),
+            cc_request_type: CcRequestType::InitialRequest,
+            cc_request_number: 0,
+            destination_host: None,
+            user_name: None,
+            cc_sub_session_id: None,
+            acct_multi_session_id: None,
+            origin_state_id: None,
+            event_timestamp: None,
+            subscription_id: vec![],
+            service_identifier: None,
+            termination_cause: None,
+            requested_service_unit: None,
+            requested_action: None,
+            used_service_unit: vec![],
+            multiple_services_indicator: None,
+            multiple_services_credit_control: vec![],
+            service_parameter_info: vec![],
+            cc_correlation_id: None,
+            user_equipment_info: None,
+            proxy_info: vec![],
+            route_record: vec![],
+            other: vec![],
+        }
+    }
+
+    /// The next request of the session: identity, routing and subscription AVPs are kept, the
+    /// CC-Request-Number is incremented and units are left for the caller to fill in.
+    pub fn next_request(&self, cc_request_type: CcRequestType) -> Self {
+        let mut request = Self::new(
+            &self.session_id,
+            &self.origin_host,
+            &self.origin_realm,
+            &self.destination_realm,
+            self.auth_application_id,
+            &self.service_context_id,
+        );
+        request.cc_request_type = cc_request_type;
+        request.cc_request_number = self.cc_request_number.wrapping_add(1);
+        request.destination_host = self.destination_host.clone();
+        request.user_name = self.user_name.clone();
+        request.cc_sub_session_id = self.cc_sub_session_id;
+        request.acct_multi_session_id = self.acct_multi_session_id.clone();
+        request.origin_state_id = self.origin_state_id;
+        request.subscription_id = self.subscription_id.clone();
+        request.multiple_services_indicator = self.multiple_services_indicator;
+        request.user_equipment_info = self.user_equipment_info.clone();
+        request
+    }
+}
+
+impl CreditControlAnswer {
+    /// A DIAMETER_SUCCESS answer.
+    pub fn new(
+        session_id: &str,
+        origin_host: &str,
+        origin_realm: &str,
+        cc_request_type: CcRequestType,
+        cc_request_number: u32,
+    ) -> Self {
+        Self {
+            session_id: session_id.to_string(),
+            result_code: Some(ResultCode::SUCCESS.value()),
+            experimental_result: None,
+            origin_host: origin_host.to_string(),
+            origin_realm: origin_realm.to_string(),
+            auth_application_id: None,
+            cc_request_type,
+            cc_request_number,
+            user_name: None,
+            cc_session_failover: None,
+            cc_sub_session_id: None,
+            acct_multi_session_id: None,
+            origin_state_id: None,
+            event_timestamp: None,
+            granted_service_unit: None,
+            multiple_services_credit_control: vec![],
+            cost_information: None,
+            final_unit_indication: None,
+            check_balance_result: None,
+            credit_control_failure_handling: None,
+            direct_debiting_failure_handling: None,
+            validity_time: None,
+            redirect_host: vec![],
+            redirect_host_usage: None,
+            redirect_max_cache_time: None,
+            proxy_info: vec![],
+            route_record: vec![],
+            failed_avp: None,
+            other: vec![],
+        }
+    }
+
+    /// The outcome of the answer, from Result-Code or, as sent by 3GPP servers, from
+    /// Experimental-Result.
+    pub fn result(&self) -> Option<ResultCode> {
+        self.result_code.map(ResultCode::new).or_else(|| {
+            self.experimental_result
+                .as_ref()
+                .map(ExperimentalResult::result_code)
+        })
+    }
+}
@@ -3,20 +3,48 @@ Author: Mr. Jason Lee -> https://github.com/lwlee2608/diameter-rs
 Updated by: Ahmed Mehanna -> https://github.com/AhmedMehanna1/diameter-protocol
  */
 
+#[cfg(feature = "std")]
+use crate::errors::DiameterResult;
+#[cfg(feature = "std")]
+use crate::errors::Error::DictionaryError;
 use crate::modeling::avp::avp::AvpType;
+use crate::modeling::avp::custom::{AvpCodec, CustomDecoder, decoder};
 use crate::modeling::message::application_id::ApplicationId;
 use crate::modeling::message::command_code::CommandCode;
+#[cfg(any(feature = "json", feature = "yaml"))]
+use crate::modeling::message::dictionary_format::DictionaryDocument;
+#[cfg(feature = "json")]
+use crate::modeling::message::freediameter;
+#[cfg(feature = "json")]
+use crate::modeling::message::freediameter::FreeDiameterImport;
+#[cfg(feature = "std")]
+use crate::modeling::message::wireshark;
+#[cfg(feature = "std")]
+use crate::modeling::message::wireshark::WiresharkImport;
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+#[cfg(feature = "std")]
+use alloc::vec;
+#[cfg(feature = "std")]
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
 use lazy_static::lazy_static;
+#[cfg(feature = "std")]
 use serde::Deserialize;
+#[cfg(feature = "std")]
 use serde_xml_rs::from_str;
-use std::collections::{BTreeMap, HashMap};
+#[cfg(feature = "std")]
+use std::path::Path;
+#[cfg(feature = "std")]
 use std::sync::RwLock;
 
 #[derive(Debug)]
 pub struct Dictionary {
+    vendors: BTreeMap<u32, String>,
     avps: BTreeMap<AvpKey, AvpDefinition>,
-    applications: HashMap<String, ApplicationId>,
-    commands: HashMap<String, CommandCode>,
+    applications: BTreeMap<String, ApplicationId>,
+    commands: BTreeMap<String, CommandCode>,
+    codecs: BTreeMap<String, CustomDecoder>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
@@ -32,15 +60,24 @@ pub struct AvpDefinition {
     pub name: String,
     pub avp_type: AvpType,
     pub m_flag: bool,
+    pub enum_values: BTreeMap<i32, String>,
 }
 
 impl Dictionary {
-    pub fn new(xmls: &[&str]) -> Self {
-        let mut dict = Dictionary {
+    /// A dictionary without definitions, to fill with `add_avp` and the other `add_*` methods.
+    pub fn empty() -> Self {
+        Dictionary {
+            vendors: BTreeMap::new(),
             avps: BTreeMap::new(),
-            applications: HashMap::new(),
-            commands: HashMap::new(),
-        };
+            applications: BTreeMap::new(),
+            commands: BTreeMap::new(),
+            codecs: BTreeMap::new(),
+        }
+    }
+
+    #[cfg(feature = "std")]
+    pub fn new(xmls: &[&str]) -> Self {
+        let mut dict = Dictionary::empty();
 
         for xml in xmls {
             dict.load_xml(xml)
@@ -49,8 +86,102 @@ impl Dictionary {
         dict
     }
 
+    #[cfg(feature = "std")]
+    pub fn try_new(xmls: &[&str]) -> DiameterResult<Self> {
+        let mut dict = Dictionary::empty();
+        for xml in xmls {
+            dict.try_load_xml(xml)?;
+        }
+        Ok(dict)
+    }
+
+    #[cfg(feature = "std")]
     pub fn load_xml(&mut self, xml: &str) {
-        parse(xml, self);
+        self.try_load_xml(xml).unwrap()
+    }
+
+    /// Loads the XML definitions, reporting malformed input instead of panicking.
+    /// Definitions parsed before the failure are kept.
+    #[cfg(feature = "std")]
+    pub fn try_load_xml(&mut self, xml: &str) -> DiameterResult<()> {
+        parse(xml, self)
+    }
+
+    /// Loads a Wireshark `diameter/*.xml` dictionary. External entities are not resolved,
+    /// use [`Dictionary::load_wireshark_file`] for dictionaries split across files. Returns the
+    /// applications and commands skipped by the import.
+    #[cfg(feature = "std")]
+    pub fn load_wireshark_xml(&mut self, xml: &str) -> DiameterResult<WiresharkImport> {
+        wireshark::parse(xml, self)
+    }
+
+    /// Loads a Wireshark dictionary file, resolving `<!ENTITY name SYSTEM "file.xml">`
+    /// includes relative to its directory.
+    #[cfg(feature = "std")]
+    pub fn load_wireshark_file<P: AsRef<Path>>(
+        &mut self,
+        path: P,
+    ) -> DiameterResult<WiresharkImport> {
+        wireshark::parse_file(path.as_ref(), self)
+    }
+
+    /// Loads a freeDiameter `dict_json` dictionary. Returns the applications and commands
+    /// skipped by the import.
+    #[cfg(feature = "json")]
+    pub fn load_freediameter_json(&mut self, json: &str) -> DiameterResult<FreeDiameterImport> {
+        freediameter::parse(json, self)
+    }
+
+    #[cfg(feature = "json")]
+    pub fn load_json(&mut self, json: &str) -> DiameterResult<()> {
+        DictionaryDocument::from_json(json)?.load_into(self)
+    }
+
+    #[cfg(feature = "json")]
+    pub fn to_json(&self) -> DiameterResult<String> {
+        DictionaryDocument::from(self).to_json()
+    }
+
+    #[cfg(feature = "yaml")]
+    pub fn load_yaml(&mut self, yaml: &str) -> DiameterResult<()> {
+        DictionaryDocument::from_yaml(yaml)?.load_into(self)
+    }
+
+    #[cfg(feature = "yaml")]
+    pub fn to_yaml(&self) -> DiameterResult<String> {
+        DictionaryDocument::from(self).to_yaml()
+    }
+
+    pub fn add_vendor(&mut self, vendor_id: u32, name: &str) {
+        self.vendors.insert(vendor_id, name.to_string());
+    }
+
+    pub fn add_application(&mut self, name: &str, application_id: ApplicationId) {
+        self.applications.insert(name.to_string(), application_id);
+    }
+
+    pub fn add_command(&mut self, name: &str, command_code: CommandCode) {
+        self.commands.insert(name.to_string(), command_code);
+    }
+
+    /// Decodes AVPs of type `type_name` with `T`. Wireshark dictionaries resolve their
+    /// `typedefn` chains when loaded, so register codecs for those names beforehand.
+    pub fn register_codec<T: AvpCodec>(&mut self, type_name: &str) {
+        self.codecs.insert(type_name.to_string(), decoder::<T>());
+    }
+
+    pub(crate) fn get_codec(&self, type_name: &str) -> Option<CustomDecoder> {
+        self.codecs.get(type_name).copied()
+    }
+
+    #[cfg(feature = "std")]
+    pub(crate) fn codecs(&self) -> impl Iterator<Item = (&str, CustomDecoder)> {
+        self.codecs.iter().map(|(name, codec)| (name.as_str(), *codec))
+    }
+
+    #[cfg(feature = "std")]
+    pub(crate) fn add_codec(&mut self, type_name: &str, codec: CustomDecoder) {
+        self.codecs.insert(type_name.to_string(), codec);
     }
 
     pub fn add_avp(&mut self, avp: AvpDefinition) {
@@ -66,6 +197,43 @@ impl Dictionary {
         }
     }
 
+    pub fn avps(&self) -> impl Iterator<Item = &AvpDefinition> {
+        self.avps.values()
+    }
+
+    pub fn vendors(&self) -> impl Iterator<Item = (u32, &str)> {
+        self.vendors.iter().map(|(id, name)| (*id, name.as_str()))
+    }
+
+    pub fn get_vendor_name(&self, vendor_id: u32) -> Option<&str> {
+        self.vendors.get(&vendor_id).map(|name| name.as_str())
+    }
+
+    pub fn get_vendor_id(&self, name: &str) -> Option<u32> {
+        self.vendors()
+            .find(|(_, vendor)| vendor.eq_ignore_ascii_case(name))
+            .map(|(id, _)| id)
+    }
+
+    /// Lists the AVPs defined by a vendor, `None` lists the IETF AVPs.
+    pub fn get_vendor_avps(&self, vendor_id: Option<u32>) -> impl Iterator<Item = &AvpDefinition> {
+        self.avps().filter(move |avp| avp.vendor_id == vendor_id)
+    }
+
+    /// Looks up a vendor-specific AVP by the vendor name, e.g. `("Timezone-Offset", "3GPP")`.
+    pub fn get_vendor_avp_by_name(&self, name: &str, vendor: &str) -> Option<&AvpDefinition> {
+        let vendor_id = self.get_vendor_id(vendor)?;
+        self.get_avp_by_name_and_vendor(name, Some(vendor_id))
+    }
+
+    pub fn applications(&self) -> impl Iterator<Item = (&str, &ApplicationId)> {
+        self.applications.iter().map(|(name, id)| (name.as_str(), id))
+    }
+
+    pub fn commands(&self) -> impl Iterator<Item = (&str, &CommandCode)> {
+        self.commands.iter().map(|(name, code)| (name.as_str(), code))
+    }
+
     pub fn get_avp(&self, code: u32, vendor_id: Option<u32>) -> Option<&AvpDefinition> {
         let key = match vendor_id {
             Some(vendor_id) => AvpKey::CodeAndVendor(code, vendor_id),
@@ -79,6 +247,36 @@ impl Dictionary {
         self.avps.values().find(|avp| avp.name == name)
     }
 
+    /// The AVP named `name` defined by `vendor_id`, `None` for the IETF one.
+    pub fn get_avp_by_name_and_vendor(
+        &self,
+        name: &str,
+        vendor_id: Option<u32>,
+    ) -> Option<&AvpDefinition> {
+        self.get_avps_by_name(name)
+            .find(|avp| avp.vendor_id == vendor_id)
+    }
+
+    /// The AVP named `name`, `None` when several vendors define an AVP of that name. Those are
+    /// looked up with `get_avp_by_name_and_vendor`.
+    pub fn get_unique_avp_by_name(&self, name: &str) -> Option<&AvpDefinition> {
+        let mut avps = self.get_avps_by_name(name);
+        let avp = avps.next()?;
+        match avps.next() {
+            Some(_) => None,
+            None => Some(avp),
+        }
+    }
+
+    /// Every AVP named `name`, by code and vendor.
+    pub fn get_avps_by_name<'a>(
+        &'a self,
+        name: &str,
+    ) -> impl Iterator<Item = &'a AvpDefinition> {
+        // Might consider indexing avp.name
+        self.avps.values().filter(move |avp| avp.name == name)
+    }
+
     pub fn get_avp_type(&self, code: u32, vendor_id: Option<u32>) -> Option<&AvpType> {
         let key = match vendor_id {
             Some(vendor_id) => AvpKey::CodeAndVendor(code, vendor_id),
@@ -90,6 +288,13 @@ impl Dictionary {
         }
     }
 
+    pub fn get_enum_name(&self, code: u32, vendor_id: Option<u32>, value: i32) -> Option<&str> {
+        self.get_avp(code, vendor_id)?
+            .enum_values
+            .get(&value)
+            .map(|name| name.as_str())
+    }
+
     pub fn get_avp_name(&self, code: u32, vendor_id: Option<u32>) -> Option<&str> {
         let key = match vendor_id {
             Some(vendor_id) => AvpKey::CodeAndVendor(code, vendor_id),
@@ -102,12 +307,23 @@ impl Dictionary {
     }
 }
 
+#[cfg(feature = "std")]
 #[derive(Debug, Deserialize, PartialEq)]
 struct Diameter {
+    #[serde(rename = "vendor", default)]
+    vendors: Vec<Vendor>,
     #[serde(rename = "application")]
     applications: Vec<Application>,
 }
 
+#[cfg(feature = "std")]
+#[derive(Debug, Deserialize, PartialEq)]
+struct Vendor {
+    id: String,
+    name: String,
+}
+
+#[cfg(feature = "std")]
 #[derive(Debug, Deserialize, PartialEq)]
 struct Application {
     id: String,
@@ -118,6 +334,7 @@ struct Application {
     avps: Vec<Avp>,
 }
 
+#[cfg(feature = "std")]
 #[derive(Debug, Deserialize, PartialEq)]
 struct Command {
     code: String,
@@ -127,12 +344,14 @@ struct Command {
     answer: CommandDetail,
 }
 
+#[cfg(feature = "std")]
 #[derive(Debug, Deserialize, PartialEq)]
 struct CommandDetail {
     #[serde(rename = "rule", default)]
     rules: Vec<Rule>,
 }
 
+#[cfg(feature = "std")]
 #[derive(Debug, Deserialize, PartialEq)]
 struct Rule {
     avp: String,
@@ -141,6 +360,7 @@ struct Rule {
     min: Option<String>,
 }
 
+#[cfg(feature = "std")]
 #[derive(Debug, Deserialize, PartialEq)]
 struct Avp {
     name: String,
@@ -156,6 +376,7 @@ struct Avp {
     data: Data,
 }
 
+#[cfg(feature = "std")]
 #[derive(Debug, Deserialize, PartialEq)]
 struct Data {
     #[serde(rename = "type")]
@@ -164,68 +385,134 @@ struct Data {
     item: Vec<Item>,
 }
 
+#[cfg(feature = "std")]
 #[derive(Debug, Deserialize, PartialEq)]
 struct Item {
     code: String,
     name: String,
 }
 
-pub fn parse(xml: &str, dictionary: &mut Dictionary) {
-    let dict: Diameter = from_str(xml).unwrap();
+#[cfg(feature = "std")]
+pub fn parse(xml: &str, dictionary: &mut Dictionary) -> DiameterResult<()> {
+    let dict: Diameter =
+        from_str(xml).map_err(|_| DictionaryError("Malformed dictionary XML"))?;
+
+    for vendor in dict.vendors.iter() {
+        dictionary.add_vendor(parse_number(&vendor.id)?, &vendor.name);
+    }
 
-    dict.applications.iter().for_each(|app| {
-        let app_id = app.id.parse::<u32>().unwrap();
-        let app_id: ApplicationId = ApplicationId::try_from(app_id).unwrap();
+    for app in dict.applications.iter() {
+        let app_id = parse_number(&app.id)?;
+        let app_id: ApplicationId = ApplicationId::try_from(app_id)?;
         dictionary.applications.insert(app.name.clone(), app_id);
 
-        app.commands.iter().for_each(|cmd| {
-            let cmd_code = cmd.code.parse::<u32>().unwrap();
-            let cmd_code: CommandCode = CommandCode::try_from(cmd_code).unwrap();
+        for cmd in app.commands.iter() {
+            let cmd_code = parse_number(&cmd.code)?;
+            let cmd_code: CommandCode = CommandCode::try_from(cmd_code)?;
             dictionary.commands.insert(cmd.name.clone(), cmd_code);
-        });
-
-        app.avps.iter().for_each(|avp| {
-            let avp_type = match avp.data.data_type.as_str() {
-                "UTF8String" => AvpType::UTF8String,
-                "OctetString" => AvpType::OctetString,
-                "Integer32" => AvpType::Integer32,
-                "Integer64" => AvpType::Integer64,
-                "Unsigned32" => AvpType::Unsigned32,
-                "Unsigned64" => AvpType::Unsigned64,
-                "Enumerated" => AvpType::Enumerated,
-                "Grouped" => AvpType::Grouped,
-                "DiameterIdentity" => AvpType::Identity,
-                "DiameterURI" => AvpType::DiameterURI,
-                "Time" => AvpType::Time,
-                "IPv4" => AvpType::AddressIPv4,
-                "IPv6" => AvpType::AddressIPv6,
-                "Float32" => AvpType::Float32,
-                "Float64" => AvpType::Float64,
-                _ => AvpType::Unknown,
-            };
+        }
+
+        for avp in app.avps.iter() {
+            let avp_type = avp_type_or_custom(&avp.data.data_type);
 
             let flags: Vec<&str> = match avp.must {
                 Some(ref s) => s.split(',').collect(),
                 None => vec![],
             };
-            let m_flag = if flags.contains(&"M") { true } else { false };
+            let m_flag = flags.contains(&"M");
             let vendor_id = match avp.vendor_id {
-                Some(ref s) => Some(s.parse::<u32>().unwrap()),
+                Some(ref s) => Some(parse_number(s)?),
                 None => None,
             };
 
+            let mut enum_values = BTreeMap::new();
+            for item in avp.data.item.iter() {
+                enum_values.insert(parse_enum_value(&item.code)?, item.name.clone());
+            }
+
             let avp_definition = AvpDefinition {
-                code: avp.code.parse::<u32>().unwrap(),
+                code: parse_number(&avp.code)?,
                 vendor_id,
                 name: avp.name.clone(),
                 avp_type,
                 m_flag,
+                enum_values,
             };
 
             dictionary.add_avp(avp_definition);
-        });
-    });
+        }
+    }
+    Ok(())
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn parse_enum_value(value: &str) -> DiameterResult<i32> {
+    value
+        .trim()
+        .parse::<i32>()
+        .map_err(|_| DictionaryError("Invalid enumerated value in dictionary XML"))
+}
+
+pub(crate) fn avp_type_from_name(name: &str) -> Option<AvpType> {
+    let avp_type = match name {
+        "UTF8String" => AvpType::UTF8String,
+        "OctetString" | "IPFilterRule" | "QoSFilterRule" => AvpType::OctetString,
+        "Integer32" => AvpType::Integer32,
+        "Integer64" => AvpType::Integer64,
+        "Unsigned32" => AvpType::Unsigned32,
+        "Unsigned64" => AvpType::Unsigned64,
+        "Enumerated" => AvpType::Enumerated,
+        "Grouped" => AvpType::Grouped,
+        "DiameterIdentity" => AvpType::Identity,
+        "DiameterURI" => AvpType::DiameterURI,
+        "Time" => AvpType::Time,
+        "Address" => AvpType::Address,
+        "IPv4" => AvpType::AddressIPv4,
+        "IPv6" => AvpType::AddressIPv6,
+        "Float32" => AvpType::Float32,
+        "Float64" => AvpType::Float64,
+        _ => return None,
+    };
+    Some(avp_type)
+}
+
+/// Type names that are not built in refer to codecs registered with the dictionary.
+pub(crate) fn avp_type_or_custom(name: &str) -> AvpType {
+    avp_type_from_name(name).unwrap_or_else(|| AvpType::Custom(name.to_string()))
+}
+
+pub(crate) fn avp_type_name(avp_type: &AvpType) -> &str {
+    match avp_type {
+        AvpType::UTF8String => "UTF8String",
+        AvpType::OctetString => "OctetString",
+        AvpType::Integer32 => "Integer32",
+        AvpType::Integer64 => "Integer64",
+        AvpType::Unsigned32 => "Unsigned32",
+        AvpType::Unsigned64 => "Unsigned64",
+        AvpType::Enumerated => "Enumerated",
+        AvpType::Grouped => "Grouped",
+        AvpType::Identity => "DiameterIdentity",
+        AvpType::DiameterURI => "DiameterURI",
+        AvpType::Time => "Time",
+        AvpType::Address => "Address",
+        AvpType::AddressIPv4 => "IPv4",
+        AvpType::AddressIPv6 => "IPv6",
+        AvpType::Float32 => "Float32",
+        AvpType::Float64 => "Float64",
+        AvpType::Custom(name) => name,
+        AvpType::Unknown => "Unknown",
+    }
 }
+
+#[cfg(feature = "std")]
+pub(crate) fn parse_number(value: &str) -> DiameterResult<u32> {
+    value
+        .trim()
+        .parse::<u32>()
+        .map_err(|_| DictionaryError("Invalid number in dictionary XML"))
+}
+
+#[cfg(feature = "std")]
 lazy_static! {
     pub static ref DEFAULT_DICT: RwLock<Dictionary> = {
         let xml = &DEFAULT_DICT_XML;
@@ -235,6 +522,7 @@ lazy_static! {
     pub static ref DEFAULT_DICT_XML: &'static str = {
         let xml = r#"
 <diameter>
+	<vendor id="10415" name="3GPP"/>
 	<application id="4" type="auth" name="Charging Control">
 		<!-- Diameter Credit Control Application -->
 		<!-- http://tools.ietf.org/html/rfc4006 -->
@@ -616,6 +904,10 @@ lazy_static! {
 			<data type="Time"/>
 		</avp>
 
+		<avp name="Filter-Id" code="11" must="M" may="P" must-not="V" may-encrypt="Y">
+			<data type="UTF8String"/>
+		</avp>
+
 		<avp name="Experimental-Result" code="297" must="M" may="P" must-not="V" may-encrypt="-">
 			<data type="Grouped">
 				<rule avp="Vendor-Id" required="true" max="1"/>
@@ -946,6 +1238,7 @@ lazy_static! {
 				<item code="1" name="INITIAL_REQUEST"/>
 				<item code="2" name="UPDATE_REQUEST"/>
 				<item code="3" name="TERMINATION_REQUEST"/>
+				<item code="4" name="EVENT_REQUEST"/>
 			</data>
 		</avp>
 
@@ -1221,6 +1514,7 @@ lazy_static! {
 				<item code="1" name="END_USER_IMSI"/>
 				<item code="2" name="END_USER_SIP_URI"/>
 				<item code="3" name="END_USER_NAI"/>
+				<item code="4" name="END_USER_PRIVATE"/>
 			</data>
 		</avp>
 
@@ -0,0 +1,189 @@
+//! # Dictionary Document
+//!
+//! A serde model of the `Dictionary` content, used to load and export dictionaries as JSON
+//! (feature `json`) or YAML (feature `yaml`):
+//! ```text
+//!  vendors:
+//!    - name: 3GPP
+//!      id: 10415
+//!  applications:
+//!    - name: Gx
+//!      id: 16777238
+//!  commands:
+//!    - name: Credit-Control
+//!      code: 272
+//!  avps:
+//!    - name: Session-Id
+//!      code: 263
+//!      type: UTF8String
+//!      mandatory: true
+//!    - name: CC-Request-Type
+//!      code: 416
+//!      type: Enumerated
+//!      mandatory: true
+//!      values:
+//!        1: INITIAL_REQUEST
+//!        2: UPDATE_REQUEST
+//!    - name: Timezone-Offset
+//!      code: 571
+//!      vendor-id: 10415
+//!      type: Integer32
+//! ```
+//! Entries are exported sorted by id/code so the output is stable and diff-friendly.
+
+use crate::errors::DiameterResult;
+#[cfg(any(feature = "json", feature = "yaml"))]
+use crate::errors::Error::DictionaryError;
+use crate::modeling::message::application_id::ApplicationId;
+use crate::modeling::message::command_code::CommandCode;
+use crate::modeling::message::dictionary::{
+    AvpDefinition, Dictionary, avp_type_name, avp_type_or_custom,
+};
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct DictionaryDocument {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub vendors: Vec<VendorEntry>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub applications: Vec<ApplicationEntry>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub commands: Vec<CommandEntry>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub avps: Vec<AvpEntry>,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct VendorEntry {
+    pub name: String,
+    pub id: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct ApplicationEntry {
+    pub name: String,
+    pub id: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct CommandEntry {
+    pub name: String,
+    pub code: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub struct AvpEntry {
+    pub name: String,
+    pub code: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vendor_id: Option<u32>,
+    #[serde(rename = "type")]
+    pub avp_type: String,
+    #[serde(default)]
+    pub mandatory: bool,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub values: BTreeMap<i32, String>,
+}
+
+impl DictionaryDocument {
+    /// Adds every entry to `dictionary`, replacing definitions with the same key.
+    pub fn load_into(self, dictionary: &mut Dictionary) -> DiameterResult<()> {
+        for vendor in self.vendors {
+            dictionary.add_vendor(vendor.id, &vendor.name);
+        }
+        for application in self.applications {
+            let application_id = ApplicationId::try_from(application.id)?;
+            dictionary.add_application(&application.name, application_id);
+        }
+        for command in self.commands {
+            let command_code = CommandCode::try_from(command.code)?;
+            dictionary.add_command(&command.name, command_code);
+        }
+        for avp in self.avps {
+            dictionary.add_avp(AvpDefinition {
+                code: avp.code,
+                vendor_id: avp.vendor_id,
+                name: avp.name,
+                avp_type: avp_type_or_custom(&avp.avp_type),
+                m_flag: avp.mandatory,
+                enum_values: avp.values,
+            });
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "json")]
+    pub fn from_json(json: &str) -> DiameterResult<Self> {
+        serde_json::from_str(json).map_err(|_| DictionaryError("Malformed dictionary JSON"))
+    }
+
+    #[cfg(feature = "json")]
+    pub fn to_json(&self) -> DiameterResult<String> {
+        serde_json::to_string_pretty(self)
+            .map_err(|_| DictionaryError("Dictionary cannot be written as JSON"))
+    }
+
+    #[cfg(feature = "yaml")]
+    pub fn from_yaml(yaml: &str) -> DiameterResult<Self> {
+        serde_yaml::from_str(yaml).map_err(|_| DictionaryError("Malformed dictionary YAML"))
+    }
+
+    #[cfg(feature = "yaml")]
+    pub fn to_yaml(&self) -> DiameterResult<String> {
+        serde_yaml::to_string(self)
+            .map_err(|_| DictionaryError("Dictionary cannot be written as YAML"))
+    }
+}
+
+impl From<&Dictionary> for DictionaryDocument {
+    fn from(dictionary: &Dictionary) -> Self {
+        let vendors = dictionary
+            .vendors()
+            .map(|(id, name)| VendorEntry {
+                name: name.to_string(),
+                id,
+            })
+            .collect();
+
+        let mut applications: Vec<ApplicationEntry> = dictionary
+            .applications()
+            .map(|(name, id)| ApplicationEntry {
+                name: name.to_string(),
+                id: id.value(),
+            })
+            .collect();
+        applications.sort_by(|a, b| (a.id, &a.name).cmp(&(b.id, &b.name)));
+
+        let mut commands: Vec<CommandEntry> = dictionary
+            .commands()
+            .map(|(name, code)| CommandEntry {
+                name: name.to_string(),
+                code: code.get_code(),
+            })
+            .collect();
+        commands.sort_by(|a, b| (a.code, &a.name).cmp(&(b.code, &b.name)));
+
+        let avps = dictionary
+            .avps()
+            .map(|avp| AvpEntry {
+                name: avp.name.clone(),
+                code: avp.code,
+                vendor_id: avp.vendor_id,
+                avp_type: avp_type_name(&avp.avp_type).to_string(),
+                mandatory: avp.m_flag,
+                values: avp.enum_values.clone(),
+            })
+            .collect();
+
+        DictionaryDocument {
+            vendors,
+            applications,
+            commands,
+            avps,
+        }
+    }
+}
@@ -0,0 +1,222 @@
+//! # Reloadable Dictionary
+//!
+//! `DictionaryHandle` owns the current `Dictionary` behind an `Arc` that can be swapped at
+//! runtime. Decoders take a snapshot with [`DictionaryHandle::load`], so a reload never affects
+//! messages that are already being decoded: they finish on the version they started with.
+//! Codecs registered with [`DictionaryHandle::register_codec`] are kept across reloads and
+//! [`DictionaryHandle::replace`].
+
+use crate::errors::DiameterResult;
+use crate::modeling::avp::custom::AvpCodec;
+use crate::modeling::message::dictionary::Dictionary;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, RwLock};
+use std::thread;
+use std::thread::JoinHandle;
+use std::time::{Duration, SystemTime};
+
+#[derive(Debug, Clone)]
+pub enum DictionarySource {
+    Xml(String),
+    File(PathBuf),
+    WiresharkFile(PathBuf),
+    #[cfg(feature = "json")]
+    JsonFile(PathBuf),
+    #[cfg(feature = "yaml")]
+    YamlFile(PathBuf),
+}
+
+type Validator = Box<dyn Fn(&Dictionary) -> DiameterResult<()> + Send + Sync>;
+
+pub struct DictionaryHandle {
+    sources: Vec<DictionarySource>,
+    current: RwLock<Arc<Dictionary>>,
+    version: AtomicU64,
+    validator: Option<Validator>,
+    reload_lock: Mutex<()>,
+}
+
+pub struct DictionaryWatcher {
+    stopped: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl DictionarySource {
+    fn load_into(&self, dictionary: &mut Dictionary) -> DiameterResult<()> {
+        match self {
+            DictionarySource::Xml(xml) => dictionary.try_load_xml(xml),
+            DictionarySource::File(path) => dictionary.try_load_xml(&fs::read_to_string(path)?),
+            DictionarySource::WiresharkFile(path) => {
+                dictionary.load_wireshark_file(path).map(|_| ())
+            }
+            #[cfg(feature = "json")]
+            DictionarySource::JsonFile(path) => dictionary.load_json(&fs::read_to_string(path)?),
+            #[cfg(feature = "yaml")]
+            DictionarySource::YamlFile(path) => dictionary.load_yaml(&fs::read_to_string(path)?),
+        }
+    }
+
+    fn modified(&self) -> Option<SystemTime> {
+        let path = match self {
+            DictionarySource::Xml(_) => return None,
+            DictionarySource::File(path) | DictionarySource::WiresharkFile(path) => path,
+            #[cfg(feature = "json")]
+            DictionarySource::JsonFile(path) => path,
+            #[cfg(feature = "yaml")]
+            DictionarySource::YamlFile(path) => path,
+        };
+        fs::metadata(path).and_then(|m| m.modified()).ok()
+    }
+}
+
+impl DictionaryHandle {
+    /// Builds the initial dictionary from the sources, in order.
+    pub fn new(sources: Vec<DictionarySource>) -> DiameterResult<Self> {
+        let dictionary = Self::build(&sources, Dictionary::new(&[]))?;
+        Ok(Self {
+            sources,
+            current: RwLock::new(Arc::new(dictionary)),
+            version: AtomicU64::new(1),
+            validator: None,
+            reload_lock: Mutex::new(()),
+        })
+    }
+
+    /// Adds a check that every new dictionary must pass before it is swapped in.
+    /// The current dictionary is checked immediately.
+    pub fn with_validator<F>(mut self, validator: F) -> DiameterResult<Self>
+    where
+        F: Fn(&Dictionary) -> DiameterResult<()> + Send + Sync + 'static,
+    {
+        validator(&self.load())?;
+        self.validator = Some(Box::new(validator));
+        Ok(self)
+    }
+
+    /// Returns a snapshot of the current dictionary.
+    pub fn load(&self) -> Arc<Dictionary> {
+        Arc::clone(&self.current.read().unwrap())
+    }
+
+    pub fn version(&self) -> u64 {
+        self.version.load(Ordering::Acquire)
+    }
+
+    /// Re-reads every source and swaps the result in. On failure the current dictionary is kept.
+    pub fn reload(&self) -> DiameterResult<u64> {
+        let _guard = self.reload_lock.lock().unwrap();
+        let dictionary = Self::build(&self.sources, self.empty_with_codecs())?;
+        self.swap(dictionary)
+    }
+
+    /// Registers a codec for `type_name` and reloads the sources with it.
+    pub fn register_codec<T: AvpCodec>(&self, type_name: &str) -> DiameterResult<u64> {
+        let _guard = self.reload_lock.lock().unwrap();
+        let mut dictionary = self.empty_with_codecs();
+        dictionary.register_codec::<T>(type_name);
+        let dictionary = Self::build(&self.sources, dictionary)?;
+        self.swap(dictionary)
+    }
+
+    /// Validates `dictionary` and swaps it in, returning the new version. Registered codecs
+    /// that `dictionary` does not define are carried over.
+    pub fn replace(&self, mut dictionary: Dictionary) -> DiameterResult<u64> {
+        let _guard = self.reload_lock.lock().unwrap();
+        let current = self.load();
+        for (type_name, codec) in current.codecs() {
+            if dictionary.get_codec(type_name).is_none() {
+                dictionary.add_codec(type_name, codec);
+            }
+        }
+        self.swap(dictionary)
+    }
+
+    /// Polls the file sources every `interval` and reloads when any of them changes, until a
+    /// reload succeeds. `on_reload` receives the outcome of every reload attempt.
+    pub fn watch<F>(self: &Arc<Self>, interval: Duration, on_reload: F) -> DictionaryWatcher
+    where
+        F: Fn(DiameterResult<u64>) + Send + 'static,
+    {
+        let handle = Arc::clone(self);
+        let stopped = Arc::new(AtomicBool::new(false));
+        let thread_stopped = Arc::clone(&stopped);
+        let mut last_seen = handle.modified_times();
+        let thread = thread::spawn(move || {
+            while !thread_stopped.load(Ordering::Acquire) {
+                thread::park_timeout(interval);
+                let modified = handle.modified_times();
+                if modified != last_seen {
+                    // A failed reload, e.g. of a file still being written, is retried on the
+                    // next poll
+                    let reloaded = handle.reload();
+                    if reloaded.is_ok() {
+                        last_seen = modified;
+                    }
+                    on_reload(reloaded);
+                }
+            }
+        });
+        DictionaryWatcher {
+            stopped,
+            thread: Some(thread),
+        }
+    }
+
+    // Callers hold `reload_lock` from the snapshot of the codecs until the swap, so that
+    // concurrent reloads and registrations are applied one after the other.
+    fn swap(&self, dictionary: Dictionary) -> DiameterResult<u64> {
+        if let Some(ref validator) = self.validator {
+            validator(&dictionary)?;
+        }
+        *self.current.write().unwrap() = Arc::new(dictionary);
+        Ok(self.version.fetch_add(1, Ordering::AcqRel) + 1)
+    }
+
+    fn modified_times(&self) -> Vec<Option<SystemTime>> {
+        self.sources
+            .iter()
+            .map(|source| source.modified())
+            .collect()
+    }
+
+    fn empty_with_codecs(&self) -> Dictionary {
+        let current = self.load();
+        let mut dictionary = Dictionary::new(&[]);
+        for (type_name, codec) in current.codecs() {
+            dictionary.add_codec(type_name, codec);
+        }
+        dictionary
+    }
+
+    fn build(
+        sources: &[DictionarySource],
+        mut dictionary: Dictionary,
+    ) -> DiameterResult<Dictionary> {
+        for source in sources {
+            source.load_into(&mut dictionary)?;
+        }
+        Ok(dictionary)
+    }
+}
+
+impl DictionaryWatcher {
+    pub fn stop(mut self) {
+        self.shutdown();
+    }
+
+    fn shutdown(&mut self) {
+        self.stopped.store(true, Ordering::Release);
+        if let Some(thread) = self.thread.take() {
+            thread.thread().unpark();
+            let _ = thread.join();
+        }
+    }
+}
+
+impl Drop for DictionaryWatcher {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
@@ -0,0 +1,175 @@
+//! # freeDiameter Dictionary Import
+//!
+//! Populates a `Dictionary` from the JSON dictionaries loaded by freeDiameter's `dict_json`
+//! extension:
+//! ```text
+//!  {
+//!    "Vendors": [ { "Code": 10415, "Name": "3GPP" } ],
+//!    "Types": [ { "Name": "TBCDString", "Base": "OctetString" } ],
+//!    "Applications": [ { "Code": 16777238, "Name": "Gx" } ],
+//!    "Commands": [
+//!      { "Code": 272, "Flags": { "Must": "RP", "MustNot": "E" }, "Name": "Credit-Control-Request" },
+//!      { "Code": 272, "Flags": { "Must": "P", "MustNot": "R" }, "Name": "Credit-Control-Answer" }
+//!    ],
+//!    "AVPs": [
+//!      { "Code": 1028, "Flags": { "Must": "MV", "MustNot": "" }, "Vendor": 10415,
+//!        "Name": "QoS-Class-Identifier", "Type": "Enumerated",
+//!        "EnumValues": [ { "Code": 1, "Name": "QCI_1" } ] }
+//!    ]
+//!  }
+//! ```
+//! Types declared under `Types` resolve through their `Base` chain as in the Wireshark import.
+//! Request and answer commands are added once, by name without the `-Request`/`-Answer`
+//! suffix. Enumerated values with non-integer codes (freeDiameter allows OctetString
+//! enumerations) and the `CommandRules`/`AVPRules` sections are ignored.
+//! Applications and commands unknown to `ApplicationId`/`CommandCode` are skipped and listed
+//! in the returned `FreeDiameterImport`.
+//!
+//! freeDiameter's `dict_legacy_xml` files use the Wireshark format, load them with
+//! `Dictionary::load_wireshark_xml`.
+
+use crate::errors::DiameterResult;
+use crate::errors::Error::DictionaryError;
+use crate::modeling::message::application_id::ApplicationId;
+use crate::modeling::message::command_code::CommandCode;
+use crate::modeling::message::dictionary::{AvpDefinition, Dictionary};
+use crate::modeling::message::wireshark::resolve_type;
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap};
+
+/// The applications and commands of a freeDiameter dictionary that were not imported, by code
+/// and name.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FreeDiameterImport {
+    pub skipped_applications: Vec<(u32, String)>,
+    pub skipped_commands: Vec<(u32, String)>,
+}
+
+impl FreeDiameterImport {
+    /// Whether every application and command was imported.
+    pub fn is_complete(&self) -> bool {
+        self.skipped_applications.is_empty() && self.skipped_commands.is_empty()
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct Document {
+    #[serde(default)]
+    vendors: Vec<Entry>,
+    #[serde(default)]
+    types: Vec<TypeEntry>,
+    #[serde(default)]
+    applications: Vec<Entry>,
+    #[serde(default)]
+    commands: Vec<Entry>,
+    #[serde(default, rename = "AVPs")]
+    avps: Vec<AvpEntry>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct Entry {
+    code: u32,
+    name: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct TypeEntry {
+    name: String,
+    base: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct AvpEntry {
+    code: u32,
+    name: String,
+    #[serde(rename = "Type")]
+    avp_type: String,
+    #[serde(default)]
+    vendor: u32,
+    #[serde(default)]
+    flags: Flags,
+    #[serde(default)]
+    enum_values: Vec<EnumEntry>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "PascalCase")]
+struct Flags {
+    #[serde(default)]
+    must: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct EnumEntry {
+    code: serde_json::Value,
+    name: String,
+}
+
+pub fn parse(json: &str, dictionary: &mut Dictionary) -> DiameterResult<FreeDiameterImport> {
+    let document: Document = serde_json::from_str(json)
+        .map_err(|_| DictionaryError("Malformed freeDiameter dictionary JSON"))?;
+    let mut import = FreeDiameterImport::default();
+
+    for vendor in document.vendors {
+        if vendor.code != 0 {
+            dictionary.add_vendor(vendor.code, &vendor.name);
+        }
+    }
+
+    for application in document.applications {
+        match ApplicationId::try_from(application.code) {
+            Ok(application_id) => dictionary.add_application(&application.name, application_id),
+            Err(_) => import
+                .skipped_applications
+                .push((application.code, application.name)),
+        }
+    }
+
+    for command in document.commands {
+        let name = command
+            .name
+            .strip_suffix("-Request")
+            .or_else(|| command.name.strip_suffix("-Answer"))
+            .unwrap_or(&command.name);
+        match CommandCode::try_from(command.code) {
+            Ok(command_code) => dictionary.add_command(name, command_code),
+            Err(_) => {
+                let skipped = (command.code, name.to_string());
+                if !import.skipped_commands.contains(&skipped) {
+                    import.skipped_commands.push(skipped);
+                }
+            }
+        }
+    }
+
+    let typedefs: HashMap<String, Option<String>> = document
+        .types
+        .into_iter()
+        .map(|typedef| (typedef.name, Some(typedef.base)))
+        .collect();
+
+    for avp in document.avps {
+        let enum_values: BTreeMap<i32, String> = avp
+            .enum_values
+            .into_iter()
+            .filter_map(|item| {
+                let code = i32::try_from(item.code.as_i64()?).ok()?;
+                Some((code, item.name))
+            })
+            .collect();
+        dictionary.add_avp(AvpDefinition {
+            code: avp.code,
+            vendor_id: (avp.vendor != 0).then_some(avp.vendor),
+            name: avp.name,
+            avp_type: resolve_type(&avp.avp_type, &typedefs, dictionary),
+            m_flag: avp.flags.must.contains('M'),
+            enum_values,
+        });
+    }
+    Ok(import)
+}
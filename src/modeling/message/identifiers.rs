@@ -0,0 +1,133 @@
+//! # Message Identifiers
+//!
+//! Generators for the header identifiers and the Session-Id AVP of requests (RFC 6733 §3 and
+//! §8.8). All of them take `&self` and can be shared between threads:
+//! ```
+//! use diameter_interface::modeling::message::identifiers::{
+//!     EndToEndGenerator, HopByHopGenerator, SessionIdGenerator,
+//! };
+//!
+//! let sessions = SessionIdGenerator::new("client.example.com");
+//! let hop_by_hop = HopByHopGenerator::new();
+//! let end_to_end = EndToEndGenerator::new();
+//!
+//! let session_id = sessions.next();
+//! assert!(session_id.starts_with("client.example.com;"));
+//! assert_ne!(sessions.next(), session_id);
+//! assert_eq!(hop_by_hop.next().wrapping_add(1), hop_by_hop.next());
+//! assert_ne!(end_to_end.next(), end_to_end.next());
+//! ```
+//! Their initial values are derived from the current time and a per-process random value, so
+//! identifiers are not reused after a restart. The low 32 bits of Session-Ids can be seeded
+//! with the Origin-State-Id instead, which changes on every restart as well.
+
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Hop-by-Hop identifiers of one connection, increasing monotonically from a random start.
+#[derive(Debug)]
+pub struct HopByHopGenerator {
+    next: AtomicU32,
+}
+
+/// End-to-End identifiers, the high 12 bits are the low 12 bits of the current time in
+/// seconds and the low 20 bits start at a random value.
+#[derive(Debug)]
+pub struct EndToEndGenerator {
+    next: AtomicU32,
+}
+
+/// Session-Ids in the `<DiameterIdentity>;<high 32 bits>;<low 32 bits>[;<optional value>]`
+/// format, the high 32 bits are initialized with the current time in seconds and the low 32
+/// bits with a random value. The low bits are incremented for every Session-Id, carrying into
+/// the high bits when they wrap.
+#[derive(Debug)]
+pub struct SessionIdGenerator {
+    identity: String,
+    next: AtomicU64,
+}
+
+impl HopByHopGenerator {
+    pub fn new() -> Self {
+        Self {
+            next: AtomicU32::new(random() as u32),
+        }
+    }
+
+    pub fn next(&self) -> u32 {
+        self.next.fetch_add(1, Ordering::Relaxed)
+    }
+}
+
+impl Default for HopByHopGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EndToEndGenerator {
+    pub fn new() -> Self {
+        let time = (unix_time() as u32 & 0xfff) << 20;
+        Self {
+            next: AtomicU32::new(time | (random() as u32 & 0xfffff)),
+        }
+    }
+
+    pub fn next(&self) -> u32 {
+        self.next.fetch_add(1, Ordering::Relaxed)
+    }
+}
+
+impl Default for EndToEndGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SessionIdGenerator {
+    /// `identity` is the Origin-Host of the sender.
+    pub fn new(identity: &str) -> Self {
+        Self {
+            identity: identity.to_string(),
+            next: AtomicU64::new((unix_time() as u32 as u64) << 32 | random() as u32 as u64),
+        }
+    }
+
+    /// Seeds the low 32 bits with the Origin-State-Id of the node instead of a random value.
+    pub fn with_origin_state_id(mut self, origin_state_id: u32) -> Self {
+        let high = *self.next.get_mut() & 0xffff_ffff_0000_0000;
+        *self.next.get_mut() = high | origin_state_id as u64;
+        self
+    }
+
+    pub fn next(&self) -> String {
+        let value = self.next.fetch_add(1, Ordering::Relaxed);
+        format!("{};{};{}", self.identity, value >> 32, value as u32)
+    }
+
+    /// A Session-Id with an implementation specific `optional` part appended.
+    pub fn next_with(&self, optional: &str) -> String {
+        format!("{};{}", self.next(), optional)
+    }
+}
+
+fn unix_time() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or(0)
+}
+
+/// A value that differs between processes, from the randomly keyed std hasher.
+fn random() -> u64 {
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u128(
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_nanos())
+            .unwrap_or(0),
+    );
+    hasher.finish()
+}
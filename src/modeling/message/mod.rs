@@ -1,4 +1,16 @@
 pub mod application_id;
+pub mod base;
 pub mod dictionary;
+pub mod dictionary_format;
+#[cfg(feature = "std")]
+pub mod dictionary_handle;
+#[cfg(feature = "json")]
+pub mod freediameter;
 pub mod command_code;
 pub mod command_flags;
+pub mod credit_control;
+#[cfg(feature = "std")]
+pub mod identifiers;
+pub mod result_code;
+#[cfg(feature = "std")]
+pub mod wireshark;
@@ -0,0 +1,279 @@
+//! # Result Codes
+//!
+//! The outcome of an answer, carried either in a Result-Code AVP or, for vendor-specific
+//! codes, in the Experimental-Result-Code of an Experimental-Result AVP:
+//! ```
+//! use diameter_interface::modeling::avp::avp::AvpFlags;
+//! use diameter_interface::modeling::avp::unsigned32::Unsigned32;
+//! use diameter_interface::modeling::diameter::DiameterMessage;
+//! use diameter_interface::modeling::message::application_id::ApplicationId;
+//! use diameter_interface::modeling::message::command_code::CommandCode;
+//! use diameter_interface::modeling::message::command_flags::CommandFlag;
+//! use diameter_interface::modeling::message::result_code::{ResultClass, ResultCode};
+//!
+//! let mut answer = DiameterMessage::new(
+//!     CommandFlag::Proxyable, CommandCode::CreditControl, ApplicationId::CreditControl, 1, 1,
+//! );
+//! answer.add_avp(268, AvpFlags::M, None, Unsigned32::new(4012));
+//!
+//! match answer.result_code() {
+//!     Some(code) if code.is_success() => {}
+//!     Some(ResultCode::ERROR_USER_UNKNOWN) => println!("unknown subscriber"),
+//!     Some(code) if code.class() == ResultClass::TransientFailure => {
+//!         assert_eq!(code, ResultCode::CREDIT_LIMIT_REACHED)
+//!     }
+//!     code => println!("failed: {:?}", code),
+//! }
+//! ```
+//! Codes of RFC 6733 and RFC 4006 are plain Result-Code values, the 3GPP ones are
+//! experimental codes of vendor 10415 and compare unequal to an RFC code with the same number.
+
+use crate::modeling::avp::avp::{Avp, AvpFlags};
+use crate::modeling::avp::group::Grouped;
+use crate::modeling::avp::unsigned32::Unsigned32;
+use alloc::vec;
+use core::fmt;
+
+const RESULT_CODE: u32 = 268;
+const VENDOR_ID: u32 = 266;
+const EXPERIMENTAL_RESULT: u32 = 297;
+const EXPERIMENTAL_RESULT_CODE: u32 = 298;
+
+const VENDOR_3GPP: u32 = 10415;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ResultCode {
+    vendor_id: Option<u32>,
+    value: u32,
+}
+
+/// The class of a result code, given by its thousands digit (RFC 6733 §7.1).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ResultClass {
+    Informational,
+    Success,
+    ProtocolError,
+    TransientFailure,
+    PermanentFailure,
+}
+
+impl ResultCode {
+    /// A Result-Code value.
+    pub const fn new(value: u32) -> Self {
+        Self {
+            vendor_id: None,
+            value,
+        }
+    }
+
+    /// An Experimental-Result-Code value defined by `vendor_id`.
+    pub const fn experimental(vendor_id: u32, value: u32) -> Self {
+        Self {
+            vendor_id: Some(vendor_id),
+            value,
+        }
+    }
+
+    pub fn value(&self) -> u32 {
+        self.value
+    }
+
+    /// The vendor of an experimental code, `None` for a Result-Code.
+    pub fn vendor_id(&self) -> Option<u32> {
+        self.vendor_id
+    }
+
+    pub fn is_experimental(&self) -> bool {
+        self.vendor_id.is_some()
+    }
+
+    /// Values outside the defined classes are treated as permanent failures.
+    pub fn class(&self) -> ResultClass {
+        match self.value / 1000 {
+            1 => ResultClass::Informational,
+            2 => ResultClass::Success,
+            3 => ResultClass::ProtocolError,
+            4 => ResultClass::TransientFailure,
+            _ => ResultClass::PermanentFailure,
+        }
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.class() == ResultClass::Success
+    }
+
+    /// Protocol errors are answered with the E bit set.
+    pub fn is_protocol_error(&self) -> bool {
+        self.class() == ResultClass::ProtocolError
+    }
+
+    pub fn is_failure(&self) -> bool {
+        matches!(
+            self.class(),
+            ResultClass::ProtocolError
+                | ResultClass::TransientFailure
+                | ResultClass::PermanentFailure
+        )
+    }
+
+    /// The specification name of a known code, e.g. `DIAMETER_SUCCESS`.
+    pub fn name(&self) -> Option<&'static str> {
+        NAMES
+            .iter()
+            .find(|(code, _)| code == self)
+            .map(|(_, name)| *name)
+    }
+
+    /// Encodes the code as a Result-Code AVP, or as an Experimental-Result AVP for an
+    /// experimental code.
+    pub fn to_avp(&self) -> Avp {
+        match self.vendor_id {
+            None => Avp::new(RESULT_CODE, AvpFlags::M, None, Unsigned32::new(self.value)),
+            Some(vendor_id) => Avp::new(
+                EXPERIMENTAL_RESULT,
+                AvpFlags::M,
+                None,
+                Grouped::new(vec![
+                    Avp::new(VENDOR_ID, AvpFlags::M, None, Unsigned32::new(vendor_id)),
+                    Avp::new(
+                        EXPERIMENTAL_RESULT_CODE,
+                        AvpFlags::M,
+                        None,
+                        Unsigned32::new(self.value),
+                    ),
+                ]),
+            ),
+        }
+    }
+
+    /// Reads the outcome from the top-level AVPs of an answer, preferring Result-Code over
+    /// Experimental-Result.
+    pub fn from_avps(avps: &[Avp]) -> Option<Self> {
+        let result_code = avps
+            .iter()
+            .find(|avp| avp.code() == RESULT_CODE && avp.vendor_id().is_none());
+        if let Some(value) = result_code.and_then(|avp| avp.value().as_u32()) {
+            return Some(Self::new(value));
+        }
+        let experimental_result = avps
+            .iter()
+            .find(|avp| avp.code() == EXPERIMENTAL_RESULT && avp.vendor_id().is_none())?
+            .value()
+            .as_grouped()?;
+        let find = |code: u32| {
+            experimental_result
+                .iter()
+                .find(|avp| avp.code() == code && avp.vendor_id().is_none())
+                .and_then(|avp| avp.value().as_u32())
+        };
+        Some(Self::experimental(
+            find(VENDOR_ID)?,
+            find(EXPERIMENTAL_RESULT_CODE)?,
+        ))
+    }
+}
+
+impl fmt::Display for ResultCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.name(), self.vendor_id) {
+            (Some(name), _) => write!(f, "{} ({})", name, self.value),
+            (None, None) => write!(f, "{}", self.value),
+            (None, Some(vendor_id)) => write!(f, "{} (vendor {})", self.value, vendor_id),
+        }
+    }
+}
+
+impl From<u32> for ResultCode {
+    fn from(value: u32) -> Self {
+        Self::new(value)
+    }
+}
+
+macro_rules! result_codes {
+    ($($name:ident = $code:expr,)*) => {
+        impl ResultCode {
+            $(pub const $name: ResultCode = $code;)*
+        }
+
+        const NAMES: &[(ResultCode, &str)] = &[
+            $((ResultCode::$name, concat!("DIAMETER_", stringify!($name))),)*
+        ];
+    };
+}
+
+result_codes! {
+    // RFC 6733
+    MULTI_ROUND_AUTH = ResultCode::new(1001),
+    SUCCESS = ResultCode::new(2001),
+    LIMITED_SUCCESS = ResultCode::new(2002),
+    COMMAND_UNSUPPORTED = ResultCode::new(3001),
+    UNABLE_TO_DELIVER = ResultCode::new(3002),
+    REALM_NOT_SERVED = ResultCode::new(3003),
+    TOO_BUSY = ResultCode::new(3004),
+    LOOP_DETECTED = ResultCode::new(3005),
+    REDIRECT_INDICATION = ResultCode::new(3006),
+    APPLICATION_UNSUPPORTED = ResultCode::new(3007),
+    INVALID_HDR_BITS = ResultCode::new(3008),
+    INVALID_AVP_BITS = ResultCode::new(3009),
+    UNKNOWN_PEER = ResultCode::new(3010),
+    AUTHENTICATION_REJECTED = ResultCode::new(4001),
+    OUT_OF_SPACE = ResultCode::new(4002),
+    ELECTION_LOST = ResultCode::new(4003),
+    AVP_UNSUPPORTED = ResultCode::new(5001),
+    UNKNOWN_SESSION_ID = ResultCode::new(5002),
+    AUTHORIZATION_REJECTED = ResultCode::new(5003),
+    INVALID_AVP_VALUE = ResultCode::new(5004),
+    MISSING_AVP = ResultCode::new(5005),
+    RESOURCES_EXCEEDED = ResultCode::new(5006),
+    CONTRADICTING_AVPS = ResultCode::new(5007),
+    AVP_NOT_ALLOWED = ResultCode::new(5008),
+    AVP_OCCURS_TOO_MANY_TIMES = ResultCode::new(5009),
+    NO_COMMON_APPLICATION = ResultCode::new(5010),
+    UNSUPPORTED_VERSION = ResultCode::new(5011),
+    UNABLE_TO_COMPLY = ResultCode::new(5012),
+    INVALID_BIT_IN_HEADER = ResultCode::new(5013),
+    INVALID_AVP_LENGTH = ResultCode::new(5014),
+    INVALID_MESSAGE_LENGTH = ResultCode::new(5015),
+    INVALID_AVP_BIT_COMBO = ResultCode::new(5016),
+    NO_COMMON_SECURITY = ResultCode::new(5017),
+
+    // RFC 4006
+    END_USER_SERVICE_DENIED = ResultCode::new(4010),
+    CREDIT_CONTROL_NOT_APPLICABLE = ResultCode::new(4011),
+    CREDIT_LIMIT_REACHED = ResultCode::new(4012),
+    USER_UNKNOWN = ResultCode::new(5030),
+    RATING_FAILED = ResultCode::new(5031),
+
+    // 3GPP TS 29.229 and TS 29.272
+    FIRST_REGISTRATION = ResultCode::experimental(VENDOR_3GPP, 2001),
+    SUBSEQUENT_REGISTRATION = ResultCode::experimental(VENDOR_3GPP, 2002),
+    UNREGISTERED_SERVICE = ResultCode::experimental(VENDOR_3GPP, 2003),
+    SUCCESS_SERVER_NAME_NOT_STORED = ResultCode::experimental(VENDOR_3GPP, 2004),
+    AUTHENTICATION_DATA_UNAVAILABLE = ResultCode::experimental(VENDOR_3GPP, 4181),
+    ERROR_USER_UNKNOWN = ResultCode::experimental(VENDOR_3GPP, 5001),
+    ERROR_IDENTITIES_DONT_MATCH = ResultCode::experimental(VENDOR_3GPP, 5002),
+    ERROR_IDENTITY_NOT_REGISTERED = ResultCode::experimental(VENDOR_3GPP, 5003),
+    ERROR_ROAMING_NOT_ALLOWED = ResultCode::experimental(VENDOR_3GPP, 5004),
+    ERROR_IDENTITY_ALREADY_REGISTERED = ResultCode::experimental(VENDOR_3GPP, 5005),
+    ERROR_AUTH_SCHEME_NOT_SUPPORTED = ResultCode::experimental(VENDOR_3GPP, 5006),
+    ERROR_IN_ASSIGNMENT_TYPE = ResultCode::experimental(VENDOR_3GPP, 5007),
+    ERROR_TOO_MUCH_DATA = ResultCode::experimental(VENDOR_3GPP, 5008),
+    ERROR_NOT_SUPPORTED_USER_DATA = ResultCode::experimental(VENDOR_3GPP, 5009),
+    ERROR_UNKNOWN_EPS_SUBSCRIPTION = ResultCode::experimental(VENDOR_3GPP, 5420),
+    ERROR_RAT_NOT_ALLOWED = ResultCode::experimental(VENDOR_3GPP, 5421),
+    ERROR_EQUIPMENT_UNKNOWN = ResultCode::experimental(VENDOR_3GPP, 5422),
+    ERROR_UNKNOWN_SERVING_NODE = ResultCode::experimental(VENDOR_3GPP, 5423),
+
+    // 3GPP TS 29.212
+    PCC_BEARER_EVENT = ResultCode::experimental(VENDOR_3GPP, 4141),
+    BEARER_EVENT = ResultCode::experimental(VENDOR_3GPP, 4142),
+    AN_GW_FAILED = ResultCode::experimental(VENDOR_3GPP, 4143),
+    PENDING_TRANSACTION = ResultCode::experimental(VENDOR_3GPP, 4144),
+    ERROR_INITIAL_PARAMETERS = ResultCode::experimental(VENDOR_3GPP, 5140),
+    ERROR_TRIGGER_EVENT = ResultCode::experimental(VENDOR_3GPP, 5141),
+    PCC_RULE_EVENT = ResultCode::experimental(VENDOR_3GPP, 5142),
+    ERROR_BEARER_NOT_AUTHORIZED = ResultCode::experimental(VENDOR_3GPP, 5143),
+    ERROR_TRAFFIC_MAPPING_INFO_REJECTED = ResultCode::experimental(VENDOR_3GPP, 5144),
+    ERROR_CONFLICTING_REQUEST = ResultCode::experimental(VENDOR_3GPP, 5147),
+    ADC_RULE_EVENT = ResultCode::experimental(VENDOR_3GPP, 5148),
+}
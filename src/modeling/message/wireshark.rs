@@ -0,0 +1,312 @@
+//! # Wireshark Dictionary Import
+//!
+//! Populates a `Dictionary` from the `diameter/*.xml` files shipped with Wireshark:
+//! ```text
+//!  <dictionary>
+//!    <base>
+//!      <typedefn type-name="UTF8String" type-parent="OctetString"/>
+//!      <avp name="Session-Id" code="263" mandatory="must" vendor-bit="mustnot">
+//!        <type type-name="UTF8String"/>
+//!      </avp>
+//!    </base>
+//!    <vendor vendor-id="TGPP" code="10415" name="3GPP"/>
+//!    <application id="16777238" name="3GPP Gx">
+//!      <avp name="Charging-Rule-Install" code="1001" vendor-id="TGPP" mandatory="must">
+//!        <grouped><gavp name="Charging-Rule-Definition"/></grouped>
+//!      </avp>
+//!    </application>
+//!    &TGPP;
+//!  </dictionary>
+//! ```
+//! Types declared with `<typedefn>` resolve through their `type-parent` chain to the closest
+//! built-in type, or to the first type in the chain with a codec registered in the dictionary.
+//! Types that resolve to neither are kept by name as `AvpType::Custom`. AVP `vendor-id` names
+//! resolve through the `<vendor>` definitions.
+//! Applications and commands unknown to `ApplicationId`/`CommandCode` are skipped and listed
+//! in the returned `WiresharkImport`, the AVPs they define are imported.
+
+use crate::errors::DiameterResult;
+use crate::errors::Error::DictionaryError;
+use crate::modeling::avp::avp::AvpType;
+use crate::modeling::message::application_id::ApplicationId;
+use crate::modeling::message::command_code::CommandCode;
+use crate::modeling::message::dictionary::{
+    AvpDefinition, Dictionary, avp_type_from_name, parse_enum_value, parse_number,
+};
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::Path;
+use xml::reader::{EventReader, XmlEvent};
+
+const MAX_TYPEDEF_DEPTH: usize = 16;
+
+/// The applications and commands of a Wireshark dictionary that were not imported, by code
+/// and name.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WiresharkImport {
+    pub skipped_applications: Vec<(u32, String)>,
+    pub skipped_commands: Vec<(u32, String)>,
+}
+
+#[derive(Debug, Default)]
+struct Element {
+    name: String,
+    attributes: HashMap<String, String>,
+    children: Vec<Element>,
+}
+
+impl Element {
+    fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes.get(name).map(|value| value.as_str())
+    }
+
+    fn child(&self, name: &str) -> Option<&Element> {
+        self.children.iter().find(|child| child.name == name)
+    }
+
+    fn descendants<'a>(&'a self, name: &str, found: &mut Vec<&'a Element>) {
+        for child in &self.children {
+            if child.name == name {
+                found.push(child);
+            }
+            child.descendants(name, found);
+        }
+    }
+}
+
+impl WiresharkImport {
+    /// Whether every application and command was imported.
+    pub fn is_complete(&self) -> bool {
+        self.skipped_applications.is_empty() && self.skipped_commands.is_empty()
+    }
+}
+
+pub fn parse(xml: &str, dictionary: &mut Dictionary) -> DiameterResult<WiresharkImport> {
+    let xml = expand_entities(xml, None)?;
+    load(&read_tree(&xml)?, dictionary)
+}
+
+pub fn parse_file(path: &Path, dictionary: &mut Dictionary) -> DiameterResult<WiresharkImport> {
+    let xml = fs::read_to_string(path)?;
+    let xml = expand_entities(&xml, Some(path.parent().unwrap_or(Path::new("."))))?;
+    load(&read_tree(&xml)?, dictionary)
+}
+
+fn load(root: &Element, dictionary: &mut Dictionary) -> DiameterResult<WiresharkImport> {
+    let mut import = WiresharkImport::default();
+    let mut vendors: HashMap<String, u32> = HashMap::new();
+    let mut elements = vec![];
+    root.descendants("vendor", &mut elements);
+    for vendor in elements.drain(..) {
+        if let (Some(name), Some(code)) = (vendor.attribute("vendor-id"), vendor.attribute("code"))
+        {
+            let code = parse_number(code)?;
+            if code != 0 {
+                dictionary.add_vendor(code, vendor.attribute("name").unwrap_or(name));
+            }
+            vendors.insert(name.to_string(), code);
+        }
+    }
+
+    let mut typedefs: HashMap<String, Option<String>> = HashMap::new();
+    root.descendants("typedefn", &mut elements);
+    for typedef in elements.drain(..) {
+        if let Some(name) = typedef.attribute("type-name") {
+            let parent = typedef.attribute("type-parent").map(|p| p.to_string());
+            typedefs.insert(name.to_string(), parent);
+        }
+    }
+
+    for section in &root.children {
+        match section.name.as_str() {
+            "base" => load_section(section, &vendors, &typedefs, dictionary, &mut import)?,
+            "application" => {
+                let id = section
+                    .attribute("id")
+                    .ok_or(DictionaryError("Wireshark application without id"))?;
+                let code = parse_number(id)?;
+                let name = section.attribute("name").unwrap_or(id);
+                match ApplicationId::try_from(code) {
+                    Ok(application_id) => dictionary.add_application(name, application_id),
+                    Err(_) => import.skipped_applications.push((code, name.to_string())),
+                }
+                load_section(section, &vendors, &typedefs, dictionary, &mut import)?
+            }
+            _ => {}
+        }
+    }
+    Ok(import)
+}
+
+fn load_section(
+    section: &Element,
+    vendors: &HashMap<String, u32>,
+    typedefs: &HashMap<String, Option<String>>,
+    dictionary: &mut Dictionary,
+    import: &mut WiresharkImport,
+) -> DiameterResult<()> {
+    for element in &section.children {
+        match element.name.as_str() {
+            "command" => {
+                let (Some(name), Some(code)) =
+                    (element.attribute("name"), element.attribute("code"))
+                else {
+                    Err(DictionaryError("Wireshark command without name or code"))?
+                };
+                let code = parse_number(code)?;
+                match CommandCode::try_from(code) {
+                    Ok(command_code) => dictionary.add_command(name, command_code),
+                    Err(_) => import.skipped_commands.push((code, name.to_string())),
+                }
+            }
+            "avp" => {
+                let avp = avp_definition(element, vendors, typedefs, dictionary)?;
+                dictionary.add_avp(avp)
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+fn avp_definition(
+    avp: &Element,
+    vendors: &HashMap<String, u32>,
+    typedefs: &HashMap<String, Option<String>>,
+    dictionary: &Dictionary,
+) -> DiameterResult<AvpDefinition> {
+    let (Some(name), Some(code)) = (avp.attribute("name"), avp.attribute("code")) else {
+        Err(DictionaryError("Wireshark AVP without name or code"))?
+    };
+
+    let vendor_id = match avp.attribute("vendor-id") {
+        None | Some("None") => None,
+        Some(vendor) => Some(
+            *vendors
+                .get(vendor)
+                .ok_or(DictionaryError("Unknown vendor in Wireshark dictionary"))?,
+        ),
+    };
+
+    let avp_type = match (avp.child("grouped"), avp.child("type")) {
+        (Some(_), _) => AvpType::Grouped,
+        (None, Some(data_type)) => match data_type.attribute("type-name") {
+            Some(type_name) => resolve_type(type_name, typedefs, dictionary),
+            None => AvpType::Unknown,
+        },
+        (None, None) => AvpType::Unknown,
+    };
+
+    let mut enum_values = BTreeMap::new();
+    for item in avp.children.iter().filter(|child| child.name == "enum") {
+        if let (Some(name), Some(value)) = (item.attribute("name"), item.attribute("code")) {
+            enum_values.insert(parse_enum_value(value)?, name.to_string());
+        }
+    }
+
+    Ok(AvpDefinition {
+        code: parse_number(code)?,
+        vendor_id,
+        name: name.to_string(),
+        avp_type,
+        m_flag: avp.attribute("mandatory") == Some("must"),
+        enum_values,
+    })
+}
+
+pub(crate) fn resolve_type(
+    type_name: &str,
+    typedefs: &HashMap<String, Option<String>>,
+    dictionary: &Dictionary,
+) -> AvpType {
+    let mut current = type_name;
+    for _ in 0..MAX_TYPEDEF_DEPTH {
+        if dictionary.get_codec(current).is_some() {
+            return AvpType::Custom(current.to_string());
+        }
+        // Wireshark derives IPAddress from OctetString, it is the RFC 6733 Address type
+        if current == "IPAddress" {
+            return AvpType::Address;
+        }
+        if let Some(avp_type) = avp_type_from_name(current) {
+            return avp_type;
+        }
+        match typedefs.get(current) {
+            Some(Some(parent)) => current = parent,
+            _ => break,
+        }
+    }
+    AvpType::Custom(type_name.to_string())
+}
+
+fn read_tree(xml: &str) -> DiameterResult<Element> {
+    let mut stack: Vec<Element> = vec![Element::default()];
+    for event in EventReader::from_str(xml) {
+        match event.map_err(|_| DictionaryError("Malformed Wireshark dictionary XML"))? {
+            XmlEvent::StartElement {
+                name, attributes, ..
+            } => stack.push(Element {
+                name: name.local_name,
+                attributes: attributes
+                    .into_iter()
+                    .map(|attribute| (attribute.name.local_name, attribute.value))
+                    .collect(),
+                children: vec![],
+            }),
+            XmlEvent::EndElement { .. } => {
+                let element = stack.pop().unwrap();
+                stack.last_mut().unwrap().children.push(element);
+            }
+            _ => {}
+        }
+    }
+    stack
+        .pop()
+        .and_then(|document| document.children.into_iter().next())
+        .ok_or(DictionaryError("Empty Wireshark dictionary"))
+}
+
+/// Removes the DOCTYPE and replaces every `&name;` declared as `<!ENTITY name SYSTEM "file">`
+/// with the content of that file.
+fn expand_entities(xml: &str, base_dir: Option<&Path>) -> DiameterResult<String> {
+    let Some(start) = xml.find("<!DOCTYPE") else {
+        return Ok(xml.to_string());
+    };
+    let end = match (xml[start..].find('['), xml[start..].find('>')) {
+        (Some(open), Some(close)) if open < close => {
+            xml[start..].find("]>").map(|end| start + end + 2)
+        }
+        (_, Some(close)) => Some(start + close + 1),
+        _ => None,
+    }
+    .ok_or(DictionaryError("Malformed Wireshark dictionary DOCTYPE"))?;
+
+    let mut expanded = format!("{}{}", &xml[..start], &xml[end..]);
+    for declaration in xml[start..end].split("<!ENTITY").skip(1) {
+        let mut tokens = declaration.split_whitespace();
+        let (Some(name), Some("SYSTEM"), Some(file)) =
+            (tokens.next(), tokens.next(), tokens.next())
+        else {
+            continue;
+        };
+        let reference = format!("&{};", name);
+        if !expanded.contains(&reference) {
+            continue;
+        }
+        let base_dir = base_dir.ok_or(DictionaryError(
+            "Wireshark dictionary entities require loading from a file",
+        ))?;
+        let file = file.trim_end_matches('>').trim_matches('"');
+        let content = fs::read_to_string(base_dir.join(file))?;
+        expanded = expanded.replace(&reference, strip_prolog(&content));
+    }
+    Ok(expanded)
+}
+
+fn strip_prolog(xml: &str) -> &str {
+    let xml = xml.trim_start_matches('\u{feff}').trim_start();
+    match xml.starts_with("<?xml") {
+        true => xml.find("?>").map(|end| &xml[end + 2..]).unwrap_or(xml),
+        false => xml,
+    }
+}
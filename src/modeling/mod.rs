@@ -1,3 +1,13 @@
 pub mod avp;
+pub mod codec;
+pub mod decode_policy;
 pub mod diameter;
+pub mod diff;
+pub mod display;
 pub mod message;
+pub mod named;
+pub mod raw;
+#[cfg(feature = "serde")]
+mod serialization;
+pub mod typed;
+pub mod view;
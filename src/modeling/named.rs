@@ -0,0 +1,678 @@
+//! # Named Messages
+//!
+//! A dictionary-aware form of a message, with command, AVP and enumeration names and plain
+//! values. With the `serde` feature it serializes to, and deserializes from, JSON like:
+//! ```json
+//! {
+//!   "command": "Credit-Control-Request",
+//!   "application_id": 4,
+//!   "proxyable": true,
+//!   "hop_by_hop": 1,
+//!   "end_to_end": 2,
+//!   "avps": [
+//!     { "Session-Id": "host;1;1" },
+//!     { "CC-Request-Type": "INITIAL_REQUEST" },
+//!     { "Subscription-Id": [
+//!       { "Subscription-Id-Type": "END_USER_IMSI" },
+//!       { "Subscription-Id-Data": "001010123456789" }
+//!     ] }
+//!   ]
+//! }
+//! ```
+//! ```
+//! use diameter_interface::modeling::avp::avp::AvpFlags;
+//! use diameter_interface::modeling::avp::enumerated::Enumerated;
+//! use diameter_interface::modeling::avp::utf8_string::UTF8String;
+//! use diameter_interface::modeling::diameter::DiameterMessage;
+//! use diameter_interface::modeling::message::application_id::ApplicationId;
+//! use diameter_interface::modeling::message::command_code::CommandCode;
+//! use diameter_interface::modeling::message::command_flags::CommandFlag;
+//! use diameter_interface::modeling::message::dictionary::{self, Dictionary};
+//! use diameter_interface::modeling::named::{NamedAvp, NamedMessage};
+//!
+//! let dict = Dictionary::new(&[&dictionary::DEFAULT_DICT_XML]);
+//! let mut message = DiameterMessage::new(
+//!     CommandFlag::Request,
+//!     CommandCode::CreditControl,
+//!     ApplicationId::Gx,
+//!     1,
+//!     2,
+//! );
+//! message.add_avp(263, AvpFlags::M, None, UTF8String::new("host;1;1".to_string()));
+//! message.add_avp(416, AvpFlags::M, None, Enumerated::new(1));
+//!
+//! let named = NamedMessage::from_message(&message, &dict);
+//! assert_eq!(named.command, "Credit-Control-Request");
+//! assert_eq!(named.avps, vec![
+//!     NamedAvp::new("Session-Id", "host;1;1"),
+//!     NamedAvp::new("CC-Request-Type", "INITIAL_REQUEST"),
+//! ]);
+//! # #[cfg(all(feature = "serde", feature = "json"))]
+//! # {
+//! let json = serde_json::to_string(&named).unwrap();
+//! let fixture: NamedMessage = serde_json::from_str(&json).unwrap();
+//! assert_eq!(NamedMessage::from_message(&fixture.to_message(&dict)?, &dict), named);
+//! # }
+//! # Ok::<(), diameter_interface::errors::Error>(())
+//! ```
+//! Commands can also be abbreviated, as `CCR` or `CCA`, and messages written as Rust literals
+//! with `diameter_msg!`.
+//!
+//! Values are converted with the data type of the dictionary definition: addresses, URIs and
+//! RFC 3339 times are strings, OctetStrings are `0x`-prefixed hex strings (other strings are
+//! taken as their UTF-8 bytes), and Enumerated and Integer32 values are item names or numbers.
+//! The M flag is set from the dictionary.
+//!
+//! AVPs missing from the dictionary are named by their code, or `<code>:<vendor id>` for
+//! vendor-specific ones. Names defined by several vendors are qualified as `<name>:<vendor>`,
+//! e.g. `Timezone-Offset:3GPP`, with 0 as the vendor of the IETF one. Unlike the lossless serde
+//! form of `DiameterMessage`, other AVP flags are not kept.
+
+use crate::errors::Error::EncodeError;
+use crate::errors::{DiameterResult, Error};
+use crate::modeling::avp::address::Address;
+use crate::modeling::avp::avp::{Avp, AvpFlags, AvpType, AvpValue};
+use crate::modeling::avp::diameter_uri::{DiameterURI, Uri};
+use crate::modeling::avp::enumerated::Enumerated;
+use crate::modeling::avp::float32::Float32;
+use crate::modeling::avp::float64::Float64;
+use crate::modeling::avp::group::Grouped;
+use crate::modeling::avp::integer32::Integer32;
+use crate::modeling::avp::integer64::Integer64;
+use crate::modeling::avp::ipv4::IPv4;
+use crate::modeling::avp::ipv6::IPv6;
+use crate::modeling::avp::octet_string::OctetString;
+use crate::modeling::avp::time::Time;
+use crate::modeling::avp::unsigned32::Unsigned32;
+use crate::modeling::avp::unsigned64::Unsigned64;
+use crate::modeling::avp::utf8_string::UTF8String;
+use crate::modeling::diameter::DiameterMessage;
+use crate::modeling::message::application_id::ApplicationId;
+use crate::modeling::message::command_code::CommandCode;
+use crate::modeling::message::command_flags::CommandFlag;
+use crate::modeling::message::dictionary::{AvpDefinition, Dictionary};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use chrono::{DateTime, SecondsFormat, Utc};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct NamedMessage {
+    /// The command name with a `-Request` or `-Answer` suffix, e.g. `Credit-Control-Request`.
+    pub command: String,
+    pub application_id: u32,
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "is_false"))]
+    pub proxyable: bool,
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "is_false"))]
+    pub error: bool,
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "is_false"))]
+    pub retransmitted: bool,
+    pub hop_by_hop: u32,
+    pub end_to_end: u32,
+    pub avps: Vec<NamedAvp>,
+}
+
+/// An AVP as its name and value, serialized as a single entry map.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NamedAvp {
+    pub name: String,
+    pub value: NamedValue,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(untagged))]
+pub enum NamedValue {
+    Unsigned(u64),
+    Integer(i64),
+    Float(f64),
+    Text(String),
+    Group(Vec<NamedAvp>),
+}
+
+impl NamedMessage {
+    pub fn from_message(message: &DiameterMessage, dict: &Dictionary) -> Self {
+        let command_code = message.command_code();
+        let command = dict
+            .commands()
+            .find(|(_, code)| **code == command_code)
+            .map(|(name, _)| name.to_string())
+            .unwrap_or_else(|| command_code.get_code().to_string());
+        let kind = match message.is_request() {
+            true => "Request",
+            false => "Answer",
+        };
+        Self {
+            command: format!("{}-{}", command, kind),
+            application_id: message.application_id().value(),
+            proxyable: message.has_flag(CommandFlag::Proxyable),
+            error: message.has_flag(CommandFlag::Error),
+            retransmitted: message.has_flag(CommandFlag::Retransmit),
+            hop_by_hop: message.hop_by_hop(),
+            end_to_end: message.end_to_end(),
+            avps: message
+                .avps()
+                .iter()
+                .map(|avp| NamedAvp::from_avp(avp, dict))
+                .collect(),
+        }
+    }
+
+    /// A message of `command` with `avps`, as built by `diameter_msg!`. The application id is
+    /// taken from the Auth-Application-Id or Acct-Application-Id AVP, at the top level or in
+    /// Vendor-Specific-Application-Id, and is 0 without one. The identifiers are 0.
+    pub fn new(command: &str, avps: Vec<NamedAvp>) -> Self {
+        Self {
+            command: command.to_string(),
+            application_id: application_id(&avps).unwrap_or(0),
+            proxyable: false,
+            error: false,
+            retransmitted: false,
+            hop_by_hop: 0,
+            end_to_end: 0,
+            avps,
+        }
+    }
+
+    pub fn to_message(&self, dict: &Dictionary) -> DiameterResult<DiameterMessage> {
+        let (command_code, request) = self.command_code(dict)?;
+        let mut flags = 0;
+        for (set, flag) in [
+            (request, CommandFlag::Request),
+            (self.proxyable, CommandFlag::Proxyable),
+            (self.error, CommandFlag::Error),
+            (self.retransmitted, CommandFlag::Retransmit),
+        ] {
+            if set {
+                flags |= flag.value();
+            }
+        }
+        let mut message = DiameterMessage::from_header(
+            1,
+            flags,
+            command_code,
+            ApplicationId::try_from(self.application_id)?,
+            self.hop_by_hop,
+            self.end_to_end,
+        );
+        for avp in &self.avps {
+            message.add(avp.to_avp(dict)?);
+        }
+        Ok(message)
+    }
+
+    /// Resolves a command name with a `-Request` or `-Answer` suffix, or an abbreviation such
+    /// as `CCR` or `CCA`.
+    fn command_code(&self, dict: &Dictionary) -> DiameterResult<(CommandCode, bool)> {
+        let (command, request) = match self.command.strip_suffix("-Request") {
+            Some(command) => (command, true),
+            None => match self.command.strip_suffix("-Answer") {
+                Some(command) => (command, false),
+                None => return self.abbreviated_command_code(dict),
+            },
+        };
+        let command_code = match dict.commands().find(|(name, _)| *name == command) {
+            Some((_, code)) => *code,
+            None => {
+                let code = command
+                    .parse::<u32>()
+                    .map_err(|_| EncodeError("Unknown command name"))?;
+                CommandCode::try_from(code)?
+            }
+        };
+        Ok((command_code, request))
+    }
+
+    fn abbreviated_command_code(&self, dict: &Dictionary) -> DiameterResult<(CommandCode, bool)> {
+        let (abbreviation, request) = match self.command.strip_suffix('R') {
+            Some(abbreviation) => (abbreviation, true),
+            None => match self.command.strip_suffix('A') {
+                Some(abbreviation) => (abbreviation, false),
+                None => Err(EncodeError(
+                    "Command name must end with -Request or -Answer, or be abbreviated",
+                ))?,
+            },
+        };
+        let command_code = dict
+            .commands()
+            .map(|(_, code)| *code)
+            .find(|code| code.abbreviation() == abbreviation)
+            .ok_or(EncodeError("Unknown command name"))?;
+        Ok((command_code, request))
+    }
+}
+
+impl NamedAvp {
+    pub fn new<T: Into<NamedValue>>(name: &str, value: T) -> Self {
+        Self {
+            name: name.to_string(),
+            value: value.into(),
+        }
+    }
+
+    pub fn from_avp(avp: &Avp, dict: &Dictionary) -> Self {
+        let name = match dict.get_avp_name(avp.code(), avp.vendor_id()) {
+            Some(name) if dict.get_unique_avp_by_name(name).is_some() => name.to_string(),
+            Some(name) => match avp.vendor_id() {
+                Some(vendor_id) => match dict.get_vendor_name(vendor_id) {
+                    Some(vendor) => format!("{}:{}", name, vendor),
+                    None => format!("{}:{}", name, vendor_id),
+                },
+                None => format!("{}:0", name),
+            },
+            None => match avp.vendor_id() {
+                Some(vendor_id) => format!("{}:{}", avp.code(), vendor_id),
+                None => avp.code().to_string(),
+            },
+        };
+        let value = match avp.value() {
+            AvpValue::Integer32(value) | AvpValue::Enumerated(value) => {
+                let value = *value.value();
+                match dict.get_enum_name(avp.code(), avp.vendor_id(), value) {
+                    Some(name) => NamedValue::Text(name.to_string()),
+                    None => NamedValue::Integer(value as i64),
+                }
+            }
+            AvpValue::Integer64(value) => NamedValue::Integer(*value.value()),
+            AvpValue::Unsigned32(value) => NamedValue::Unsigned(*value.value() as u64),
+            AvpValue::Unsigned64(value) => NamedValue::Unsigned(*value.value()),
+            AvpValue::Float32(value) => NamedValue::Float(*value.value() as f64),
+            AvpValue::Float64(value) => NamedValue::Float(*value.value()),
+            AvpValue::UTF8String(value) | AvpValue::Identity(value) => {
+                NamedValue::Text(value.value().clone())
+            }
+            AvpValue::OctetString(value) => NamedValue::Text(to_hex(value.value())),
+            AvpValue::Address(value) => NamedValue::Text(value.value().to_string()),
+            AvpValue::AddressIPv4(value) => NamedValue::Text(value.value().to_string()),
+            AvpValue::AddressIPv6(value) => NamedValue::Text(value.value().to_string()),
+            AvpValue::DiameterURI(value) => NamedValue::Text(value.value().to_string()),
+            AvpValue::Time(value) => {
+                NamedValue::Text(value.value().to_rfc3339_opts(SecondsFormat::Secs, true))
+            }
+            AvpValue::Grouped(value) => NamedValue::Group(
+                value
+                    .avps()
+                    .iter()
+                    .map(|avp| NamedAvp::from_avp(avp, dict))
+                    .collect(),
+            ),
+            AvpValue::Custom(_) => {
+                let mut data = vec![];
+                // Encoding into a Vec only fails if the codec does.
+                let _ = avp.value().encode(&mut data);
+                NamedValue::Text(to_hex(&data))
+            }
+        };
+        Self { name, value }
+    }
+
+    pub fn to_avp(&self, dict: &Dictionary) -> DiameterResult<Avp> {
+        let definition = match self.definition(dict)? {
+            Some(definition) => definition,
+            None => return self.to_unknown_avp(dict),
+        };
+        let (code, vendor_id) = (definition.code, definition.vendor_id);
+        let flags = match definition.m_flag {
+            true => AvpFlags::M,
+            false => AvpFlags::O,
+        };
+        let value: AvpValue = match (&definition.avp_type, &self.value) {
+            (AvpType::Grouped, NamedValue::Group(avps)) => Grouped::new(
+                avps.iter()
+                    .map(|avp| avp.to_avp(dict))
+                    .collect::<DiameterResult<Vec<Avp>>>()?,
+            )
+            .into(),
+            (AvpType::Enumerated | AvpType::Integer32, NamedValue::Text(name)) => {
+                let value = definition
+                    .enum_values
+                    .iter()
+                    .find(|(_, item)| *item == name)
+                    .map(|(value, _)| *value)
+                    .ok_or(EncodeError("Unknown enumeration name"))?;
+                match definition.avp_type {
+                    AvpType::Enumerated => Enumerated::new(value).into(),
+                    _ => Integer32::new(value).into(),
+                }
+            }
+            (AvpType::Enumerated, value) => {
+                Enumerated::new(int(value)?.try_into().map_err(|_| OUT_OF_RANGE)?).into()
+            }
+            (AvpType::Integer32, value) => {
+                Integer32::new(int(value)?.try_into().map_err(|_| OUT_OF_RANGE)?).into()
+            }
+            (AvpType::Integer64, value) => Integer64::new(int(value)?).into(),
+            (AvpType::Unsigned32, value) => {
+                Unsigned32::new(uint(value)?.try_into().map_err(|_| OUT_OF_RANGE)?).into()
+            }
+            (AvpType::Unsigned64, value) => Unsigned64::new(uint(value)?).into(),
+            (AvpType::Float32, value) => Float32::new(float(value)? as f32).into(),
+            (AvpType::Float64, value) => Float64::new(float(value)?).into(),
+            (AvpType::UTF8String | AvpType::Identity, NamedValue::Text(text)) => {
+                UTF8String::new(text.clone()).into()
+            }
+            (AvpType::OctetString | AvpType::Unknown, NamedValue::Text(text)) => {
+                OctetString::new(octets(text)).into()
+            }
+            (AvpType::Address, NamedValue::Text(text)) => {
+                Address::new(text.parse().map_err(|_| INVALID_ADDRESS)?).into()
+            }
+            (AvpType::AddressIPv4, NamedValue::Text(text)) => {
+                IPv4::new(text.parse().map_err(|_| INVALID_ADDRESS)?).into()
+            }
+            (AvpType::AddressIPv6, NamedValue::Text(text)) => {
+                IPv6::new(text.parse().map_err(|_| INVALID_ADDRESS)?).into()
+            }
+            (AvpType::DiameterURI, NamedValue::Text(text)) => {
+                DiameterURI::new(text.parse::<Uri>()?).into()
+            }
+            (AvpType::Time, NamedValue::Text(text)) => {
+                let time = DateTime::parse_from_rfc3339(text)
+                    .map_err(|_| EncodeError("Invalid RFC 3339 time"))?;
+                Time::new(time.with_timezone(&Utc)).into()
+            }
+            (AvpType::Custom(type_name), NamedValue::Text(text)) => {
+                match dict.get_codec(type_name) {
+                    Some(decoder) => decoder(&octets(text))?.into(),
+                    None => OctetString::new(octets(text)).into(),
+                }
+            }
+            _ => Err(UNEXPECTED_VALUE)?,
+        };
+        Ok(Avp::new(code, flags, vendor_id, value))
+    }
+
+    /// The dictionary definition of the AVP, `None` for an AVP named by its code. Names defined
+    /// by several vendors are qualified as `<name>:<vendor>`, with the vendor name or id, or 0
+    /// for the IETF one.
+    fn definition<'a>(&self, dict: &'a Dictionary) -> DiameterResult<Option<&'a AvpDefinition>> {
+        let (name, vendor) = match self.name.rsplit_once(':') {
+            Some((code, _)) if code.parse::<u32>().is_ok() => return Ok(None),
+            Some((name, vendor)) => (name, vendor),
+            None => {
+                return match dict.get_unique_avp_by_name(&self.name) {
+                    Some(definition) => Ok(Some(definition)),
+                    None if dict.get_avps_by_name(&self.name).next().is_some() => {
+                        Err(EncodeError("AVP name defined by several vendors"))
+                    }
+                    None => Ok(None),
+                };
+            }
+        };
+        let vendor_id = match vendor.parse::<u32>() {
+            Ok(0) => None,
+            Ok(vendor_id) => Some(vendor_id),
+            Err(_) => Some(
+                dict.get_vendor_id(vendor)
+                    .ok_or(EncodeError("Unknown vendor name"))?,
+            ),
+        };
+        dict.get_avp_by_name_and_vendor(name, vendor_id)
+            .map(Some)
+            .ok_or(EncodeError("Unknown AVP name"))
+    }
+
+    fn to_unknown_avp(&self, dict: &Dictionary) -> DiameterResult<Avp> {
+        let (code, vendor_id) = match self.name.split_once(':') {
+            Some((code, vendor_id)) => (code, Some(vendor_id)),
+            None => (self.name.as_str(), None),
+        };
+        let code = code
+            .parse::<u32>()
+            .map_err(|_| EncodeError("Unknown AVP name"))?;
+        let vendor_id = match vendor_id {
+            Some(vendor_id) => Some(
+                vendor_id
+                    .parse::<u32>()
+                    .map_err(|_| EncodeError("Unknown AVP name"))?,
+            ),
+            None => None,
+        };
+        let value: AvpValue = match &self.value {
+            NamedValue::Unsigned(value) => match u32::try_from(*value) {
+                Ok(value) => Unsigned32::new(value).into(),
+                Err(_) => Unsigned64::new(*value).into(),
+            },
+            NamedValue::Integer(value) => match i32::try_from(*value) {
+                Ok(value) => Integer32::new(value).into(),
+                Err(_) => Integer64::new(*value).into(),
+            },
+            NamedValue::Float(value) => Float64::new(*value).into(),
+            NamedValue::Text(text) => OctetString::new(octets(text)).into(),
+            NamedValue::Group(avps) => Grouped::new(
+                avps.iter()
+                    .map(|avp| avp.to_avp(dict))
+                    .collect::<DiameterResult<Vec<Avp>>>()?,
+            )
+            .into(),
+        };
+        Ok(Avp::new(code, AvpFlags::O, vendor_id, value))
+    }
+}
+
+/// The value of the first Auth-Application-Id or Acct-Application-Id AVP.
+fn application_id(avps: &[NamedAvp]) -> Option<u32> {
+    avps.iter().find_map(|avp| match (avp.name.as_str(), &avp.value) {
+        ("Auth-Application-Id" | "Acct-Application-Id", value) => uint(value).ok()?.try_into().ok(),
+        ("Vendor-Specific-Application-Id", NamedValue::Group(avps)) => application_id(avps),
+        _ => None,
+    })
+}
+
+const UNEXPECTED_VALUE: Error = EncodeError("Unexpected value for AVP type");
+const OUT_OF_RANGE: Error = EncodeError("Value out of range for AVP type");
+const INVALID_ADDRESS: Error = EncodeError("Invalid IP address");
+
+fn int(value: &NamedValue) -> DiameterResult<i64> {
+    match value {
+        NamedValue::Integer(value) => Ok(*value),
+        NamedValue::Unsigned(value) => i64::try_from(*value).map_err(|_| OUT_OF_RANGE),
+        _ => Err(UNEXPECTED_VALUE),
+    }
+}
+
+fn uint(value: &NamedValue) -> DiameterResult<u64> {
+    match value {
+        NamedValue::Unsigned(value) => Ok(*value),
+        NamedValue::Integer(value) => u64::try_from(*value).map_err(|_| OUT_OF_RANGE),
+        _ => Err(UNEXPECTED_VALUE),
+    }
+}
+
+fn float(value: &NamedValue) -> DiameterResult<f64> {
+    match value {
+        NamedValue::Float(value) => Ok(*value),
+        NamedValue::Integer(value) => Ok(*value as f64),
+        NamedValue::Unsigned(value) => Ok(*value as f64),
+        _ => Err(UNEXPECTED_VALUE),
+    }
+}
+
+/// `0x`-prefixed hex strings are decoded, other strings are taken as their UTF-8 bytes.
+fn octets(text: &str) -> Vec<u8> {
+    match text.strip_prefix("0x").and_then(from_hex) {
+        Some(data) => data,
+        None => text.as_bytes().to_vec(),
+    }
+}
+
+pub(crate) fn to_hex(data: &[u8]) -> String {
+    let mut hex = String::with_capacity(2 + data.len() * 2);
+    hex.push_str("0x");
+    for byte in data {
+        hex.push_str(&format!("{:02x}", byte));
+    }
+    hex
+}
+
+/// Decodes a hex string, with or without `0x` prefix.
+pub(crate) fn from_hex(hex: &str) -> Option<Vec<u8>> {
+    let hex = hex.strip_prefix("0x").unwrap_or(hex);
+    if !hex.len().is_multiple_of(2) || !hex.is_ascii() {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).ok())
+        .collect()
+}
+
+impl From<&str> for NamedValue {
+    fn from(value: &str) -> Self {
+        NamedValue::Text(value.to_string())
+    }
+}
+
+impl From<String> for NamedValue {
+    fn from(value: String) -> Self {
+        NamedValue::Text(value)
+    }
+}
+
+impl From<u32> for NamedValue {
+    fn from(value: u32) -> Self {
+        NamedValue::Unsigned(value as u64)
+    }
+}
+
+impl From<u64> for NamedValue {
+    fn from(value: u64) -> Self {
+        NamedValue::Unsigned(value)
+    }
+}
+
+impl From<i32> for NamedValue {
+    fn from(value: i32) -> Self {
+        NamedValue::Integer(value as i64)
+    }
+}
+
+impl From<i64> for NamedValue {
+    fn from(value: i64) -> Self {
+        NamedValue::Integer(value)
+    }
+}
+
+impl From<f64> for NamedValue {
+    fn from(value: f64) -> Self {
+        NamedValue::Float(value)
+    }
+}
+
+impl From<Vec<NamedAvp>> for NamedValue {
+    fn from(value: Vec<NamedAvp>) -> Self {
+        NamedValue::Group(value)
+    }
+}
+
+#[cfg(feature = "serde")]
+fn is_false(value: &bool) -> bool {
+    !*value
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for NamedAvp {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeMap;
+        let mut map = serializer.serialize_map(Some(1))?;
+        map.serialize_entry(&self.name, &self.value)?;
+        map.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for NamedAvp {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::{Error, MapAccess, Visitor};
+        use core::fmt;
+
+        struct NamedAvpVisitor;
+
+        impl<'de> Visitor<'de> for NamedAvpVisitor {
+            type Value = NamedAvp;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "a map with a single AVP name and value")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<NamedAvp, A::Error> {
+                let (name, value) = map
+                    .next_entry::<String, NamedValue>()?
+                    .ok_or_else(|| A::Error::custom("missing AVP name"))?;
+                if map.next_key::<String>()?.is_some() {
+                    return Err(A::Error::custom("more than one AVP in a map"));
+                }
+                Ok(NamedAvp { name, value })
+            }
+        }
+
+        deserializer.deserialize_map(NamedAvpVisitor)
+    }
+}
+
+/// Builds a `DiameterMessage` from a nested literal of AVP names and values, resolved with a
+/// `Dictionary` at runtime as in `NamedMessage::to_message`:
+/// ```
+/// use diameter_interface::diameter_msg;
+/// use diameter_interface::modeling::message::command_flags::CommandFlag;
+/// use diameter_interface::modeling::message::dictionary::{self, Dictionary};
+///
+/// let dict = Dictionary::new(&[&dictionary::DEFAULT_DICT_XML]);
+/// let session_id = "client.example.com;1;1".to_string();
+/// let mut ccr = diameter_msg!(&dict, CCR {
+///     Session-Id: session_id.as_str(),
+///     Auth-Application-Id: 4,
+///     CC-Request-Type: "INITIAL_REQUEST",
+///     CC-Request-Number: 0,
+///     Subscription-Id {
+///         Subscription-Id-Type: "END_USER_E164",
+///         Subscription-Id-Data: "46701234567",
+///     },
+/// })?;
+/// ccr.set_flag(CommandFlag::Proxyable);
+/// assert_eq!(ccr.avps().len(), 5);
+/// # Ok::<(), diameter_interface::errors::Error>(())
+/// ```
+/// The command is named as `Credit-Control-Request` or abbreviated as `CCR`. Values are
+/// expressions converted with `NamedValue::from`, and AVPs in braces are Grouped. AVPs
+/// missing from the dictionary are named by their code, e.g. `1234: "0x01"`. The identifiers
+/// are 0 and the application id is taken from the message, see `NamedMessage::new`.
+#[macro_export]
+macro_rules! diameter_msg {
+    ($dict:expr, $first:tt $(- $rest:tt)* { $($avps:tt)* }) => {{
+        let mut avps = $crate::modeling::typed::__private::Vec::new();
+        $crate::__diameter_avps!(avps; $($avps)*);
+        $crate::modeling::named::NamedMessage::new(
+            concat!(stringify!($first) $(, "-", stringify!($rest))*),
+            avps,
+        )
+        .to_message($dict)
+    }};
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __diameter_avps {
+    ($avps:ident;) => {};
+    ($avps:ident; $first:tt $(- $rest:tt)* { $($group:tt)* } $(, $($tail:tt)*)?) => {
+        {
+            let mut group = $crate::modeling::typed::__private::Vec::new();
+            $crate::__diameter_avps!(group; $($group)*);
+            $avps.push($crate::modeling::named::NamedAvp::new(
+                concat!(stringify!($first) $(, "-", stringify!($rest))*),
+                group,
+            ));
+        }
+        $crate::__diameter_avps!($avps; $($($tail)*)?);
+    };
+    ($avps:ident; $first:tt $(- $rest:tt)* : $value:expr $(, $($tail:tt)*)?) => {
+        $avps.push($crate::modeling::named::NamedAvp::new(
+            concat!(stringify!($first) $(, "-", stringify!($rest))*),
+            $value,
+        ));
+        $crate::__diameter_avps!($avps; $($($tail)*)?);
+    };
+}
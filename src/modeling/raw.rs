@@ -0,0 +1,206 @@
+//! # Raw Messages
+//!
+//! `RawMessage` keeps the bytes a message was received in, for relays and proxies that forward
+//! messages with a few changes. Header fields are edited in place and top-level AVPs are
+//! appended, replaced or removed as encoded bytes, leaving the other AVPs, including those
+//! unknown to the dictionary, unchanged and undecoded:
+//! ```
+//! use diameter_interface::modeling::avp::avp::{Avp, AvpFlags};
+//! use diameter_interface::modeling::avp::utf8_string::Identity;
+//! use diameter_interface::modeling::diameter::DiameterMessage;
+//! use diameter_interface::modeling::message::application_id::ApplicationId;
+//! use diameter_interface::modeling::message::command_code::CommandCode;
+//! use diameter_interface::modeling::message::command_flags::CommandFlag;
+//! use diameter_interface::modeling::raw::RawMessage;
+//! use std::io::Write;
+//!
+//! const ROUTE_RECORD: u32 = 282;
+//! const DESTINATION_HOST: u32 = 293;
+//!
+//! let request = DiameterMessage::new(
+//!     CommandFlag::Request, CommandCode::CreditControl, ApplicationId::CreditControl, 1, 1,
+//! );
+//! let mut received = vec![];
+//! request.encode_to(&mut received)?;
+//!
+//! let mut message = RawMessage::new(received)?;
+//! message.set_hop_by_hop(2);
+//! let own_identity = "relay.example.com".to_string();
+//! message.add(Avp::new(ROUTE_RECORD, AvpFlags::M, None, Identity::new(own_identity)))?;
+//! let next_hop = "server.example.com".to_string();
+//! message.set_avp(Avp::new(DESTINATION_HOST, AvpFlags::M, None, Identity::new(next_hop)))?;
+//!
+//! let mut stream = vec![];
+//! stream.write_all(message.as_bytes())?;
+//! assert_eq!(message.message_length() as usize, stream.len());
+//! # Ok::<(), diameter_interface::errors::Error>(())
+//! ```
+//! The Message Length is kept equal to the length of the bytes after every change.
+
+use crate::errors::DiameterResult;
+use crate::errors::Error::EncodeError;
+use crate::modeling::avp::avp::Avp;
+use crate::modeling::decode_policy::DecodePolicy;
+use crate::modeling::diameter::DiameterMessage;
+use crate::modeling::message::command_flags::CommandFlag;
+use crate::modeling::message::dictionary::Dictionary;
+use crate::modeling::view::{AvpRef, MessageRef};
+use alloc::sync::Arc;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::Range;
+
+const HEADER_LENGTH: usize = 20;
+const MAX_MESSAGE_LENGTH: usize = 0x00ffffff;
+
+#[derive(Debug, Clone)]
+pub struct RawMessage {
+    data: Vec<u8>,
+}
+
+impl RawMessage {
+    /// Takes the message at the start of `data`, checking its header and AVP lengths as
+    /// `MessageRef::new` does. Bytes after the Message Length are dropped.
+    pub fn new(data: Vec<u8>) -> DiameterResult<Self> {
+        Self::new_with_policy(data, DecodePolicy::strict())
+    }
+
+    /// As `new`, with the checks of `MessageRef::new_with_policy`. The padding missing after
+    /// the last AVP, when tolerated, is added so that AVPs can be appended.
+    pub fn new_with_policy(mut data: Vec<u8>, policy: DecodePolicy) -> DiameterResult<Self> {
+        let length = MessageRef::new_with_policy(&data, policy)?.as_bytes().len();
+        data.truncate(length);
+        let mut message = Self { data };
+        if !length.is_multiple_of(4) {
+            message.data.resize(length.next_multiple_of(4), 0);
+            message.update_length();
+        }
+        Ok(message)
+    }
+
+    pub fn from_message(message: &DiameterMessage) -> DiameterResult<Self> {
+        let mut data = vec![0u8; message.encoded_len()];
+        message.encode_into(&mut data)?;
+        Ok(Self { data })
+    }
+
+    /// A borrowed view of the message, to read its header and AVPs.
+    pub fn view(&self) -> MessageRef<'_> {
+        MessageRef::new_unchecked(&self.data)
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.data
+    }
+
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.data
+    }
+
+    /// Decodes the message into an owned `DiameterMessage`.
+    pub fn to_message(&self, dict: Arc<Dictionary>) -> DiameterResult<DiameterMessage> {
+        self.view().to_message(dict)
+    }
+
+    pub fn message_length(&self) -> u32 {
+        self.data.len() as u32
+    }
+
+    pub fn command_flags(&self) -> u8 {
+        self.data[4]
+    }
+
+    pub fn has_flag(&self, flag: CommandFlag) -> bool {
+        self.data[4] & flag.value() != 0
+    }
+
+    pub fn set_flag(&mut self, flag: CommandFlag) {
+        self.data[4] |= flag.value();
+    }
+
+    pub fn clear_flag(&mut self, flag: CommandFlag) {
+        self.data[4] &= !flag.value();
+    }
+
+    pub fn is_request(&self) -> bool {
+        self.has_flag(CommandFlag::Request)
+    }
+
+    pub fn hop_by_hop(&self) -> u32 {
+        self.view().hop_by_hop()
+    }
+
+    pub fn end_to_end(&self) -> u32 {
+        self.view().end_to_end()
+    }
+
+    pub fn set_hop_by_hop(&mut self, hop_by_hop: u32) {
+        self.data[12..16].copy_from_slice(&hop_by_hop.to_be_bytes());
+    }
+
+    pub fn set_end_to_end(&mut self, end_to_end: u32) {
+        self.data[16..20].copy_from_slice(&end_to_end.to_be_bytes());
+    }
+
+    /// Returns the first top-level AVP with the given code and vendor.
+    pub fn get_avp(&self, code: u32, vendor_id: Option<u32>) -> Option<AvpRef<'_>> {
+        self.view().get_avp(code, vendor_id)
+    }
+
+    /// Appends `avp` after the last top-level AVP.
+    pub fn add(&mut self, avp: Avp) -> DiameterResult<()> {
+        let end = self.data.len();
+        self.splice(end..end, &avp)
+    }
+
+    /// Replaces the first top-level AVP with the same code and vendor as `avp`, or appends
+    /// `avp` when there is none.
+    pub fn set_avp(&mut self, avp: Avp) -> DiameterResult<()> {
+        match self.find(avp.code(), avp.vendor_id()).into_iter().next() {
+            Some(range) => self.splice(range, &avp),
+            None => self.add(avp),
+        }
+    }
+
+    /// Removes every top-level AVP with the given code and vendor, returning their number.
+    pub fn remove_avps(&mut self, code: u32, vendor_id: Option<u32>) -> usize {
+        let ranges = self.find(code, vendor_id);
+        for range in ranges.iter().rev() {
+            self.data.drain(range.clone());
+        }
+        self.update_length();
+        ranges.len()
+    }
+
+    /// The byte ranges, padding included, of the top-level AVPs with the given code and vendor.
+    fn find(&self, code: u32, vendor_id: Option<u32>) -> Vec<Range<usize>> {
+        let mut offset = HEADER_LENGTH;
+        let mut ranges = vec![];
+        for avp in self.view().avps() {
+            let length = (avp.get_length() + avp.get_padding()) as usize;
+            if avp.code() == code && avp.vendor_id() == vendor_id {
+                ranges.push(offset..offset + length);
+            }
+            offset += length;
+        }
+        ranges
+    }
+
+    /// Replaces the bytes in `range` with the encoding of `avp`.
+    fn splice(&mut self, range: Range<usize>, avp: &Avp) -> DiameterResult<()> {
+        let avp_length = (avp.get_length() + avp.get_padding()) as usize;
+        if self.data.len() - range.len() + avp_length > MAX_MESSAGE_LENGTH {
+            Err(EncodeError("Message length exceeds 24 bits"))?
+        }
+        let mut encoded = Vec::with_capacity(avp_length);
+        avp.encode_to(&mut encoded)?;
+        self.data.splice(range, encoded);
+        self.update_length();
+        Ok(())
+    }
+
+    fn update_length(&mut self) {
+        let length = self.data.len() as u32;
+        self.data[1..4].copy_from_slice(&length.to_be_bytes()[1..]);
+    }
+}
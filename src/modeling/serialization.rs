@@ -0,0 +1,274 @@
+//! # Serde Support
+//!
+//! Lossless `Serialize` and `Deserialize` implementations for `DiameterMessage`, `Avp` and
+//! `AvpValue`, enabled by the `serde` feature. Header fields and AVP flags are kept as raw
+//! numbers and every value is tagged with its data type, so a message deserialized from its
+//! serialized form encodes to the same bytes:
+//! ```json
+//! {
+//!   "version": 1,
+//!   "flags": 192,
+//!   "command_code": 272,
+//!   "application_id": 4,
+//!   "hop_by_hop": 1,
+//!   "end_to_end": 2,
+//!   "avps": [
+//!     { "code": 263, "flags": 64, "value": { "UTF8String": "host;1;1" } },
+//!     { "code": 1032, "flags": 192, "vendor_id": 10415, "value": { "Enumerated": 1004 } }
+//!   ]
+//! }
+//! ```
+//! OctetString values are hex strings and Time values RFC 3339 strings. Values of custom data
+//! types are serialized as the OctetString of their encoding.
+//!
+//! See `NamedMessage` for a form with dictionary names.
+
+use crate::modeling::avp::address::Address;
+use crate::modeling::avp::avp::{Avp, AvpFlags, AvpValue};
+use crate::modeling::avp::diameter_uri::{DiameterURI, Uri};
+use crate::modeling::avp::float32::Float32;
+use crate::modeling::avp::float64::Float64;
+use crate::modeling::avp::group::Grouped;
+use crate::modeling::avp::integer32::Integer32;
+use crate::modeling::avp::integer64::Integer64;
+use crate::modeling::avp::ipv4::IPv4;
+use crate::modeling::avp::ipv6::IPv6;
+use crate::modeling::avp::octet_string::OctetString;
+use crate::modeling::avp::time::Time;
+use crate::modeling::avp::unsigned32::Unsigned32;
+use crate::modeling::avp::unsigned64::Unsigned64;
+use crate::modeling::avp::utf8_string::{Identity, UTF8String};
+use crate::modeling::diameter::DiameterMessage;
+use crate::modeling::message::application_id::ApplicationId;
+use crate::modeling::message::command_code::CommandCode;
+use crate::modeling::named::{from_hex, to_hex};
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use chrono::{DateTime, SecondsFormat, Utc};
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+#[derive(Serialize)]
+struct MessageRef<'a> {
+    version: u8,
+    flags: u8,
+    command_code: u32,
+    application_id: u32,
+    hop_by_hop: u32,
+    end_to_end: u32,
+    avps: &'a [Avp],
+}
+
+#[derive(Deserialize)]
+struct MessageRepr {
+    #[serde(default = "default_version")]
+    version: u8,
+    flags: u8,
+    command_code: u32,
+    application_id: u32,
+    hop_by_hop: u32,
+    end_to_end: u32,
+    #[serde(default)]
+    avps: Vec<Avp>,
+}
+
+#[derive(Serialize)]
+struct AvpRef<'a> {
+    code: u32,
+    flags: u8,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    vendor_id: Option<u32>,
+    value: &'a AvpValue,
+}
+
+#[derive(Deserialize)]
+struct AvpRepr {
+    code: u32,
+    flags: u8,
+    #[serde(default)]
+    vendor_id: Option<u32>,
+    value: AvpValue,
+}
+
+/// Mirrors `AvpValue` with serializable values, the variant order gives the variant indices.
+#[derive(Deserialize)]
+#[serde(rename = "AvpValue")]
+enum AvpValueRepr {
+    Address(IpAddr),
+    AddressIPv4(Ipv4Addr),
+    AddressIPv6(Ipv6Addr),
+    Identity(String),
+    DiameterURI(String),
+    Enumerated(i32),
+    Float32(f32),
+    Float64(f64),
+    Grouped(Vec<Avp>),
+    Integer32(i32),
+    Integer64(i64),
+    OctetString(String),
+    Time(String),
+    Unsigned32(u32),
+    Unsigned64(u64),
+    UTF8String(String),
+}
+
+fn default_version() -> u8 {
+    1
+}
+
+impl Serialize for DiameterMessage {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        MessageRef {
+            version: self.version(),
+            flags: self.command_flags(),
+            command_code: self.command_code().get_code(),
+            application_id: self.application_id().value(),
+            hop_by_hop: self.hop_by_hop(),
+            end_to_end: self.end_to_end(),
+            avps: self.avps(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for DiameterMessage {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = MessageRepr::deserialize(deserializer)?;
+        let mut message = DiameterMessage::from_header(
+            repr.version,
+            repr.flags,
+            CommandCode::try_from(repr.command_code).map_err(D::Error::custom)?,
+            ApplicationId::try_from(repr.application_id).map_err(D::Error::custom)?,
+            repr.hop_by_hop,
+            repr.end_to_end,
+        );
+        for avp in repr.avps {
+            message.add(avp);
+        }
+        Ok(message)
+    }
+}
+
+impl Serialize for Avp {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        AvpRef {
+            code: self.code(),
+            flags: self.flags(),
+            vendor_id: self.vendor_id(),
+            value: self.value(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Avp {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = AvpRepr::deserialize(deserializer)?;
+        Ok(Avp::new(repr.code, AvpFlags::M, repr.vendor_id, repr.value).with_raw_flags(repr.flags))
+    }
+}
+
+impl Serialize for AvpValue {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let name = "AvpValue";
+        match self {
+            AvpValue::Address(value) => {
+                serializer.serialize_newtype_variant(name, 0, "Address", value.value())
+            }
+            AvpValue::AddressIPv4(value) => {
+                serializer.serialize_newtype_variant(name, 1, "AddressIPv4", value.value())
+            }
+            AvpValue::AddressIPv6(value) => {
+                serializer.serialize_newtype_variant(name, 2, "AddressIPv6", value.value())
+            }
+            AvpValue::Identity(value) => {
+                serializer.serialize_newtype_variant(name, 3, "Identity", value.value())
+            }
+            AvpValue::DiameterURI(value) => serializer.serialize_newtype_variant(
+                name,
+                4,
+                "DiameterURI",
+                &value.value().to_string(),
+            ),
+            AvpValue::Enumerated(value) => {
+                serializer.serialize_newtype_variant(name, 5, "Enumerated", value.value())
+            }
+            AvpValue::Float32(value) => {
+                serializer.serialize_newtype_variant(name, 6, "Float32", value.value())
+            }
+            AvpValue::Float64(value) => {
+                serializer.serialize_newtype_variant(name, 7, "Float64", value.value())
+            }
+            AvpValue::Grouped(value) => {
+                serializer.serialize_newtype_variant(name, 8, "Grouped", value.avps())
+            }
+            AvpValue::Integer32(value) => {
+                serializer.serialize_newtype_variant(name, 9, "Integer32", value.value())
+            }
+            AvpValue::Integer64(value) => {
+                serializer.serialize_newtype_variant(name, 10, "Integer64", value.value())
+            }
+            AvpValue::OctetString(value) => serializer.serialize_newtype_variant(
+                name,
+                11,
+                "OctetString",
+                &to_hex(value.value()),
+            ),
+            AvpValue::Time(value) => serializer.serialize_newtype_variant(
+                name,
+                12,
+                "Time",
+                &value.value().to_rfc3339_opts(SecondsFormat::Secs, true),
+            ),
+            AvpValue::Unsigned32(value) => {
+                serializer.serialize_newtype_variant(name, 13, "Unsigned32", value.value())
+            }
+            AvpValue::Unsigned64(value) => {
+                serializer.serialize_newtype_variant(name, 14, "Unsigned64", value.value())
+            }
+            AvpValue::UTF8String(value) => {
+                serializer.serialize_newtype_variant(name, 15, "UTF8String", value.value())
+            }
+            AvpValue::Custom(_) => {
+                let mut data = vec![];
+                self.encode(&mut data).map_err(serde::ser::Error::custom)?;
+                serializer.serialize_newtype_variant(name, 11, "OctetString", &to_hex(&data))
+            }
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for AvpValue {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = match AvpValueRepr::deserialize(deserializer)? {
+            AvpValueRepr::Address(value) => Address::new(value).into(),
+            AvpValueRepr::AddressIPv4(value) => IPv4::new(value).into(),
+            AvpValueRepr::AddressIPv6(value) => IPv6::new(value).into(),
+            AvpValueRepr::Identity(value) => AvpValue::Identity(Identity::new(value)),
+            AvpValueRepr::DiameterURI(value) => {
+                DiameterURI::new(value.parse::<Uri>().map_err(D::Error::custom)?).into()
+            }
+            AvpValueRepr::Enumerated(value) => AvpValue::Enumerated(Integer32::new(value)),
+            AvpValueRepr::Float32(value) => Float32::new(value).into(),
+            AvpValueRepr::Float64(value) => Float64::new(value).into(),
+            AvpValueRepr::Grouped(value) => Grouped::new(value).into(),
+            AvpValueRepr::Integer32(value) => Integer32::new(value).into(),
+            AvpValueRepr::Integer64(value) => Integer64::new(value).into(),
+            AvpValueRepr::OctetString(value) => {
+                let data = from_hex(&value)
+                    .ok_or_else(|| D::Error::custom("Invalid OctetString hex value"))?;
+                OctetString::new(data).into()
+            }
+            AvpValueRepr::Time(value) => {
+                let time = DateTime::parse_from_rfc3339(&value).map_err(D::Error::custom)?;
+                Time::new(time.with_timezone(&Utc)).into()
+            }
+            AvpValueRepr::Unsigned32(value) => Unsigned32::new(value).into(),
+            AvpValueRepr::Unsigned64(value) => Unsigned64::new(value).into(),
+            AvpValueRepr::UTF8String(value) => UTF8String::new(value).into(),
+        };
+        Ok(value)
+    }
+}
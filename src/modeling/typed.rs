@@ -0,0 +1,267 @@
+//! # Typed AVPs and Messages
+//!
+//! Maps Rust structs to Grouped AVPs and Diameter messages with `#[derive(DiameterAvp)]` and
+//! `#[derive(DiameterMessage)]`:
+//! ```
+//! # use diameter_interface::modeling::avp::avp::Avp;
+//! # use diameter_interface::modeling::typed::{DiameterAvp, DiameterMessage, TypedMessage};
+//! #[derive(Debug, Clone, PartialEq, DiameterAvp)]
+//! pub struct SubscriptionId {
+//!     #[avp(code = 450, mandatory)]
+//!     pub subscription_id_type: i32,
+//!     #[avp(code = 444, mandatory)]
+//!     pub subscription_id_data: String,
+//! }
+//!
+//! #[derive(Debug, Clone, PartialEq, DiameterMessage)]
+//! #[diameter(command = CreditControl, application = Gx, request, proxyable)]
+//! pub struct Ccr {
+//!     #[avp(code = 263, mandatory)]
+//!     pub session_id: String,                    // required: missing -> Error::MissingAvp
+//!     #[avp(code = 443, mandatory)]
+//!     pub subscription_id: Vec<SubscriptionId>,  // zero or more, `min = 1` for one or more
+//!     #[avp(code = 1027, vendor = 10415, mandatory)]
+//!     pub ip_can_type: Option<i32>,              // zero or one
+//!     #[avp(rest)]
+//!     pub other: Vec<Avp>,                       // every AVP not mapped to a field
+//! }
+//!
+//! let ccr = Ccr {
+//!     session_id: "host.example.com;1;1".to_string(),
+//!     subscription_id: vec![SubscriptionId {
+//!         subscription_id_type: 1,
+//!         subscription_id_data: "001010123456789".to_string(),
+//!     }],
+//!     ip_can_type: Some(5),
+//!     other: vec![],
+//! };
+//! let message = ccr.to_message(1, 1);
+//! assert_eq!(Ccr::from_message(&message).unwrap(), ccr);
+//! ```
+//! A required or optional field takes the first occurrence of its AVP. Further occurrences go
+//! to the `rest` field, or fail with `Error::TooManyAvps` when there is none.
+//!
+//! Field types implement [`AvpField`]: numbers, `String`, `Vec<u8>`, IP addresses,
+//! `DateTime<Utc>`, `Uri`, custom `AvpCodec` types, [`avp_enum!`](crate::avp_enum) enums and
+//! other derived Grouped structs.
+//!
+//! Session messages take the header Application-Id from a `u32` or `Option<u32>` field with
+//! `#[diameter(application_field = auth_application_id)]`, and use `application` when the
+//! field is absent or holds an id unknown to `ApplicationId`.
+
+use crate::errors::DiameterResult;
+use crate::errors::Error::DecodeError;
+use crate::modeling::avp::address::Address;
+use crate::modeling::avp::avp::{Avp, AvpValue};
+use crate::modeling::avp::custom::{AvpCodec, Custom};
+use crate::modeling::avp::diameter_uri::{DiameterURI, Uri};
+use crate::modeling::avp::float32::Float32;
+use crate::modeling::avp::float64::Float64;
+use crate::modeling::avp::integer32::Integer32;
+use crate::modeling::avp::integer64::Integer64;
+use crate::modeling::avp::ipv4::IPv4;
+use crate::modeling::avp::ipv6::IPv6;
+use crate::modeling::avp::octet_string::OctetString;
+use crate::modeling::avp::time::Time;
+use crate::modeling::avp::unsigned32::Unsigned32;
+use crate::modeling::avp::unsigned64::Unsigned64;
+use crate::modeling::avp::utf8_string::UTF8String;
+use crate::modeling::diameter::DiameterMessage;
+use crate::modeling::message::application_id::ApplicationId;
+use crate::modeling::message::command_code::CommandCode;
+use crate::modeling::message::command_flags::CommandFlag;
+use alloc::borrow::ToOwned;
+use alloc::string::String;
+use alloc::vec::Vec;
+use chrono::{DateTime, Utc};
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+pub use diameter_derive::{DiameterAvp, DiameterMessage};
+
+/// A Rust value stored as the data of a single AVP.
+pub trait AvpField: Sized {
+    fn to_avp_value(&self) -> AvpValue;
+
+    fn from_avp_value(value: &AvpValue) -> DiameterResult<Self>;
+}
+
+/// A set of AVPs mapped to struct fields, the content of a Grouped AVP or a message.
+pub trait AvpGroup: Sized {
+    fn to_avps(&self) -> Vec<Avp>;
+
+    fn from_avps(avps: &[Avp]) -> DiameterResult<Self>;
+}
+
+/// A struct mapped to a Diameter request or answer.
+pub trait TypedMessage: AvpGroup {
+    const COMMAND_CODE: CommandCode;
+    const APPLICATION_ID: ApplicationId;
+    const REQUEST: bool;
+    const PROXYABLE: bool;
+
+    /// Application-Id of the header, `APPLICATION_ID` unless the message carries its own.
+    fn application_id(&self) -> ApplicationId {
+        Self::APPLICATION_ID
+    }
+
+    fn to_message(&self, hop_by_hop: u32, end_to_end: u32) -> DiameterMessage {
+        let mut message = DiameterMessage::new(
+            CommandFlag::Request,
+            Self::COMMAND_CODE,
+            self.application_id(),
+            hop_by_hop,
+            end_to_end,
+        );
+        if !Self::REQUEST {
+            message.clear_flag(CommandFlag::Request);
+        }
+        if Self::PROXYABLE {
+            message.set_flag(CommandFlag::Proxyable);
+        }
+        for avp in self.to_avps() {
+            message.add(avp);
+        }
+        message
+    }
+
+    /// Decodes the AVPs of `message` after checking its command code and R flag.
+    fn from_message(message: &DiameterMessage) -> DiameterResult<Self> {
+        if message.command_code() != Self::COMMAND_CODE {
+            Err(DecodeError("Unexpected command code"))?
+        }
+        if message.is_request() != Self::REQUEST {
+            Err(DecodeError("Unexpected request flag"))?
+        }
+        Self::from_avps(message.avps())
+    }
+}
+
+macro_rules! impl_avp_field {
+    ($ty:ty, $data:ident, $getter:ident) => {
+        impl AvpField for $ty {
+            fn to_avp_value(&self) -> AvpValue {
+                $data::new(self.clone()).into()
+            }
+
+            fn from_avp_value(value: &AvpValue) -> DiameterResult<Self> {
+                value
+                    .$getter()
+                    .map(|value| value.to_owned())
+                    .ok_or(DecodeError("Unexpected AVP value type"))
+            }
+        }
+    };
+}
+
+impl_avp_field!(i32, Integer32, as_i32);
+impl_avp_field!(i64, Integer64, as_i64);
+impl_avp_field!(u32, Unsigned32, as_u32);
+impl_avp_field!(u64, Unsigned64, as_u64);
+impl_avp_field!(f32, Float32, as_f32);
+impl_avp_field!(f64, Float64, as_f64);
+impl_avp_field!(String, UTF8String, as_str);
+impl_avp_field!(Vec<u8>, OctetString, as_bytes);
+impl_avp_field!(IpAddr, Address, as_ip_addr);
+impl_avp_field!(Ipv4Addr, IPv4, as_ipv4);
+impl_avp_field!(Ipv6Addr, IPv6, as_ipv6);
+impl_avp_field!(DateTime<Utc>, Time, as_time);
+impl_avp_field!(Uri, DiameterURI, as_uri);
+
+impl<T: AvpCodec> AvpField for T {
+    fn to_avp_value(&self) -> AvpValue {
+        Custom::from_codec(self.clone()).into()
+    }
+
+    fn from_avp_value(value: &AvpValue) -> DiameterResult<Self> {
+        value
+            .as_custom::<T>()
+            .cloned()
+            .ok_or(DecodeError("Unexpected AVP value type"))
+    }
+}
+
+impl AvpField for AvpValue {
+    fn to_avp_value(&self) -> AvpValue {
+        self.clone()
+    }
+
+    fn from_avp_value(value: &AvpValue) -> DiameterResult<Self> {
+        Ok(value.clone())
+    }
+}
+
+/// Declares an Enumerated AVP value type usable as a typed field. Values missing from the
+/// enum are kept as `Unknown`, and encoded back unchanged:
+/// ```
+/// use diameter_interface::avp_enum;
+/// use diameter_interface::modeling::typed::AvpField;
+///
+/// avp_enum! {
+///     pub enum DisconnectCause {
+///         Rebooting = 0,
+///         Busy = 1,
+///     }
+/// }
+///
+/// assert_eq!(DisconnectCause::from(1), DisconnectCause::Busy);
+/// assert_eq!(DisconnectCause::Busy.value(), 1);
+///
+/// let value = DisconnectCause::Unknown(7).to_avp_value();
+/// assert_eq!(value.as_i32(), Some(7));
+/// assert_eq!(DisconnectCause::from_avp_value(&value).unwrap(), DisconnectCause::Unknown(7));
+/// ```
+#[macro_export]
+macro_rules! avp_enum {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident {
+            $($(#[$variant_meta:meta])* $variant:ident = $value:literal),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        $vis enum $name {
+            $($(#[$variant_meta])* $variant,)*
+            /// A value missing from the enum.
+            Unknown(i32),
+        }
+
+        impl $name {
+            pub fn value(&self) -> i32 {
+                match self {
+                    $($name::$variant => $value,)*
+                    $name::Unknown(value) => *value,
+                }
+            }
+        }
+
+        impl From<i32> for $name {
+            fn from(value: i32) -> Self {
+                match value {
+                    $($value => $name::$variant,)*
+                    value => $name::Unknown(value),
+                }
+            }
+        }
+
+        impl $crate::modeling::typed::AvpField for $name {
+            fn to_avp_value(&self) -> $crate::modeling::avp::avp::AvpValue {
+                $crate::modeling::avp::enumerated::Enumerated::new(self.value()).into()
+            }
+
+            fn from_avp_value(
+                value: &$crate::modeling::avp::avp::AvpValue,
+            ) -> $crate::errors::DiameterResult<Self> {
+                value
+                    .as_i32()
+                    .map($name::from)
+                    .ok_or($crate::errors::Error::DecodeError("Unexpected AVP value type"))
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+pub mod __private {
+    pub use alloc::vec::Vec;
+}
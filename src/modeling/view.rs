@@ -0,0 +1,332 @@
+//! # Borrowed Message Views
+//!
+//! `MessageRef` and `AvpRef` read a Diameter message in place from a byte slice, such as a
+//! received buffer or a `bytes::Bytes`, without copying or decoding AVP values:
+//! ```
+//! use diameter_interface::modeling::avp::avp::AvpFlags;
+//! use diameter_interface::modeling::avp::utf8_string::UTF8String;
+//! use diameter_interface::modeling::diameter::DiameterMessage;
+//! use diameter_interface::modeling::message::application_id::ApplicationId;
+//! use diameter_interface::modeling::message::command_code::CommandCode;
+//! use diameter_interface::modeling::message::command_flags::CommandFlag;
+//! use diameter_interface::modeling::message::dictionary::{self, Dictionary};
+//! use diameter_interface::modeling::view::MessageRef;
+//! use std::sync::Arc;
+//!
+//! let dict = Arc::new(Dictionary::new(&[&dictionary::DEFAULT_DICT_XML]));
+//! let mut request = DiameterMessage::new(
+//!     CommandFlag::Request, CommandCode::CreditControl, ApplicationId::CreditControl, 7, 8,
+//! );
+//! request.add_avp(263, AvpFlags::M, None, UTF8String::new("host;1;1".to_string()));
+//! let mut buffer = vec![];
+//! request.encode_to(&mut buffer)?;
+//!
+//! let message = MessageRef::new(&buffer)?;
+//! if let Some(session_id) = message.get_avp(263, None).and_then(|avp| avp.as_str()) {
+//!     println!("{} {}", message.hop_by_hop(), session_id);
+//! }
+//! let owned: DiameterMessage = message.to_message(Arc::clone(&dict))?;
+//! assert_eq!(owned.avps(), request.avps());
+//! # Ok::<(), diameter_interface::errors::Error>(())
+//! ```
+//! `MessageRef::new` checks the header and the length of every top-level AVP once, iterating
+//! over the AVPs afterwards cannot fail. The AVPs of a Grouped AVP are checked when
+//! `AvpRef::as_grouped` is called, as the type of an AVP is only known to the dictionary.
+//!
+//! Values are read on access: `as_str` borrows the UTF-8 octets of the message and `as_bytes`
+//! returns the octets unchanged.
+
+use crate::errors::DiameterResult;
+use crate::errors::Error::DecodeError;
+use crate::modeling::avp::avp::Avp;
+use crate::modeling::decode_policy::{DecodePolicy, Deviation};
+use crate::modeling::diameter::DiameterMessage;
+use crate::modeling::message::application_id::ApplicationId;
+use crate::modeling::message::command_code::CommandCode;
+use crate::modeling::message::command_flags::CommandFlag;
+use crate::modeling::message::dictionary::Dictionary;
+use alloc::sync::Arc;
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+const HEADER_LENGTH: usize = 20;
+const AVP_HEADER_LENGTH: usize = 8;
+const AVP_VENDOR_HEADER_LENGTH: usize = 12;
+const VENDOR_FLAG_BIT: u8 = 0b10000000;
+const MANDATORY_FLAG_BIT: u8 = 0b01000000;
+
+const FAMILY_IPV4: u16 = 1;
+const FAMILY_IPV6: u16 = 2;
+
+/// A Diameter message borrowed from the bytes it was received in.
+#[derive(Debug, Clone, Copy)]
+pub struct MessageRef<'a> {
+    data: &'a [u8],
+}
+
+/// An AVP borrowed from the bytes of a message.
+#[derive(Debug, Clone, Copy)]
+pub struct AvpRef<'a> {
+    code: u32,
+    flags: u8,
+    vendor_id: Option<u32>,
+    data: &'a [u8], // the header and value, without padding
+    header_len: usize,
+}
+
+/// Iterates over a sequence of AVPs whose lengths have been checked.
+#[derive(Debug, Clone)]
+pub struct AvpIter<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> MessageRef<'a> {
+    /// Checks the header and the AVP lengths of the message at the start of `data`. Bytes after
+    /// the Message Length are not part of the message and are ignored.
+    pub fn new(data: &'a [u8]) -> DiameterResult<Self> {
+        Self::new_with_policy(data, DecodePolicy::strict())
+    }
+
+    /// As `new`, accepting a Message Length that is not a multiple of 4 when `policy` tolerates
+    /// `Deviation::MissingPadding`.
+    pub fn new_with_policy(data: &'a [u8], policy: DecodePolicy) -> DiameterResult<Self> {
+        let header = data
+            .first_chunk::<HEADER_LENGTH>()
+            .ok_or(DecodeError("Message header is truncated"))?;
+        if header[0] != 1 {
+            Err(DecodeError("Unsupported Diameter version"))?
+        }
+        let length = u32::from_be_bytes([0, header[1], header[2], header[3]]) as usize;
+        let padded = length.is_multiple_of(4) || policy.tolerates(Deviation::MissingPadding);
+        if length < HEADER_LENGTH || !padded {
+            Err(DecodeError("Invalid message length"))?
+        }
+        let data = data
+            .get(..length)
+            .ok_or(DecodeError("Message is shorter than its length"))?;
+        let message = Self { data };
+        validate(message.avp_data())?;
+        Ok(message)
+    }
+
+    /// A view of `data`, which holds exactly one message whose lengths have been checked.
+    pub(crate) fn new_unchecked(data: &'a [u8]) -> Self {
+        Self { data }
+    }
+
+    /// The bytes of the message, including the header.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.data
+    }
+
+    pub fn version(&self) -> u8 {
+        self.data[0]
+    }
+
+    pub fn message_length(&self) -> u32 {
+        self.data.len() as u32
+    }
+
+    pub fn command_flags(&self) -> u8 {
+        self.data[4]
+    }
+
+    pub fn has_flag(&self, flag: CommandFlag) -> bool {
+        self.command_flags() & flag.value() != 0
+    }
+
+    pub fn is_request(&self) -> bool {
+        self.has_flag(CommandFlag::Request)
+    }
+
+    pub fn command_code(&self) -> DiameterResult<CommandCode> {
+        CommandCode::try_from(self.read_u32(4) & 0x00ffffff)
+    }
+
+    pub fn application_id(&self) -> DiameterResult<ApplicationId> {
+        ApplicationId::try_from(self.read_u32(8))
+    }
+
+    pub fn hop_by_hop(&self) -> u32 {
+        self.read_u32(12)
+    }
+
+    pub fn end_to_end(&self) -> u32 {
+        self.read_u32(16)
+    }
+
+    /// The top-level AVPs, in order.
+    pub fn avps(&self) -> AvpIter<'a> {
+        AvpIter {
+            data: self.avp_data(),
+        }
+    }
+
+    /// Returns the first top-level AVP with the given code and vendor.
+    pub fn get_avp(&self, code: u32, vendor_id: Option<u32>) -> Option<AvpRef<'a>> {
+        self.avps()
+            .find(|avp| avp.code() == code && avp.vendor_id() == vendor_id)
+    }
+
+    /// Decodes the message into an owned `DiameterMessage`.
+    pub fn to_message(&self, dict: Arc<Dictionary>) -> DiameterResult<DiameterMessage> {
+        DiameterMessage::decode_from(&mut &self.data[..], dict)
+    }
+
+    fn avp_data(&self) -> &'a [u8] {
+        &self.data[HEADER_LENGTH..]
+    }
+
+    fn read_u32(&self, offset: usize) -> u32 {
+        let b = &self.data[offset..offset + 4];
+        u32::from_be_bytes([b[0], b[1], b[2], b[3]])
+    }
+}
+
+impl<'a> AvpRef<'a> {
+    pub fn code(&self) -> u32 {
+        self.code
+    }
+
+    pub fn vendor_id(&self) -> Option<u32> {
+        self.vendor_id
+    }
+
+    pub fn flags(&self) -> u8 {
+        self.flags
+    }
+
+    pub fn is_mandatory(&self) -> bool {
+        self.flags & MANDATORY_FLAG_BIT != 0
+    }
+
+    pub fn get_length(&self) -> u32 {
+        self.data.len() as u32
+    }
+
+    pub fn get_padding(&self) -> u32 {
+        (4 - self.get_length() % 4) % 4
+    }
+
+    /// The octets of the value, without the AVP header and padding.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        &self.data[self.header_len..]
+    }
+
+    /// Returns the value as UTF-8 text, for UTF8String and DiameterIdentity AVPs.
+    pub fn as_str(&self) -> Option<&'a str> {
+        core::str::from_utf8(self.as_bytes()).ok()
+    }
+
+    pub fn as_i32(&self) -> Option<i32> {
+        self.as_bytes().try_into().ok().map(i32::from_be_bytes)
+    }
+
+    pub fn as_i64(&self) -> Option<i64> {
+        self.as_bytes().try_into().ok().map(i64::from_be_bytes)
+    }
+
+    pub fn as_u32(&self) -> Option<u32> {
+        self.as_bytes().try_into().ok().map(u32::from_be_bytes)
+    }
+
+    pub fn as_u64(&self) -> Option<u64> {
+        self.as_bytes().try_into().ok().map(u64::from_be_bytes)
+    }
+
+    pub fn as_f32(&self) -> Option<f32> {
+        self.as_bytes().try_into().ok().map(f32::from_be_bytes)
+    }
+
+    pub fn as_f64(&self) -> Option<f64> {
+        self.as_bytes().try_into().ok().map(f64::from_be_bytes)
+    }
+
+    /// Returns the value of an Address AVP holding an IPv4 or IPv6 address.
+    pub fn as_ip_addr(&self) -> Option<IpAddr> {
+        let (family, address) = self.as_bytes().split_first_chunk::<2>()?;
+        match u16::from_be_bytes(*family) {
+            FAMILY_IPV4 => {
+                let octets: [u8; 4] = address.try_into().ok()?;
+                Some(IpAddr::V4(Ipv4Addr::from(octets)))
+            }
+            FAMILY_IPV6 => {
+                let octets: [u8; 16] = address.try_into().ok()?;
+                Some(IpAddr::V6(Ipv6Addr::from(octets)))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the AVPs of a Grouped AVP, or `None` when the value is not a valid sequence of
+    /// AVPs.
+    pub fn as_grouped(&self) -> Option<AvpIter<'a>> {
+        let data = self.as_bytes();
+        validate(data).ok()?;
+        Some(AvpIter { data })
+    }
+
+    /// Decodes the AVP into an owned `Avp`.
+    pub fn to_avp(&self, dict: Arc<Dictionary>) -> DiameterResult<Avp> {
+        let mut data = self.data.to_vec();
+        data.resize(data.len() + self.get_padding() as usize, 0);
+        Avp::decode_from(&mut &data[..], dict)
+    }
+}
+
+impl<'a> Iterator for AvpIter<'a> {
+    type Item = AvpRef<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.data.is_empty() {
+            return None;
+        }
+        let (avp, rest) = split_avp(self.data).ok()?;
+        self.data = rest;
+        Some(avp)
+    }
+}
+
+/// Checks that `data` is a sequence of complete AVPs.
+fn validate(mut data: &[u8]) -> DiameterResult<()> {
+    while !data.is_empty() {
+        let (_, rest) = split_avp(data)?;
+        data = rest;
+    }
+    Ok(())
+}
+
+/// Splits the first AVP from `data`, returning it and the bytes after its padding.
+fn split_avp(data: &[u8]) -> DiameterResult<(AvpRef<'_>, &[u8])> {
+    let b = data
+        .first_chunk::<AVP_HEADER_LENGTH>()
+        .ok_or(DecodeError("AVP header is truncated"))?;
+    let code = u32::from_be_bytes([b[0], b[1], b[2], b[3]]);
+    let flags = b[4];
+    let length = u32::from_be_bytes([0, b[5], b[6], b[7]]) as usize;
+    let (vendor_id, header_len) = match flags & VENDOR_FLAG_BIT != 0 {
+        false => (None, AVP_HEADER_LENGTH),
+        true => {
+            let b = data
+                .get(AVP_HEADER_LENGTH..AVP_VENDOR_HEADER_LENGTH)
+                .ok_or(DecodeError("AVP header is truncated"))?;
+            let vendor_id = u32::from_be_bytes([b[0], b[1], b[2], b[3]]);
+            (Some(vendor_id), AVP_VENDOR_HEADER_LENGTH)
+        }
+    };
+    if length < header_len {
+        Err(DecodeError("Invalid AVP length"))?
+    }
+    let avp_data = data
+        .get(..length)
+        .ok_or(DecodeError("AVP length exceeds the enclosing length"))?;
+    let padded_length = (length + 3) & !3;
+    let rest = data.get(padded_length..).unwrap_or_default();
+    let avp = AvpRef {
+        code,
+        flags,
+        vendor_id,
+        data: avp_data,
+        header_len,
+    };
+    Ok((avp, rest))
+}
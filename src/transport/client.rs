@@ -1,7 +1,10 @@
 use crate::errors::DiameterResult;
 use crate::errors::Error::ClientError;
+use crate::modeling::decode_policy::DecodePolicy;
 use crate::modeling::diameter::DiameterMessage;
 use crate::modeling::message::dictionary::Dictionary;
+use crate::modeling::message::identifiers::HopByHopGenerator;
+use crate::transport::local_node::LocalNode;
 use std::io::Write;
 use std::net::{Shutdown, TcpStream};
 use std::sync::Arc;
@@ -9,6 +12,10 @@ use std::sync::Arc;
 pub struct DiameterClient {
     address: &'static str,
     stream: Option<TcpStream>,
+    hop_by_hop: HopByHopGenerator,
+    local_node: Option<Arc<LocalNode>>,
+    buffer: Vec<u8>, // reused to encode every message
+    policy: DecodePolicy,
 }
 
 impl DiameterClient {
@@ -16,12 +23,29 @@ impl DiameterClient {
         Self {
             address,
             stream: None,
+            hop_by_hop: HopByHopGenerator::new(),
+            local_node: None,
+            buffer: Vec::new(),
+            policy: DecodePolicy::strict(),
         }
     }
 
+    /// Applies the identity of `local_node` to every message sent.
+    pub fn with_local_node(mut self, local_node: Arc<LocalNode>) -> Self {
+        self.local_node = Some(local_node);
+        self
+    }
+
+    /// Decodes the answers of the peer with `policy` instead of the strict policy.
+    pub fn with_decode_policy(mut self, policy: DecodePolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
     pub fn connect(&mut self) -> DiameterResult<()> {
         let stream = TcpStream::connect(self.address)?;
         self.stream = Some(stream);
+        self.hop_by_hop = HopByHopGenerator::new();
         Ok(())
     }
 
@@ -34,16 +58,25 @@ impl DiameterClient {
         }
     }
 
+    /// Sends `message` and waits for its answer. Requests are given the next Hop-by-Hop
+    /// identifier of the connection.
     pub fn send_message(
         &mut self,
         message: &mut DiameterMessage,
         dict: Arc<Dictionary>,
     ) -> DiameterResult<DiameterMessage> {
         if let Some(ref mut stream) = self.stream {
-            let mut buffer = vec![];
-            message.encode_to(&mut buffer)?;
-            stream.write_all(&buffer)?;
-            let answer = DiameterMessage::decode_from(stream, Arc::clone(&dict))?;
+            if let Some(local_node) = &self.local_node {
+                local_node.apply(message);
+            }
+            if message.is_request() {
+                message.set_hop_by_hop(self.hop_by_hop.next());
+            }
+            self.buffer.clear();
+            message.encode_to(&mut self.buffer)?;
+            stream.write_all(&self.buffer)?;
+            let answer =
+                DiameterMessage::decode_with_policy(stream, Arc::clone(&dict), self.policy)?;
             Ok(answer)
         } else {
             Err(ClientError("Connection not established yet!"))
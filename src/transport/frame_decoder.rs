@@ -0,0 +1,178 @@
+//! # Frame Decoder
+//!
+//! Splits a byte stream into Diameter messages, for sockets read in arbitrary fragments such as
+//! non-blocking sockets driven by mio or epoll:
+//! ```
+//! use diameter_interface::errors::DiameterResult;
+//! use diameter_interface::modeling::diameter::DiameterMessage;
+//! use diameter_interface::modeling::message::application_id::ApplicationId;
+//! use diameter_interface::modeling::message::command_code::CommandCode;
+//! use diameter_interface::modeling::message::command_flags::CommandFlag;
+//! use diameter_interface::modeling::message::dictionary::{self, Dictionary};
+//! use diameter_interface::transport::frame_decoder::FrameDecoder;
+//! use std::io::{ErrorKind, Read};
+//! use std::sync::Arc;
+//!
+//! fn receive<R: Read>(
+//!     decoder: &mut FrameDecoder,
+//!     socket: &mut R,
+//!     handle: &mut impl FnMut(DiameterMessage),
+//! ) -> DiameterResult<()> {
+//!     loop {
+//!         match decoder.read_from(socket) {
+//!             Ok(0) => break, // closed by the peer
+//!             Ok(_) => {}
+//!             Err(e) if e.kind() == ErrorKind::WouldBlock => break,
+//!             Err(e) => return Err(e.into()),
+//!         }
+//!         while let Some(message) = decoder.next_message()? {
+//!             handle(message);
+//!         }
+//!     }
+//!     Ok(())
+//! }
+//!
+//! let dict = Arc::new(Dictionary::new(&[&dictionary::DEFAULT_DICT_XML]));
+//! let mut stream = vec![];
+//! for hop_by_hop in 1..=2 {
+//!     let dwr = DiameterMessage::new(
+//!         CommandFlag::Request, CommandCode::DeviceWatchdog, ApplicationId::Common, hop_by_hop, 1,
+//!     );
+//!     dwr.encode_to(&mut stream)?;
+//! }
+//!
+//! let mut decoder = FrameDecoder::new(Arc::clone(&dict));
+//! let mut received = vec![];
+//! receive(&mut decoder, &mut stream.as_slice(), &mut |message| received.push(message))?;
+//! assert_eq!(received.len(), 2);
+//! # Ok::<(), diameter_interface::errors::Error>(())
+//! ```
+//! The version and Message Length of a frame are checked as soon as they are buffered, before
+//! the body of the frame. A frame with an invalid header cannot be skipped, as the start of
+//! the next frame is unknown, and the connection should be closed. A frame with a valid header
+//! whose AVPs fail to decode is dropped, and decoding continues with the next frame.
+//!
+//! Relays can take frames with `next_frame` instead, as `RawMessage`s whose AVPs are not decoded.
+//!
+//! Messages are decoded with the strict `DecodePolicy`, or the policy set for the peer with
+//! `with_decode_policy`. A policy tolerating `Deviation::MissingPadding` also accepts a Message
+//! Length that is not a multiple of 4, in `next_message` and `next_frame`.
+
+use crate::errors::DiameterResult;
+use crate::errors::Error::DecodeError;
+use crate::modeling::decode_policy::{DecodePolicy, Deviation};
+use crate::modeling::diameter::DiameterMessage;
+use crate::modeling::message::dictionary::Dictionary;
+use crate::modeling::raw::RawMessage;
+use std::io;
+use std::io::Read;
+use std::sync::Arc;
+
+const HEADER_LENGTH: usize = 20;
+const MAX_MESSAGE_LENGTH: usize = 0x00ffffff;
+const READ_CHUNK_SIZE: usize = 4096;
+
+pub struct FrameDecoder {
+    dict: Arc<Dictionary>,
+    buffer: Vec<u8>,
+    max_length: usize,
+    policy: DecodePolicy,
+}
+
+impl FrameDecoder {
+    pub fn new(dict: Arc<Dictionary>) -> Self {
+        Self {
+            dict,
+            buffer: Vec::new(),
+            max_length: MAX_MESSAGE_LENGTH,
+            policy: DecodePolicy::strict(),
+        }
+    }
+
+    /// Rejects frames longer than `max_length` octets before buffering them.
+    pub fn with_max_length(mut self, max_length: usize) -> Self {
+        self.max_length = max_length;
+        self
+    }
+
+    /// Decodes messages with `policy` instead of the strict policy.
+    pub fn with_decode_policy(mut self, policy: DecodePolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Appends `data` to the buffered bytes.
+    pub fn feed(&mut self, data: &[u8]) -> DiameterResult<()> {
+        self.buffer.extend_from_slice(data);
+        self.frame_length()?;
+        Ok(())
+    }
+
+    /// Reads the bytes available from `reader` into the buffer, returning their number. A
+    /// return of `0` means the end of the stream. `WouldBlock` and other errors of `reader` are
+    /// returned unchanged.
+    pub fn read_from<R: Read>(&mut self, reader: &mut R) -> io::Result<usize> {
+        let buffered = self.buffer.len();
+        self.buffer.resize(buffered + READ_CHUNK_SIZE, 0);
+        let result = reader.read(&mut self.buffer[buffered..]);
+        let read = *result.as_ref().unwrap_or(&0);
+        self.buffer.truncate(buffered + read);
+        result
+    }
+
+    /// Returns the next complete message, or `None` until more bytes are buffered.
+    pub fn next_message(&mut self) -> DiameterResult<Option<DiameterMessage>> {
+        let Some(length) = self.complete_frame_length()? else {
+            return Ok(None);
+        };
+        let message = DiameterMessage::decode_with_policy(
+            &mut &self.buffer[..length],
+            Arc::clone(&self.dict),
+            self.policy,
+        );
+        self.buffer.drain(..length);
+        message.map(Some)
+    }
+
+    /// Returns the next complete message without decoding its AVPs, or `None` until more bytes
+    /// are buffered.
+    pub fn next_frame(&mut self) -> DiameterResult<Option<RawMessage>> {
+        let Some(length) = self.complete_frame_length()? else {
+            return Ok(None);
+        };
+        let frame: Vec<u8> = self.buffer.drain(..length).collect();
+        RawMessage::new_with_policy(frame, self.policy).map(Some)
+    }
+
+    /// The number of buffered bytes not yet returned as a message.
+    pub fn buffered(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Returns the length of the next frame once all of its bytes are buffered.
+    fn complete_frame_length(&self) -> DiameterResult<Option<usize>> {
+        match self.frame_length()? {
+            Some(length) if self.buffer.len() >= length => Ok(Some(length)),
+            _ => Ok(None),
+        }
+    }
+
+    /// Checks the header of the next frame, returning its length once known.
+    fn frame_length(&self) -> DiameterResult<Option<usize>> {
+        if self.buffer.first().is_some_and(|version| *version != 1) {
+            Err(DecodeError("Unsupported Diameter version"))?
+        }
+        let Some(b) = self.buffer.first_chunk::<4>() else {
+            return Ok(None);
+        };
+        let length = u32::from_be_bytes([0, b[1], b[2], b[3]]) as usize;
+        let padded = length.is_multiple_of(4) || self.policy.tolerates(Deviation::MissingPadding);
+        if length < HEADER_LENGTH || !padded {
+            Err(DecodeError("Invalid message length"))?
+        }
+        if length > self.max_length {
+            Err(DecodeError("Message length exceeds the maximum"))?
+        }
+        Ok(Some(length))
+    }
+}
@@ -0,0 +1,256 @@
+//! # Local Node
+//!
+//! The identity of this Diameter node, applied to every outgoing request and answer:
+//! ```
+//! use diameter_interface::modeling::avp::avp::AvpFlags;
+//! use diameter_interface::modeling::avp::utf8_string::Identity;
+//! use diameter_interface::modeling::diameter::DiameterMessage;
+//! use diameter_interface::modeling::message::application_id::ApplicationId;
+//! use diameter_interface::modeling::message::command_code::CommandCode;
+//! use diameter_interface::modeling::message::command_flags::CommandFlag;
+//! use diameter_interface::transport::client::DiameterClient;
+//! use diameter_interface::transport::local_node::LocalNode;
+//! use std::sync::Arc;
+//!
+//! let state_file = std::env::temp_dir().join("local-node-doc-origin-state-id");
+//! let node = Arc::new(
+//!     LocalNode::new("client.example.com", "example.com")
+//!         .with_vendor_id(10415)
+//!         .with_product_name("my-client")
+//!         .with_state_file(&state_file)?,
+//! );
+//! let client = DiameterClient::new("127.0.0.1:3868").with_local_node(Arc::clone(&node));
+//!
+//! let mut dwr = DiameterMessage::new(
+//!     CommandFlag::Request, CommandCode::DeviceWatchdog, ApplicationId::Common, 1, 1,
+//! );
+//! dwr.add_avp(264, AvpFlags::M, None, Identity::new("stale.example.com".to_string()));
+//! node.apply(&mut dwr);
+//! let origin_host = dwr.get_avp(264, None).and_then(|avp| avp.value().as_str());
+//! assert_eq!(origin_host, Some("client.example.com"));
+//! # std::fs::remove_file(state_file)?;
+//! # Ok::<(), diameter_interface::errors::Error>(())
+//! ```
+//! Origin-Host, Origin-Realm and Origin-State-Id are set on every message, replacing every
+//! occurrence already present. CER and CEA also get Vendor-Id, Product-Name and, unless
+//! present, Host-IP-Address.
+//!
+//! Origin-State-Id must increase whenever the node restarts (RFC 6733 §8.16). It defaults to
+//! the start time in seconds, `with_state_file` keeps a counter in a file instead.
+
+use crate::errors::DiameterResult;
+use crate::errors::Error::ClientError;
+use crate::modeling::avp::address::Address;
+use crate::modeling::avp::avp::{Avp, AvpFlags};
+use crate::modeling::avp::unsigned32::Unsigned32;
+use crate::modeling::avp::utf8_string::{Identity, UTF8String};
+use crate::modeling::diameter::DiameterMessage;
+use crate::modeling::message::command_code::CommandCode;
+use crate::modeling::message::result_code::ResultCode;
+use std::fs;
+use std::io::ErrorKind;
+use std::net::IpAddr;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const HOST_IP_ADDRESS: u32 = 257;
+const ORIGIN_HOST: u32 = 264;
+const VENDOR_ID: u32 = 266;
+const PRODUCT_NAME: u32 = 269;
+const ORIGIN_STATE_ID: u32 = 278;
+const ORIGIN_REALM: u32 = 296;
+
+#[derive(Debug, Clone)]
+pub struct LocalNode {
+    origin_host: String,
+    origin_realm: String,
+    origin_state_id: u32,
+    vendor_id: u32,
+    product_name: String,
+    host_ip_addresses: Vec<IpAddr>,
+}
+
+impl LocalNode {
+    pub fn new(origin_host: &str, origin_realm: &str) -> Self {
+        let origin_state_id = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs() as u32)
+            .unwrap_or(0);
+        Self {
+            origin_host: origin_host.to_string(),
+            origin_realm: origin_realm.to_string(),
+            origin_state_id,
+            vendor_id: 0,
+            product_name: env!("CARGO_PKG_NAME").to_string(),
+            host_ip_addresses: vec![],
+        }
+    }
+
+    pub fn with_vendor_id(mut self, vendor_id: u32) -> Self {
+        self.vendor_id = vendor_id;
+        self
+    }
+
+    pub fn with_product_name(mut self, product_name: &str) -> Self {
+        self.product_name = product_name.to_string();
+        self
+    }
+
+    pub fn with_host_ip_address(mut self, host_ip_address: IpAddr) -> Self {
+        self.host_ip_addresses.push(host_ip_address);
+        self
+    }
+
+    pub fn with_origin_state_id(mut self, origin_state_id: u32) -> Self {
+        self.origin_state_id = origin_state_id;
+        self
+    }
+
+    /// Takes the Origin-State-Id from the file at `path`, incremented by one, and writes the new
+    /// value back. A missing file starts the counter at the current Origin-State-Id.
+    pub fn with_state_file<P: AsRef<Path>>(mut self, path: P) -> DiameterResult<Self> {
+        match fs::read_to_string(&path) {
+            Ok(content) => {
+                let previous: u32 = content
+                    .trim()
+                    .parse()
+                    .map_err(|_| ClientError("Invalid Origin-State-Id state file"))?;
+                self.origin_state_id = previous.wrapping_add(1);
+            }
+            Err(e) if e.kind() == ErrorKind::NotFound => {}
+            Err(e) => return Err(e.into()),
+        }
+        // Written aside and renamed over the state file, so that a crash while writing cannot
+        // leave it truncated
+        let mut temporary = path.as_ref().as_os_str().to_owned();
+        temporary.push(".tmp");
+        fs::write(&temporary, self.origin_state_id.to_string())?;
+        fs::rename(&temporary, &path)?;
+        Ok(self)
+    }
+
+    pub fn origin_host(&self) -> &str {
+        &self.origin_host
+    }
+
+    pub fn origin_realm(&self) -> &str {
+        &self.origin_realm
+    }
+
+    pub fn origin_state_id(&self) -> u32 {
+        self.origin_state_id
+    }
+
+    pub fn vendor_id(&self) -> u32 {
+        self.vendor_id
+    }
+
+    pub fn product_name(&self) -> &str {
+        &self.product_name
+    }
+
+    pub fn host_ip_addresses(&self) -> &[IpAddr] {
+        &self.host_ip_addresses
+    }
+
+    /// Sets the identity AVPs of this node on `message`.
+    pub fn apply(&self, message: &mut DiameterMessage) {
+        replace(
+            message,
+            Avp::new(
+                ORIGIN_HOST,
+                AvpFlags::M,
+                None,
+                Identity::new(self.origin_host.clone()),
+            ),
+        );
+        replace(
+            message,
+            Avp::new(
+                ORIGIN_REALM,
+                AvpFlags::M,
+                None,
+                Identity::new(self.origin_realm.clone()),
+            ),
+        );
+        replace(
+            message,
+            Avp::new(
+                ORIGIN_STATE_ID,
+                AvpFlags::M,
+                None,
+                Unsigned32::new(self.origin_state_id),
+            ),
+        );
+        if message.command_code() != CommandCode::CapabilitiesExchange {
+            return;
+        }
+        if message.get_avp(HOST_IP_ADDRESS, None).is_none() {
+            for host_ip_address in &self.host_ip_addresses {
+                message.add_avp(
+                    HOST_IP_ADDRESS,
+                    AvpFlags::M,
+                    None,
+                    Address::new(*host_ip_address),
+                );
+            }
+        }
+        replace(
+            message,
+            Avp::new(
+                VENDOR_ID,
+                AvpFlags::M,
+                None,
+                Unsigned32::new(self.vendor_id),
+            ),
+        );
+        replace(
+            message,
+            Avp::new(
+                PRODUCT_NAME,
+                AvpFlags::O,
+                None,
+                UTF8String::new(self.product_name.clone()),
+            ),
+        );
+    }
+
+    /// `DiameterMessage::answer_for` with the identity of this node applied.
+    pub fn answer_for(&self, request: &DiameterMessage) -> DiameterMessage {
+        let mut answer = DiameterMessage::answer_for(request);
+        self.apply(&mut answer);
+        answer
+    }
+
+    /// `DiameterMessage::error_answer_for` from this node.
+    pub fn error_answer_for(
+        &self,
+        request: &DiameterMessage,
+        result_code: ResultCode,
+        failed_avps: Vec<Avp>,
+    ) -> DiameterMessage {
+        let mut answer = DiameterMessage::error_answer_for(
+            request,
+            &self.origin_host,
+            &self.origin_realm,
+            result_code,
+            failed_avps,
+        );
+        self.apply(&mut answer);
+        answer
+    }
+}
+
+/// Sets `avp` as the only top-level AVP with its code and vendor, in place of a single
+/// occurrence or after the other AVPs when there were several.
+fn replace(message: &mut DiameterMessage, avp: Avp) {
+    let occurrences = message
+        .avps()
+        .iter()
+        .filter(|existing| existing.code() == avp.code() && existing.vendor_id() == avp.vendor_id())
+        .count();
+    if occurrences > 1 {
+        message.remove_avps(avp.code(), avp.vendor_id());
+    }
+    message.set_avp(avp);
+}
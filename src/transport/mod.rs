@@ -1 +1,3 @@
 pub mod client;
+pub mod frame_decoder;
+pub mod local_node;
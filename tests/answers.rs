@@ -0,0 +1,133 @@
+mod common;
+
+use common::{dictionary, encode, encode_avp};
+use diameter_interface::modeling::avp::avp::{Avp, AvpFlags};
+use diameter_interface::modeling::avp::group::Grouped;
+use diameter_interface::modeling::avp::unsigned32::Unsigned32;
+use diameter_interface::modeling::avp::utf8_string::{Identity, UTF8String};
+use diameter_interface::modeling::diameter::DiameterMessage;
+use diameter_interface::modeling::message::application_id::ApplicationId;
+use diameter_interface::modeling::message::command_code::CommandCode;
+use diameter_interface::modeling::message::command_flags::CommandFlag;
+use diameter_interface::modeling::message::result_code::ResultCode;
+
+fn proxy_info(host: &str) -> Avp {
+    Avp::new(
+        284,
+        AvpFlags::M,
+        None,
+        Grouped::new(vec![
+            Avp::new(280, AvpFlags::M, None, Identity::new(host.to_string())),
+            Avp::new(33, AvpFlags::M, None, UTF8String::new(host.to_string())),
+        ]),
+    )
+}
+
+fn request() -> DiameterMessage {
+    let mut request = DiameterMessage::new(
+        CommandFlag::Request,
+        CommandCode::CreditControl,
+        ApplicationId::CreditControl,
+        11,
+        22,
+    );
+    request.set_flag(CommandFlag::Proxyable);
+    request.add_avp(
+        263,
+        AvpFlags::M,
+        None,
+        UTF8String::new("client.example.com;1;1".to_string()),
+    );
+    request.add(proxy_info("proxy1.example.com"));
+    request.add_avp(416, AvpFlags::M, None, Unsigned32::new(1));
+    request.add(proxy_info("proxy2.example.com"));
+    request
+}
+
+fn codes(message: &DiameterMessage) -> Vec<u32> {
+    message.avps().iter().map(|avp| avp.code()).collect()
+}
+
+fn assert_consistent_length(message: &DiameterMessage) {
+    let encoded = encode(message);
+    assert_eq!(encoded.len(), message.encoded_len());
+    DiameterMessage::decode_from(&mut encoded.as_slice(), dictionary()).unwrap();
+}
+
+#[test]
+fn answer_copies_the_request() {
+    let request = request();
+    let answer = DiameterMessage::answer_for(&request);
+
+    assert!(!answer.is_request());
+    assert!(answer.has_flag(CommandFlag::Proxyable));
+    assert!(!answer.has_flag(CommandFlag::Error));
+    assert_eq!(answer.command_code(), CommandCode::CreditControl);
+    assert_eq!(answer.application_id(), ApplicationId::CreditControl);
+    assert_eq!((answer.hop_by_hop(), answer.end_to_end()), (11, 22));
+    assert_eq!(codes(&answer), vec![263, 284, 284]);
+    assert_eq!(
+        encode_avp(&answer.avps()[1]),
+        encode_avp(&request.avps()[1])
+    );
+    assert_eq!(
+        encode_avp(&answer.avps()[2]),
+        encode_avp(&request.avps()[3])
+    );
+    assert_consistent_length(&answer);
+}
+
+#[test]
+fn protocol_errors_set_the_e_bit() {
+    let failed = Avp::new(416, AvpFlags::M, None, Unsigned32::new(1));
+    let answer = DiameterMessage::error_answer_for(
+        &request(),
+        "server.example.com",
+        "example.com",
+        ResultCode::UNABLE_TO_DELIVER,
+        vec![failed.clone()],
+    );
+
+    assert!(answer.has_flag(CommandFlag::Error));
+    assert!(!answer.is_request());
+    assert_eq!(codes(&answer), vec![263, 264, 296, 268, 279, 284, 284]);
+    assert_eq!(answer.result_code(), Some(ResultCode::UNABLE_TO_DELIVER));
+    let failed_avp = answer.get_avp(279, None).unwrap().value().as_grouped();
+    assert_eq!(failed_avp.map(|avps| avps.len()), Some(1));
+    assert_eq!(encode_avp(&failed_avp.unwrap()[0]), encode_avp(&failed));
+    assert_consistent_length(&answer);
+}
+
+#[test]
+fn other_failures_leave_the_e_bit_clear() {
+    for result_code in [
+        ResultCode::AUTHENTICATION_REJECTED,
+        ResultCode::MISSING_AVP,
+        ResultCode::ERROR_USER_UNKNOWN,
+        ResultCode::SUCCESS,
+    ] {
+        let answer = DiameterMessage::error_answer_for(
+            &request(),
+            "server.example.com",
+            "example.com",
+            result_code,
+            vec![],
+        );
+        assert!(!answer.has_flag(CommandFlag::Error), "{}", result_code);
+        assert_eq!(answer.result_code(), Some(result_code));
+        assert!(answer.get_avp(279, None).is_none());
+    }
+}
+
+#[test]
+fn experimental_codes_use_experimental_result() {
+    let answer = DiameterMessage::error_answer_for(
+        &request(),
+        "server.example.com",
+        "example.com",
+        ResultCode::ERROR_USER_UNKNOWN,
+        vec![],
+    );
+    assert_eq!(codes(&answer), vec![263, 264, 296, 297, 284, 284]);
+    assert_consistent_length(&answer);
+}
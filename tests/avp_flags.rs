@@ -0,0 +1,19 @@
+mod common;
+
+use common::{dictionary, encode_avp};
+use diameter_interface::modeling::avp::avp::{Avp, AvpFlags};
+use diameter_interface::modeling::avp::unsigned32::Unsigned32;
+
+#[test]
+fn flags_are_encoded() {
+    let mandatory = Avp::new(268, AvpFlags::M, None, Unsigned32::new(2001));
+    assert_eq!(encode_avp(&mandatory)[4], 0x40);
+
+    // Firmware-Revision, which must not have the M bit
+    let optional = Avp::new(267, AvpFlags::O, None, Unsigned32::new(1));
+    let encoded = encode_avp(&optional);
+    assert_eq!(encoded[4], 0);
+
+    let decoded = Avp::decode_from(&mut encoded.as_slice(), dictionary()).unwrap();
+    assert_eq!(encode_avp(&decoded), encoded);
+}
@@ -0,0 +1,85 @@
+mod common;
+
+use common::wire_round_trip;
+use diameter_interface::modeling::message::application_id::ApplicationId;
+use diameter_interface::modeling::message::base::{
+    AbortSessionAnswer, AbortSessionRequest, ReAuthAnswer, ReAuthRequest, SessionTerminationAnswer,
+    SessionTerminationRequest,
+};
+use diameter_interface::modeling::message::result_code::ResultCode;
+use diameter_interface::modeling::typed::TypedMessage;
+
+const SESSION_ID: &str = "client.example.com;1;1";
+const GX: u32 = 16777238;
+
+#[test]
+fn requests_take_auth_application_id() {
+    let rar = ReAuthRequest::new(
+        SESSION_ID,
+        "server.example.com",
+        "example.com",
+        "client.example.com",
+        "example.com",
+        GX,
+    );
+    let message = rar.to_message(1, 1);
+    assert_eq!(message.application_id(), ApplicationId::Gx);
+    let decoded = ReAuthRequest::from_message(&wire_round_trip(&message)).unwrap();
+    assert_eq!(decoded.auth_application_id, GX);
+
+    let asr = AbortSessionRequest::new(
+        SESSION_ID,
+        "server.example.com",
+        "example.com",
+        "client.example.com",
+        "example.com",
+        4,
+    );
+    assert_eq!(
+        asr.to_message(1, 1).application_id(),
+        ApplicationId::CreditControl
+    );
+
+    let stq = SessionTerminationRequest::new(
+        SESSION_ID,
+        "client.example.com",
+        "example.com",
+        "example.com",
+        GX,
+    );
+    assert_eq!(stq.to_message(1, 1).application_id(), ApplicationId::Gx);
+}
+
+#[test]
+fn answers_take_auth_application_id() {
+    let mut raa = ReAuthAnswer::new(SESSION_ID, "client.example.com", "example.com");
+    assert_eq!(raa.to_message(1, 1).application_id(), ApplicationId::Common);
+    raa.auth_application_id = Some(GX);
+    let message = raa.to_message(1, 1);
+    assert_eq!(message.application_id(), ApplicationId::Gx);
+    let decoded = ReAuthAnswer::from_message(&wire_round_trip(&message)).unwrap();
+    assert_eq!(decoded.auth_application_id, Some(GX));
+    assert_eq!(ResultCode::from(decoded.result_code), ResultCode::SUCCESS);
+
+    let mut asa = AbortSessionAnswer::new(SESSION_ID, "client.example.com", "example.com");
+    asa.auth_application_id = Some(4);
+    assert_eq!(
+        asa.to_message(1, 1).application_id(),
+        ApplicationId::CreditControl
+    );
+
+    let mut sta = SessionTerminationAnswer::new(SESSION_ID, "server.example.com", "example.com");
+    sta.auth_application_id = Some(GX);
+    let decoded = SessionTerminationAnswer::from_message(&sta.to_message(1, 1)).unwrap();
+    assert_eq!(decoded.auth_application_id, Some(GX));
+}
+
+#[test]
+fn unknown_application_falls_back_to_common() {
+    let mut raa = ReAuthAnswer::new(SESSION_ID, "client.example.com", "example.com");
+    raa.auth_application_id = Some(99);
+    let message = raa.to_message(1, 1);
+    assert_eq!(message.application_id(), ApplicationId::Common);
+    let decoded = ReAuthAnswer::from_message(&message).unwrap();
+    assert_eq!(decoded.auth_application_id, Some(99));
+}
@@ -0,0 +1,150 @@
+//! The generated modules are checked in under `tests/generated` and compiled by this test.
+//! After a change to the generator, refresh them with
+//! `UPDATE_GENERATED=1 cargo test --test codegen`.
+
+use diameter_interface::codegen;
+use diameter_interface::modeling::message::dictionary::{self, Dictionary};
+use std::path::Path;
+
+#[allow(clippy::all)]
+mod default_dictionary {
+    include!("generated/default_dictionary.rs");
+}
+
+#[allow(clippy::all)]
+mod keywords {
+    include!("generated/keywords.rs");
+}
+
+const KEYWORDS_XML: &str = r#"
+<diameter>
+	<vendor id="10415" name="3GPP"/>
+	<application id="0" name="Base">
+		<avp name="Type" code="5000" must="M" may="P" must-not="V" may-encrypt="-">
+			<data type="Unsigned32"/>
+		</avp>
+		<avp name="Self" code="5001" must="M" may="P" must-not="V" may-encrypt="-">
+			<data type="Enumerated">
+				<item code="0" name="SELF"/>
+				<item code="1" name="GEN"/>
+				<item code="2" name="ASYNC"/>
+				<item code="3" name="3GPP"/>
+			</data>
+		</avp>
+		<avp name="Result" code="5002" must="M" may="P" must-not="V" may-encrypt="-">
+			<data type="Enumerated">
+				<item code="1" name="OK"/>
+				<item code="2" name="ERR"/>
+			</data>
+		</avp>
+		<avp name="Find" code="5003" must="M" may="P" must-not="V" may-encrypt="-">
+			<data type="UTF8String"/>
+		</avp>
+		<avp name="Get-Foo" code="5004" must="M" may="P" must-not="V" may-encrypt="-">
+			<data type="Unsigned32"/>
+		</avp>
+		<avp name="Foo" code="5005" must="M" may="P" must-not="V" may-encrypt="-">
+			<data type="Unsigned32"/>
+		</avp>
+		<avp name="Try-From" code="5006" must="M" may="P" must-not="V" may-encrypt="-">
+			<data type="Enumerated">
+				<item code="0" name="DYN"/>
+			</data>
+		</avp>
+		<avp name="Crate" code="5007" must="M" may="P" must-not="V" may-encrypt="-">
+			<data type="OctetString"/>
+		</avp>
+		<avp name="Super" code="5008" must="V" may="P" must-not="M" may-encrypt="-" vendor-id="10415">
+			<data type="Grouped">
+				<rule avp="Type" required="false"/>
+			</data>
+		</avp>
+		<avp name="Match" code="5009" must="M" may="P" must-not="V" may-encrypt="-">
+			<data type="Time"/>
+		</avp>
+	</application>
+</diameter>"#;
+
+/// Compares the generated source with the checked-in file, or rewrites the file when
+/// `UPDATE_GENERATED` is set.
+fn assert_generated(dict: &Dictionary, file: &str) {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/generated")
+        .join(file);
+    let generated = codegen::generate(dict);
+    if std::env::var_os("UPDATE_GENERATED").is_some() {
+        std::fs::write(&path, &generated).unwrap();
+    }
+    let checked_in = std::fs::read_to_string(&path).unwrap();
+    assert!(
+        generated == checked_in,
+        "{} is out of date, run UPDATE_GENERATED=1 cargo test --test codegen",
+        file
+    );
+}
+
+#[test]
+fn default_dictionary_is_current() {
+    let dict = Dictionary::new(&[&dictionary::DEFAULT_DICT_XML]);
+    assert_generated(&dict, "default_dictionary.rs");
+}
+
+#[test]
+fn keywords_are_current() {
+    assert_generated(&Dictionary::new(&[KEYWORDS_XML]), "keywords.rs");
+}
+
+#[test]
+fn default_dictionary_accessors() {
+    use default_dictionary::{avp, code, enums, vendor};
+
+    assert_eq!(vendor::_3GPP, 10415);
+    assert_eq!(code::SESSION_ID, 263);
+
+    let avps = vec![
+        avp::session_id("host;1;2"),
+        avp::cc_request_type(enums::CcRequestType::UpdateRequest),
+        avp::cc_request_number(3),
+    ];
+    assert_eq!(avps[0].code(), code::SESSION_ID);
+    assert!(avps[0].is_mandatory());
+    assert_eq!(avp::get_session_id(&avps), Some("host;1;2"));
+    assert_eq!(
+        avp::get_cc_request_type(&avps),
+        Some(enums::CcRequestType::UpdateRequest)
+    );
+    assert_eq!(avp::get_cc_request_number(&avps), Some(3));
+    assert_eq!(avp::get_origin_host(&avps), None);
+    assert_eq!(enums::CcRequestType::try_from(9), Err(9));
+}
+
+#[test]
+fn keyword_names_are_escaped() {
+    use keywords::{avp, code, enums};
+
+    assert_eq!(code::TYPE, 5000);
+    assert_eq!(code::SELF, 5001);
+    let avps = vec![
+        avp::type_(7),
+        avp::self_(enums::Self_::Gen),
+        avp::result(enums::Result::Err),
+        avp::find_5003("found"),
+        avp::get_foo(1),
+        avp::foo_5005(2),
+        avp::try_from(enums::TryFrom::Dyn),
+        avp::crate_(vec![1, 2]),
+        avp::super_(vec![avp::type_(8)]),
+    ];
+    assert_eq!(avp::get_type_(&avps), Some(7));
+    assert_eq!(avp::get_self_(&avps), Some(enums::Self_::Gen));
+    assert_eq!(enums::Self_::Self_ as i32, 0);
+    assert_eq!(enums::Self_::Value3gpp as i32, 3);
+    assert_eq!(avp::get_result(&avps), Some(enums::Result::Err));
+    assert_eq!(avp::get_find_5003(&avps), Some("found"));
+    assert_eq!(avp::get_get_foo(&avps), Some(1));
+    assert_eq!(avp::get_foo_5005(&avps), Some(2));
+    assert_eq!(avp::get_try_from(&avps), Some(enums::TryFrom::Dyn));
+    assert_eq!(avp::get_crate_(&avps), Some(&[1u8, 2][..]));
+    assert_eq!(avps[8].vendor_id(), Some(10415));
+    assert_eq!(avp::get_type_(avp::get_super_(&avps).unwrap()), Some(8));
+}
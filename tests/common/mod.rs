@@ -0,0 +1,35 @@
+//! Helpers shared by the integration tests.
+#![allow(dead_code)] // each test crate uses a part of them
+
+use diameter_interface::modeling::avp::avp::Avp;
+use diameter_interface::modeling::diameter::DiameterMessage;
+use diameter_interface::modeling::message::dictionary::{self, Dictionary};
+use std::sync::Arc;
+
+/// The default dictionary.
+pub fn dictionary() -> Arc<Dictionary> {
+    Arc::new(Dictionary::new(&[&dictionary::DEFAULT_DICT_XML]))
+}
+
+pub fn encode(message: &DiameterMessage) -> Vec<u8> {
+    let mut encoded = vec![];
+    message.encode_to(&mut encoded).unwrap();
+    encoded
+}
+
+pub fn encode_avp(avp: &Avp) -> Vec<u8> {
+    let mut encoded = vec![];
+    avp.encode_to(&mut encoded).unwrap();
+    encoded
+}
+
+/// Encodes `message` and decodes it back with `dict`.
+pub fn wire_round_trip_with(message: &DiameterMessage, dict: Arc<Dictionary>) -> DiameterMessage {
+    let encoded = encode(message);
+    DiameterMessage::decode_from(&mut encoded.as_slice(), dict).unwrap()
+}
+
+/// Encodes `message` and decodes it back with the default dictionary.
+pub fn wire_round_trip(message: &DiameterMessage) -> DiameterMessage {
+    wire_round_trip_with(message, dictionary())
+}
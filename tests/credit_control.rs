@@ -0,0 +1,112 @@
+mod common;
+
+use common::wire_round_trip;
+use diameter_interface::modeling::message::application_id::ApplicationId;
+use diameter_interface::modeling::message::credit_control::{
+    CcRequestType, CreditControlAnswer, CreditControlRequest, GrantedServiceUnit,
+    MultipleServicesCreditControl, RequestedServiceUnit, SubscriptionId, SubscriptionIdType,
+};
+use diameter_interface::modeling::message::result_code::ResultCode;
+use diameter_interface::modeling::typed::TypedMessage;
+
+const SESSION_ID: &str = "client.example.com;1;1";
+const GX: u32 = 16777238;
+
+fn ccr(auth_application_id: u32) -> CreditControlRequest {
+    let mut ccr = CreditControlRequest::new(
+        SESSION_ID,
+        "client.example.com",
+        "example.com",
+        "example.com",
+        auth_application_id,
+        "32251@3gpp.org",
+    );
+    ccr.subscription_id.push(SubscriptionId::new(
+        SubscriptionIdType::EndUserImsi,
+        "001010123456789",
+    ));
+    ccr.multiple_services_credit_control
+        .push(MultipleServicesCreditControl::requesting(
+            RequestedServiceUnit::default(),
+        ));
+    ccr
+}
+
+fn cca(auth_application_id: Option<u32>) -> CreditControlAnswer {
+    let mut cca = CreditControlAnswer::new(
+        SESSION_ID,
+        "ocs.example.com",
+        "example.com",
+        CcRequestType::InitialRequest,
+        0,
+    );
+    cca.auth_application_id = auth_application_id;
+    cca
+}
+
+#[test]
+fn request_round_trip() {
+    let message = ccr(4).to_message(1, 2);
+    assert!(message.is_request());
+    assert_eq!(message.application_id(), ApplicationId::CreditControl);
+
+    let decoded = CreditControlRequest::from_message(&wire_round_trip(&message)).unwrap();
+    assert_eq!(decoded.session_id, SESSION_ID);
+    assert_eq!(decoded.cc_request_type, CcRequestType::InitialRequest);
+    assert_eq!(
+        decoded.subscription_id[0].subscription_id_type,
+        SubscriptionIdType::EndUserImsi
+    );
+    assert_eq!(decoded.multiple_services_credit_control.len(), 1);
+    assert!(decoded.other.is_empty());
+}
+
+#[test]
+fn answer_round_trip() {
+    let mut cca = cca(Some(4));
+    cca.granted_service_unit = Some(GrantedServiceUnit {
+        cc_time: Some(3600),
+        ..Default::default()
+    });
+    let message = cca.to_message(1, 2);
+    assert!(!message.is_request());
+
+    let decoded = CreditControlAnswer::from_message(&wire_round_trip(&message)).unwrap();
+    assert_eq!(decoded.result(), Some(ResultCode::SUCCESS));
+    assert_eq!(decoded.auth_application_id, Some(4));
+    assert_eq!(decoded.granted_service_unit.unwrap().cc_time, Some(3600));
+}
+
+#[test]
+fn header_application_id() {
+    assert_eq!(ccr(GX).to_message(1, 1).application_id(), ApplicationId::Gx);
+    assert_eq!(
+        ccr(99).to_message(1, 1).application_id(),
+        ApplicationId::CreditControl
+    );
+
+    assert_eq!(
+        cca(Some(GX)).to_message(1, 1).application_id(),
+        ApplicationId::Gx
+    );
+    assert_eq!(
+        cca(None).to_message(1, 1).application_id(),
+        ApplicationId::CreditControl
+    );
+    assert_eq!(
+        cca(Some(99)).to_message(1, 1).application_id(),
+        ApplicationId::CreditControl
+    );
+}
+
+#[test]
+fn unknown_cc_request_type_is_kept() {
+    let mut cca = cca(Some(4));
+    cca.cc_request_type = CcRequestType::Unknown(9);
+
+    let decoded =
+        CreditControlAnswer::from_message(&wire_round_trip(&cca.to_message(1, 1))).unwrap();
+    assert_eq!(decoded.cc_request_type, CcRequestType::Unknown(9));
+    let again = CreditControlAnswer::from_message(&decoded.to_message(1, 1)).unwrap();
+    assert_eq!(again.cc_request_type.value(), 9);
+}
@@ -0,0 +1,117 @@
+mod common;
+
+use common::encode_avp;
+use diameter_interface::errors::{DiameterResult, Error};
+use diameter_interface::modeling::avp::avp::{Avp, AvpFlags, AvpType, AvpValue};
+use diameter_interface::modeling::avp::custom::{AvpCodec, Custom};
+use diameter_interface::modeling::avp::octet_string::OctetString;
+use diameter_interface::modeling::codec::Writer;
+use diameter_interface::modeling::message::dictionary::{AvpDefinition, Dictionary};
+use diameter_interface::modeling::named::{NamedAvp, NamedValue};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+/// A 16-bit big-endian counter, rejecting any other length.
+#[derive(Debug, Clone, PartialEq)]
+struct Short(u16);
+
+impl AvpCodec for Short {
+    fn encode_to(&self, writer: &mut dyn Writer) -> DiameterResult<()> {
+        writer.write_all(&self.0.to_be_bytes())?;
+        Ok(())
+    }
+
+    fn encoded_len(&self) -> u32 {
+        2
+    }
+
+    fn decode(data: &[u8]) -> DiameterResult<Self> {
+        let bytes = data
+            .try_into()
+            .map_err(|_| Error::DecodeError("Invalid Short length"))?;
+        Ok(Short(u16::from_be_bytes(bytes)))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Other;
+
+impl AvpCodec for Other {
+    fn encode_to(&self, _: &mut dyn Writer) -> DiameterResult<()> {
+        Ok(())
+    }
+
+    fn encoded_len(&self) -> u32 {
+        0
+    }
+
+    fn decode(_: &[u8]) -> DiameterResult<Self> {
+        Ok(Other)
+    }
+}
+
+fn counter_dictionary(with_codec: bool) -> Dictionary {
+    let mut dict = Dictionary::empty();
+    if with_codec {
+        dict.register_codec::<Short>("Short");
+    }
+    dict.add_avp(AvpDefinition {
+        code: 3000,
+        vendor_id: None,
+        name: "Counter".to_string(),
+        avp_type: AvpType::Custom("Short".to_string()),
+        m_flag: false,
+        enum_values: BTreeMap::new(),
+    });
+    dict
+}
+
+fn round_trip(avp: &Avp, dict: Dictionary) -> DiameterResult<Avp> {
+    let encoded = encode_avp(avp);
+    assert_eq!(encoded.len(), 12); // 8 + 2, padded to 12
+    Avp::decode_from(&mut encoded.as_slice(), Arc::new(dict))
+}
+
+#[test]
+fn registered_codec_round_trip() {
+    let avp = Avp::new(3000, AvpFlags::O, None, Custom::from_codec(Short(513)));
+    assert_eq!(avp.get_length(), 10);
+    assert_eq!(avp.get_padding(), 2);
+
+    let decoded = round_trip(&avp, counter_dictionary(true)).unwrap();
+    assert_eq!(decoded.value().as_custom::<Short>(), Some(&Short(513)));
+    assert!(decoded.value().as_custom::<Other>().is_none());
+    assert_eq!(encode_avp(&decoded), encode_avp(&avp));
+}
+
+#[test]
+fn missing_codec_decodes_octets() {
+    let avp = Avp::new(3000, AvpFlags::O, None, Custom::from_codec(Short(513)));
+    let decoded = round_trip(&avp, counter_dictionary(false)).unwrap();
+    assert_eq!(decoded.value().as_bytes(), Some(&[2u8, 1][..]));
+    assert!(decoded.value().as_custom::<Short>().is_none());
+}
+
+#[test]
+fn codec_errors_fail_decoding() {
+    let avp = Avp::new(3000, AvpFlags::O, None, OctetString::new(vec![1, 2, 3]));
+    let encoded = encode_avp(&avp);
+    match Avp::decode_from(&mut encoded.as_slice(), Arc::new(counter_dictionary(true))) {
+        Err(Error::DecodeError("Invalid Short length")) => {}
+        other => panic!("unexpected {:?}", other),
+    }
+}
+
+#[test]
+fn named_values_use_the_codec() {
+    let named = NamedAvp::new("Counter", "0x0201");
+
+    let avp = named.to_avp(&counter_dictionary(true)).unwrap();
+    assert_eq!(avp.value().as_custom::<Short>(), Some(&Short(513)));
+    let avp = named.to_avp(&counter_dictionary(false)).unwrap();
+    assert!(matches!(avp.value(), AvpValue::OctetString(_)));
+    assert_eq!(avp.value().as_bytes(), Some(&[2u8, 1][..]));
+
+    let back = NamedAvp::from_avp(&avp, &counter_dictionary(false));
+    assert_eq!(back.value, NamedValue::Text("0x0201".to_string()));
+}
@@ -0,0 +1,204 @@
+mod common;
+
+use common::{dictionary, encode};
+use diameter_interface::errors::Error;
+use diameter_interface::modeling::decode_policy::{DecodePolicy, DecodeWarning, Deviation};
+use diameter_interface::modeling::diameter::DiameterMessage;
+use std::sync::Arc;
+
+const ORIGIN_HOST: u32 = 264;
+const CLASS: u32 = 25;
+const UNKNOWN: u32 = 9999;
+
+/// An AVP without vendor, padded unless `padded` is false.
+fn avp(code: u32, flags: u8, value: &[u8], padded: bool) -> Vec<u8> {
+    let length = 8 + value.len() as u32;
+    let mut avp = code.to_be_bytes().to_vec();
+    avp.push(flags);
+    avp.extend_from_slice(&length.to_be_bytes()[1..]);
+    avp.extend_from_slice(value);
+    if padded {
+        avp.resize(avp.len().div_ceil(4) * 4, 0);
+    }
+    avp
+}
+
+/// A Device-Watchdog-Request holding `avps`.
+fn frame(avps: &[Vec<u8>]) -> Vec<u8> {
+    let avps = avps.concat();
+    let length = 20 + avps.len() as u32;
+    let mut frame = vec![1];
+    frame.extend_from_slice(&length.to_be_bytes()[1..]);
+    frame.extend_from_slice(&[0x80, 0, 1, 24, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 2]);
+    frame.extend(avps);
+    frame
+}
+
+fn origin_host() -> Vec<u8> {
+    avp(ORIGIN_HOST, 0x40, b"client.example.com", true)
+}
+
+/// A message showing `deviation` once, in the AVP with the returned code.
+fn deviating(deviation: Deviation) -> (Vec<u8>, u32) {
+    match deviation {
+        Deviation::MandatoryBitClear => (frame(&[avp(ORIGIN_HOST, 0, b"host", true)]), ORIGIN_HOST),
+        Deviation::UnknownMandatoryAvp => (
+            frame(&[origin_host(), avp(UNKNOWN, 0x40, &[1, 2], true)]),
+            UNKNOWN,
+        ),
+        Deviation::EmptyString => (frame(&[avp(ORIGIN_HOST, 0x40, b"", true)]), ORIGIN_HOST),
+        Deviation::InvalidUtf8 => (
+            frame(&[avp(ORIGIN_HOST, 0x40, &[b'a', 0xff, 0xfe, b'b'], true)]),
+            ORIGIN_HOST,
+        ),
+        Deviation::MissingPadding => (
+            frame(&[origin_host(), avp(CLASS, 0x40, &[1, 2, 3], false)]),
+            CLASS,
+        ),
+    }
+}
+
+fn decode(frame: &[u8], policy: DecodePolicy) -> Result<DiameterMessage, Error> {
+    DiameterMessage::decode_with_policy(&mut &frame[..], dictionary(), policy)
+}
+
+fn assert_rejected(frame: &[u8], policy: DecodePolicy, deviation: Deviation) {
+    match decode(frame, policy) {
+        Err(Error::DecodeError(reason)) => assert_eq!(reason, deviation.description()),
+        other => panic!("unexpected {:?} for {:?}", other, deviation),
+    }
+}
+
+fn assert_tolerated(frame: &[u8], policy: DecodePolicy, deviation: Deviation, code: u32) {
+    let message = decode(frame, policy).unwrap();
+    let expected = DecodeWarning {
+        code,
+        vendor_id: None,
+        deviation,
+    };
+    assert_eq!(message.warnings(), &[expected]);
+}
+
+#[test]
+fn strict_rejects_every_deviation() {
+    for deviation in Deviation::ALL {
+        let (frame, _) = deviating(deviation);
+        assert_rejected(&frame, DecodePolicy::strict(), deviation);
+        assert_rejected(&frame, DecodePolicy::default(), deviation);
+    }
+}
+
+#[test]
+fn strict_decode_from_rejects_every_deviation() {
+    let dict = dictionary();
+    for deviation in Deviation::ALL {
+        let (frame, _) = deviating(deviation);
+        assert!(DiameterMessage::decode_from(&mut &frame[..], Arc::clone(&dict)).is_err());
+    }
+}
+
+#[test]
+fn lenient_tolerates_every_deviation() {
+    for deviation in Deviation::ALL {
+        let (frame, code) = deviating(deviation);
+        assert_tolerated(&frame, DecodePolicy::lenient(), deviation, code);
+    }
+}
+
+#[test]
+fn custom_policies_tolerate_the_deviations_chosen() {
+    for deviation in Deviation::ALL {
+        let (frame, code) = deviating(deviation);
+        let only = DecodePolicy::strict().with_tolerated(deviation);
+        assert!(only.tolerates(deviation));
+        assert_tolerated(&frame, only, deviation, code);
+
+        let all_but = DecodePolicy::lenient().without_tolerated(deviation);
+        assert!(!all_but.tolerates(deviation));
+        assert_rejected(&frame, all_but, deviation);
+    }
+}
+
+#[test]
+fn tolerated_values() {
+    let lenient = DecodePolicy::lenient();
+    let value = |deviation, code| {
+        let (frame, _) = deviating(deviation);
+        let message = decode(&frame, lenient).unwrap();
+        message.get_avp(code, None).unwrap().value().clone()
+    };
+    assert_eq!(
+        value(Deviation::EmptyString, ORIGIN_HOST).as_str(),
+        Some("")
+    );
+    assert_eq!(
+        value(Deviation::InvalidUtf8, ORIGIN_HOST).as_bytes(),
+        Some(&[b'a', 0xff, 0xfe, b'b'][..])
+    );
+    assert_eq!(
+        value(Deviation::UnknownMandatoryAvp, UNKNOWN).as_bytes(),
+        Some(&[1, 2][..])
+    );
+    assert_eq!(
+        value(Deviation::MissingPadding, CLASS).as_bytes(),
+        Some(&[1, 2, 3][..])
+    );
+}
+
+#[test]
+fn tolerated_messages_encode_unchanged() {
+    let lenient = DecodePolicy::lenient();
+    for deviation in Deviation::ALL {
+        let (frame, _) = deviating(deviation);
+        let message = decode(&frame, lenient).unwrap();
+        let encoded = encode(&message);
+        assert_eq!(encoded.len(), message.encoded_len(), "{:?}", deviation);
+        // Except for the padding, added when encoding
+        if deviation != Deviation::MissingPadding {
+            assert_eq!(encoded, frame, "{:?}", deviation);
+        }
+        assert_eq!(
+            decode(&encoded, lenient).unwrap(),
+            message,
+            "{:?}",
+            deviation
+        );
+    }
+
+    // A Device-Watchdog-Request with Origin-Host ff ff ff ff
+    let frame = frame(&[avp(ORIGIN_HOST, 0x40, &[0xff; 4], true)]);
+    let message = decode(&frame, lenient).unwrap();
+    let encoded = encode(&message);
+    assert_eq!(encoded, frame);
+    let decoded = decode(&encoded, lenient).unwrap();
+    assert_eq!(decoded.warnings()[0].deviation, Deviation::InvalidUtf8);
+    assert_eq!(decoded, message);
+}
+
+#[test]
+fn unknown_avps_without_the_m_bit_are_not_a_deviation() {
+    let frame = frame(&[origin_host(), avp(UNKNOWN, 0, &[1, 2], true)]);
+    let message = decode(&frame, DecodePolicy::strict()).unwrap();
+    assert!(message.warnings().is_empty());
+    let unknown = message.get_avp(UNKNOWN, None).unwrap();
+    assert_eq!(unknown.value().as_bytes(), Some(&[1, 2][..]));
+}
+
+#[test]
+fn warnings_display() {
+    let warning = DecodeWarning {
+        code: 1021,
+        vendor_id: Some(10415),
+        deviation: Deviation::MandatoryBitClear,
+    };
+    assert_eq!(
+        warning.to_string(),
+        "Mandatory AVP without the M bit (AVP 1021, vendor 10415)"
+    );
+    let (frame, _) = deviating(Deviation::EmptyString);
+    let message = decode(&frame, DecodePolicy::lenient()).unwrap();
+    assert_eq!(
+        message.warnings()[0].to_string(),
+        "Empty UTF8String (AVP 264)"
+    );
+}
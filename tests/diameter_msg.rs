@@ -0,0 +1,140 @@
+mod common;
+
+use common::dictionary;
+use diameter_interface::diameter_msg;
+use diameter_interface::errors::Error;
+use diameter_interface::modeling::diameter::DiameterMessage;
+use diameter_interface::modeling::message::application_id::ApplicationId;
+use diameter_interface::modeling::message::command_code::CommandCode;
+use diameter_interface::modeling::message::command_flags::CommandFlag;
+use diameter_interface::modeling::named::{NamedAvp, NamedMessage};
+
+fn codes(message: &DiameterMessage) -> Vec<u32> {
+    message.avps().iter().map(|avp| avp.code()).collect()
+}
+
+fn rejected(result: Result<DiameterMessage, Error>, reason: &str) {
+    match result {
+        Err(Error::EncodeError(message)) => assert_eq!(message, reason),
+        other => panic!("unexpected {:?}", other),
+    }
+}
+
+#[test]
+fn expands_to_a_named_message() {
+    let dict = dictionary();
+    let session_id = "client.example.com;1;1".to_string();
+    let ccr = diameter_msg!(&dict, CCR {
+        Session-Id: session_id.as_str(),
+        Auth-Application-Id: 4,
+        CC-Request-Type: "INITIAL_REQUEST",
+        CC-Request-Number: 0,
+        Subscription-Id {
+            Subscription-Id-Type: "END_USER_E164",
+            Subscription-Id-Data: "46701234567",
+        },
+    })
+    .unwrap();
+
+    let expected = NamedMessage::new(
+        "CCR",
+        vec![
+            NamedAvp::new("Session-Id", "client.example.com;1;1"),
+            NamedAvp::new("Auth-Application-Id", 4),
+            NamedAvp::new("CC-Request-Type", "INITIAL_REQUEST"),
+            NamedAvp::new("CC-Request-Number", 0),
+            NamedAvp::new(
+                "Subscription-Id",
+                vec![
+                    NamedAvp::new("Subscription-Id-Type", "END_USER_E164"),
+                    NamedAvp::new("Subscription-Id-Data", "46701234567"),
+                ],
+            ),
+        ],
+    )
+    .to_message(&dict)
+    .unwrap();
+    assert_eq!(ccr, expected);
+}
+
+#[test]
+fn header_of_the_message() {
+    let dict = dictionary();
+    let ccr = diameter_msg!(&dict, Credit-Control-Request {
+        Auth-Application-Id: 4,
+    })
+    .unwrap();
+    assert_eq!(ccr.command_code(), CommandCode::CreditControl);
+    assert!(ccr.is_request());
+    assert!(!ccr.has_flag(CommandFlag::Proxyable));
+    assert_eq!(ccr.application_id(), ApplicationId::CreditControl);
+    assert_eq!((ccr.hop_by_hop(), ccr.end_to_end()), (0, 0));
+
+    let cca = diameter_msg!(&dict, CCA { Result-Code: 2001 }).unwrap();
+    assert!(!cca.is_request());
+    assert_eq!(cca.application_id(), ApplicationId::Common);
+
+    let dwr = diameter_msg!(&dict, DWR {}).unwrap();
+    assert_eq!(dwr.command_code(), CommandCode::DeviceWatchdog);
+    assert!(dwr.avps().is_empty());
+}
+
+#[test]
+fn values_and_nesting() {
+    let dict = dictionary();
+    let cc_time: u32 = 60;
+    let cca = diameter_msg!(&dict, CCA {
+        Result-Code: 2001,
+        Multiple-Services-Credit-Control {
+            Granted-Service-Unit {
+                CC-Time: cc_time * 2,
+                CC-Total-Octets: 1u64 << 40,
+            },
+            Rating-Group: 10
+        }
+    })
+    .unwrap();
+    assert_eq!(codes(&cca), vec![268, 456]);
+
+    let mscc = cca
+        .get_avp(456, None)
+        .unwrap()
+        .value()
+        .as_grouped()
+        .unwrap();
+    assert_eq!(mscc[1].value().as_u32(), Some(10));
+    let gsu = mscc[0].value().as_grouped().unwrap();
+    assert_eq!(gsu[0].value().as_u32(), Some(120));
+    assert_eq!(gsu[1].value().as_u64(), Some(1 << 40));
+    assert!(gsu.iter().all(|avp| avp.vendor_id().is_none()));
+}
+
+#[test]
+fn avps_by_code() {
+    let dict = dictionary();
+    let message = diameter_msg!(&dict, DWR {
+        Origin-Host: "client.example.com",
+        1234: "0x01ff",
+    })
+    .unwrap();
+    assert_eq!(codes(&message), vec![264, 1234]);
+    let unknown = message.get_avp(1234, None).unwrap();
+    assert_eq!(unknown.value().as_bytes(), Some(&[0x01, 0xff][..]));
+}
+
+#[test]
+#[rustfmt::skip] // keeps the hyphenated command names of the macro together
+fn names_resolved_at_runtime() {
+    let dict = dictionary();
+    let unknown_command = diameter_msg!(&dict, No-Such-Request {});
+    rejected(unknown_command, "Unknown command name");
+    let not_a_command = diameter_msg!(&dict, Credit-Control {});
+    rejected(
+        not_a_command,
+        "Command name must end with -Request or -Answer, or be abbreviated",
+    );
+    let unknown_avp = diameter_msg!(&dict, DWR { No-Such-Avp: 1 });
+    rejected(unknown_avp, "Unknown AVP name");
+    let unknown_enum = diameter_msg!(&dict, CCR { CC-Request-Type: "NO_SUCH_TYPE" });
+    rejected(unknown_enum, "Unknown enumeration name");
+}
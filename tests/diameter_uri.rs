@@ -0,0 +1,92 @@
+mod common;
+
+use common::dictionary;
+use diameter_interface::modeling::avp::avp::{Avp, AvpFlags};
+use diameter_interface::modeling::avp::diameter_uri::{
+    DiameterURI, Protocol, Transport, Uri, UriScheme,
+};
+
+#[test]
+fn parse_format_round_trip() {
+    for value in [
+        "aaa://host.example.com",
+        "aaas://host.example.com:5658",
+        "aaa://host.example.com:3868;transport=sctp",
+        "aaa://host.example.com;transport=tcp;protocol=diameter",
+        "aaa://host.example.com:1813;transport=udp;protocol=radius",
+        "aaa://host.example.com;protocol=tacacs+",
+        "aaa://[2001:db8::1]:3868;transport=tcp",
+        "aaa://[2001:db8::1]",
+    ] {
+        let uri: Uri = value.parse().unwrap();
+        assert_eq!(uri.to_string(), value);
+        assert_eq!(uri.to_string().parse::<Uri>().unwrap(), uri);
+    }
+}
+
+#[test]
+fn parse_fields_and_defaults() {
+    let uri: Uri = "AAAS://host.example.com;transport=sctp".parse().unwrap();
+    assert_eq!(uri.scheme(), UriScheme::Aaas);
+    assert_eq!(uri.fqdn(), "host.example.com");
+    assert_eq!(uri.port(), 5658);
+    assert_eq!(uri.transport(), Transport::Sctp);
+    assert_eq!(uri.protocol(), Protocol::Diameter);
+    assert!(uri.is_secure());
+
+    let uri: Uri = "aaa://host.example.com".parse().unwrap();
+    assert_eq!(uri.port(), 3868);
+    assert_eq!(uri.transport(), Transport::Tcp);
+    assert!(!uri.is_secure());
+}
+
+#[test]
+fn builder_formats() {
+    let uri = Uri::new(UriScheme::Aaa, "host.example.com")
+        .with_port(3869)
+        .with_transport(Transport::Udp)
+        .with_protocol(Protocol::Radius);
+    assert_eq!(
+        uri.to_string(),
+        "aaa://host.example.com:3869;transport=udp;protocol=radius"
+    );
+}
+
+#[test]
+fn parse_rejects_invalid() {
+    for value in [
+        "host.example.com",
+        "http://host.example.com",
+        "aaa://",
+        "aaa://host..example.com",
+        "aaa://host.example.com:port",
+        "aaa://host.example.com:70000",
+        "aaa://host.example.com;transport=quic",
+        "aaa://host.example.com;protocol=http",
+        "aaa://host.example.com;transport=tcp;transport=udp",
+        "aaa://host.example.com;color=blue",
+        "aaa://[2001:db8::1",
+        "aaa://[not-an-address]",
+    ] {
+        assert!(value.parse::<Uri>().is_err(), "{}", value);
+    }
+}
+
+#[test]
+fn avp_round_trip() {
+    let uri = DiameterURI::parse("aaa://host.example.com:3868;transport=tcp").unwrap();
+    let avp = Avp::new(292, AvpFlags::M, None, uri); // Redirect-Host
+
+    let mut encoded = vec![];
+    avp.encode_to(&mut encoded).unwrap();
+    assert_eq!(encoded.len() % 4, 0);
+    assert_eq!(
+        &encoded[8..49],
+        b"aaa://host.example.com:3868;transport=tcp"
+    );
+
+    let decoded = Avp::decode_from(&mut encoded.as_slice(), dictionary()).unwrap();
+    let mut reencoded = vec![];
+    decoded.encode_to(&mut reencoded).unwrap();
+    assert_eq!(reencoded, encoded);
+}
@@ -0,0 +1,110 @@
+#![cfg(any(feature = "json", feature = "yaml"))]
+
+use diameter_interface::modeling::avp::avp::AvpType;
+use diameter_interface::modeling::message::dictionary::{self, AvpDefinition, Dictionary};
+use diameter_interface::modeling::message::dictionary_format::DictionaryDocument;
+use std::collections::BTreeMap;
+
+/// The default dictionary with a programmatic vendor AVP and a custom type.
+fn vendor_dictionary() -> Dictionary {
+    let mut dict = Dictionary::new(&[&dictionary::DEFAULT_DICT_XML]);
+    dict.add_avp(AvpDefinition {
+        code: 5,
+        vendor_id: Some(5535),
+        name: "3GPP2-BSID".to_string(),
+        avp_type: AvpType::Custom("TBCDString".to_string()),
+        m_flag: false,
+        enum_values: BTreeMap::new(),
+    });
+    dict
+}
+
+fn assert_same_content(loaded: &Dictionary, dict: &Dictionary) {
+    assert_eq!(
+        DictionaryDocument::from(loaded),
+        DictionaryDocument::from(dict)
+    );
+    assert_eq!(loaded.get_avp_name(5, Some(5535)), Some("3GPP2-BSID"));
+    assert_eq!(
+        loaded.get_avp_type(5, Some(5535)),
+        Some(&AvpType::Custom("TBCDString".to_string()))
+    );
+    assert_eq!(loaded.get_enum_name(416, None, 1), Some("INITIAL_REQUEST"));
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn json_round_trip() {
+    let dict = vendor_dictionary();
+    let json = dict.to_json().unwrap();
+
+    let mut loaded = Dictionary::empty();
+    loaded.load_json(&json).unwrap();
+    assert_same_content(&loaded, &dict);
+    assert_eq!(loaded.to_json().unwrap(), json);
+}
+
+#[cfg(feature = "yaml")]
+#[test]
+fn yaml_round_trip() {
+    let dict = vendor_dictionary();
+    let yaml = dict.to_yaml().unwrap();
+
+    let mut loaded = Dictionary::empty();
+    loaded.load_yaml(&yaml).unwrap();
+    assert_same_content(&loaded, &dict);
+    assert_eq!(loaded.to_yaml().unwrap(), yaml);
+}
+
+#[cfg(feature = "yaml")]
+#[test]
+fn yaml_document_fields() {
+    let yaml = r#"
+applications:
+  - name: Gx
+    id: 16777238
+commands:
+  - name: Credit-Control
+    code: 272
+avps:
+  - name: CC-Request-Type
+    code: 416
+    type: Enumerated
+    mandatory: true
+    values:
+      1: INITIAL_REQUEST
+  - name: Timezone-Offset
+    code: 571
+    vendor-id: 10415
+    type: Integer32
+"#;
+    let mut dict = Dictionary::empty();
+    dict.load_yaml(yaml).unwrap();
+
+    let cc_request_type = dict.get_avp(416, None).unwrap();
+    assert!(cc_request_type.m_flag);
+    assert_eq!(cc_request_type.avp_type, AvpType::Enumerated);
+    assert_eq!(dict.get_enum_name(416, None, 1), Some("INITIAL_REQUEST"));
+    assert_eq!(dict.get_avp_name(571, Some(10415)), Some("Timezone-Offset"));
+    assert!(!dict.get_avp(571, Some(10415)).unwrap().m_flag);
+}
+
+#[cfg(all(feature = "json", feature = "yaml"))]
+#[test]
+fn json_and_yaml_agree() {
+    let dict = vendor_dictionary();
+    let from_json = DictionaryDocument::from_json(&dict.to_json().unwrap()).unwrap();
+    let from_yaml = DictionaryDocument::from_yaml(&dict.to_yaml().unwrap()).unwrap();
+    assert_eq!(from_json, from_yaml);
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn malformed_documents_are_rejected() {
+    let mut dict = Dictionary::empty();
+    assert!(dict.load_json("{ \"avps\": [ { \"name\": 1 } ] }").is_err());
+    assert!(
+        dict.load_json(r#"{ "applications": [ { "name": "S6a", "id": 16777251 } ] }"#)
+            .is_err()
+    );
+}
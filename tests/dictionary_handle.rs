@@ -0,0 +1,191 @@
+use diameter_interface::errors::{DiameterResult, Error};
+use diameter_interface::modeling::avp::avp::{Avp, AvpFlags};
+use diameter_interface::modeling::avp::custom::{AvpCodec, Custom};
+use diameter_interface::modeling::codec::Writer;
+use diameter_interface::modeling::message::dictionary::Dictionary;
+use diameter_interface::modeling::message::dictionary_handle::{
+    DictionaryHandle, DictionarySource,
+};
+use std::sync::Arc;
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+const BASE_XML: &str = r#"<diameter><application id="0" name="Base">
+    <avp name="Counter" code="1000" must="-" may="P" must-not="V" may-encrypt="-">
+        <data type="Unsigned32"/>
+    </avp>
+</application></diameter>"#;
+
+const CODECS: u32 = 8;
+
+#[derive(Debug, Clone, PartialEq)]
+struct Counter(u32);
+
+impl AvpCodec for Counter {
+    fn encode_to(&self, writer: &mut dyn Writer) -> DiameterResult<()> {
+        writer.write_all(&self.0.to_be_bytes())?;
+        Ok(())
+    }
+
+    fn encoded_len(&self) -> u32 {
+        4
+    }
+
+    fn decode(data: &[u8]) -> DiameterResult<Self> {
+        let bytes = data
+            .try_into()
+            .map_err(|_| Error::DecodeError("Invalid Counter"))?;
+        Ok(Counter(u32::from_be_bytes(bytes)))
+    }
+}
+
+/// One AVP per codec, code `1000 + i` of type `Counter{i}`.
+fn custom_xml() -> String {
+    let avps: String = (0..CODECS)
+        .map(|i| {
+            format!(
+                r#"<avp name="Counter-{i}" code="{code}" must="-" may="P" must-not="V" may-encrypt="-">
+                    <data type="Counter{i}"/>
+                </avp>"#,
+                code = 1000 + i
+            )
+        })
+        .collect();
+    format!(r#"<diameter><application id="0" name="Base">{avps}</application></diameter>"#)
+}
+
+fn decodes_custom(handle: &DictionaryHandle, code: u32) -> bool {
+    let avp = Avp::new(code, AvpFlags::O, None, Custom::from_codec(Counter(code)));
+    let mut encoded = vec![];
+    avp.encode_to(&mut encoded).unwrap();
+    match Avp::decode_from(&mut encoded.as_slice(), handle.load()) {
+        Ok(decoded) => decoded.value().as_custom::<Counter>() == Some(&Counter(code)),
+        Err(_) => false,
+    }
+}
+
+fn handle() -> DictionaryHandle {
+    DictionaryHandle::new(vec![DictionarySource::Xml(BASE_XML.to_string())]).unwrap()
+}
+
+#[test]
+fn reload_swaps_and_keeps_snapshots() {
+    let handle = handle();
+    assert_eq!(handle.version(), 1);
+    let snapshot = handle.load();
+
+    assert_eq!(handle.reload().unwrap(), 2);
+    assert_eq!(handle.version(), 2);
+    assert!(!Arc::ptr_eq(&snapshot, &handle.load()));
+    assert!(snapshot.get_avp_by_name("Counter").is_some());
+}
+
+#[test]
+fn failed_validation_keeps_current() {
+    let handle = handle()
+        .with_validator(|dict| match dict.get_avp_by_name("Counter") {
+            Some(_) => Ok(()),
+            None => Err(Error::DictionaryError("Counter is missing")),
+        })
+        .unwrap();
+    let current = handle.load();
+
+    assert!(handle.replace(Dictionary::empty()).is_err());
+    assert_eq!(handle.version(), 1);
+    assert!(Arc::ptr_eq(&current, &handle.load()));
+}
+
+#[test]
+fn concurrent_reloads_keep_every_codec() {
+    let handle =
+        Arc::new(DictionaryHandle::new(vec![DictionarySource::Xml(custom_xml())]).unwrap());
+
+    let threads: Vec<_> = (0..CODECS)
+        .flat_map(|i| {
+            let registering = Arc::clone(&handle);
+            let reloading = Arc::clone(&handle);
+            [
+                thread::spawn(move || {
+                    registering
+                        .register_codec::<Counter>(&format!("Counter{}", i))
+                        .unwrap()
+                }),
+                thread::spawn(move || reloading.reload().unwrap()),
+            ]
+        })
+        .collect();
+    let mut versions: Vec<u64> = threads.into_iter().map(|t| t.join().unwrap()).collect();
+    versions.sort();
+
+    // Every reload and registration produced its own version, and none lost a codec.
+    assert_eq!(versions, (2..2 + 2 * CODECS as u64).collect::<Vec<_>>());
+    assert_eq!(handle.version(), 1 + 2 * CODECS as u64);
+    for i in 0..CODECS {
+        assert!(
+            decodes_custom(&handle, 1000 + i),
+            "codec Counter{} was lost",
+            i
+        );
+    }
+}
+
+#[test]
+fn file_source_reloads_changes() {
+    let path = std::env::temp_dir().join(format!("dictionary-handle-{}.xml", std::process::id()));
+    std::fs::write(&path, BASE_XML).unwrap();
+    let handle = DictionaryHandle::new(vec![DictionarySource::File(path.clone())]).unwrap();
+    assert!(handle.load().get_avp_by_name("Extra").is_none());
+
+    let extra = r#"<diameter><application id="0" name="Base">
+        <avp name="Extra" code="2000" must="-" may="P" must-not="V" may-encrypt="-">
+            <data type="Unsigned32"/>
+        </avp>
+    </application></diameter>"#;
+    std::fs::write(&path, extra).unwrap();
+    handle.reload().unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert!(handle.load().get_avp_by_name("Extra").is_some());
+    assert!(handle.reload().is_err());
+    assert!(handle.load().get_avp_by_name("Extra").is_some());
+}
+
+#[test]
+fn replace_keeps_registered_codecs() {
+    let handle = DictionaryHandle::new(vec![DictionarySource::Xml(custom_xml())]).unwrap();
+    handle.register_codec::<Counter>("Counter0").unwrap();
+
+    let mut dictionary = Dictionary::empty();
+    dictionary.try_load_xml(&custom_xml()).unwrap();
+    handle.replace(dictionary).unwrap();
+
+    assert!(decodes_custom(&handle, 1000));
+}
+
+#[test]
+fn watcher_retries_failed_reloads() {
+    let path = std::env::temp_dir().join(format!("dictionary-watch-{}.xml", std::process::id()));
+    std::fs::write(&path, BASE_XML).unwrap();
+    let handle =
+        Arc::new(DictionaryHandle::new(vec![DictionarySource::File(path.clone())]).unwrap());
+
+    let (sender, receiver) = mpsc::channel();
+    let watcher = handle.watch(Duration::from_millis(10), move |reloaded| {
+        let _ = sender.send(reloaded.is_ok());
+    });
+    let timeout = Duration::from_secs(5);
+
+    // A half-written file fails on every poll until it is complete, not just once
+    std::fs::write(&path, "<diameter><application").unwrap();
+    assert!(!receiver.recv_timeout(timeout).unwrap());
+    assert!(!receiver.recv_timeout(timeout).unwrap());
+
+    std::fs::write(&path, BASE_XML).unwrap();
+    while !receiver.recv_timeout(timeout).unwrap() {}
+    watcher.stop();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(handle.version(), 2);
+    assert!(handle.load().get_avp_by_name("Counter").is_some());
+}
@@ -0,0 +1,307 @@
+use diameter_interface::modeling::avp::avp::{Avp, AvpFlags};
+use diameter_interface::modeling::avp::enumerated::Enumerated;
+use diameter_interface::modeling::avp::group::Grouped;
+use diameter_interface::modeling::avp::integer32::Integer32;
+use diameter_interface::modeling::avp::unsigned32::Unsigned32;
+use diameter_interface::modeling::avp::utf8_string::UTF8String;
+use diameter_interface::modeling::diameter::DiameterMessage;
+use diameter_interface::modeling::diff::{Difference, HeaderField};
+use diameter_interface::modeling::message::application_id::ApplicationId;
+use diameter_interface::modeling::message::command_code::CommandCode;
+use diameter_interface::modeling::message::command_flags::CommandFlag;
+use diameter_interface::modeling::message::dictionary::{self, Dictionary};
+
+const EXTRA_XML: &str = r#"
+<diameter>
+	<vendor id="10415" name="3GPP"/>
+	<vendor id="5535" name="3GPP2"/>
+	<application id="16777238" name="Gx">
+		<avp name="Timezone-Offset" code="571" must="V" may="P" must-not="M" may-encrypt="Y" vendor-id="10415">
+			<data type="Integer32"/>
+		</avp>
+		<avp name="Timezone-Offset" code="571" must="V" may="P" must-not="M" may-encrypt="Y" vendor-id="5535">
+			<data type="Integer32"/>
+		</avp>
+	</application>
+</diameter>"#;
+
+fn gx_dictionary() -> Dictionary {
+    Dictionary::new(&[&dictionary::DEFAULT_DICT_XML, EXTRA_XML])
+}
+
+fn subscription_id(data: &str) -> Avp {
+    Avp::new(
+        443,
+        AvpFlags::M,
+        None,
+        Grouped::new(vec![
+            Avp::new(450, AvpFlags::M, None, Enumerated::new(1)),
+            Avp::new(444, AvpFlags::M, None, UTF8String::new(data.to_string())),
+        ]),
+    )
+}
+
+fn mscc(rating_group: u32, cc_time: u32) -> Avp {
+    Avp::new(
+        456,
+        AvpFlags::M,
+        None,
+        Grouped::new(vec![
+            Avp::new(
+                431,
+                AvpFlags::M,
+                None,
+                Grouped::new(vec![Avp::new(
+                    420,
+                    AvpFlags::M,
+                    None,
+                    Unsigned32::new(cc_time),
+                )]),
+            ),
+            Avp::new(432, AvpFlags::M, None, Unsigned32::new(rating_group)),
+        ]),
+    )
+}
+
+fn message(hop_by_hop: u32, avps: Vec<Avp>) -> DiameterMessage {
+    let mut message = DiameterMessage::new(
+        CommandFlag::Request,
+        CommandCode::CreditControl,
+        ApplicationId::CreditControl,
+        hop_by_hop,
+        1,
+    );
+    for avp in avps {
+        message.add(avp);
+    }
+    message
+}
+
+fn session_id(value: &str) -> Avp {
+    Avp::new(263, AvpFlags::M, None, UTF8String::new(value.to_string()))
+}
+
+fn request_type(value: i32) -> Avp {
+    Avp::new(416, AvpFlags::M, None, Enumerated::new(value))
+}
+
+#[test]
+fn equal_messages() {
+    let dict = gx_dictionary();
+    let old = message(1, vec![session_id("a;1"), mscc(1, 60)]);
+    let new = message(1, vec![session_id("a;1"), mscc(1, 60)]);
+    let diff = old.diff(&new, &dict);
+    assert!(diff.is_empty());
+    assert!(diff.differences().is_empty());
+    assert_eq!(diff.to_string(), "");
+}
+
+#[test]
+fn header_fields() {
+    let dict = gx_dictionary();
+    let old = message(1, vec![]);
+    let mut new = message(2, vec![]);
+    new.set_flag(CommandFlag::Proxyable);
+
+    let diff = old.diff(&new, &dict);
+    assert_eq!(
+        diff.differences(),
+        vec![
+            Difference::Header {
+                field: HeaderField::CommandFlags,
+                old: 0x80,
+                new: 0xc0
+            },
+            Difference::Header {
+                field: HeaderField::HopByHop,
+                old: 1,
+                new: 2
+            },
+        ]
+    );
+    assert_eq!(
+        diff.to_string(),
+        "changed Flags: 128 -> 192\nchanged Hop-by-Hop Identifier: 1 -> 2"
+    );
+
+    let diff = old
+        .diff(&new, &dict)
+        .ignore_field(HeaderField::HopByHop)
+        .ignore_field(HeaderField::CommandFlags);
+    assert!(diff.is_empty());
+}
+
+#[test]
+fn added_removed_and_changed() {
+    let dict = gx_dictionary();
+    let old = message(1, vec![session_id("a;1"), request_type(1)]);
+    let new = message(1, vec![session_id("a;2"), mscc(1, 60)]);
+    let diff = old.diff(&new, &dict);
+    assert_eq!(
+        diff.differences(),
+        vec![
+            Difference::Changed {
+                path: "Session-Id".to_string(),
+                old: session_id("a;1"),
+                new: session_id("a;2"),
+            },
+            Difference::Removed {
+                path: "CC-Request-Type".to_string(),
+                avp: request_type(1),
+            },
+            Difference::Added {
+                path: "Multiple-Services-Credit-Control".to_string(),
+                avp: mscc(1, 60),
+            },
+        ]
+    );
+    assert_eq!(
+        diff.to_string(),
+        "changed Session-Id: Session-Id=\"a;1\" -> Session-Id=\"a;2\"
+removed CC-Request-Type: CC-Request-Type=INITIAL_REQUEST
+added Multiple-Services-Credit-Control: \
+Multiple-Services-Credit-Control={Granted-Service-Unit={CC-Time=60} Rating-Group=1}"
+    );
+}
+
+#[test]
+fn grouped_avps_by_path() {
+    let dict = gx_dictionary();
+    let old = message(1, vec![mscc(1, 60)]);
+    let new = message(1, vec![mscc(1, 120)]);
+    assert_eq!(
+        old.diff(&new, &dict).to_string(),
+        "changed Multiple-Services-Credit-Control/Granted-Service-Unit/CC-Time: \
+CC-Time=60 -> CC-Time=120"
+    );
+
+    // A Grouped AVP with different flags is changed as a whole
+    let group = mscc(1, 60).value().as_grouped().unwrap().to_vec();
+    let flagged = Avp::new(456, AvpFlags::O, None, Grouped::new(group));
+    let new = message(1, vec![flagged.clone()]);
+    match &old.diff(&new, &dict).differences()[..] {
+        [Difference::Changed { path, new, .. }] => {
+            assert_eq!(path, "Multiple-Services-Credit-Control");
+            assert_eq!(new, &flagged);
+        }
+        other => panic!("unexpected {:?}", other),
+    }
+}
+
+#[test]
+fn repeated_avps_are_indexed() {
+    let dict = gx_dictionary();
+    let old = message(1, vec![subscription_id("1"), subscription_id("2")]);
+    let new = message(1, vec![subscription_id("1"), subscription_id("3")]);
+    assert_eq!(
+        old.diff(&new, &dict).to_string(),
+        "changed Subscription-Id[1]/Subscription-Id-Data: \
+Subscription-Id-Data=\"2\" -> Subscription-Id-Data=\"3\""
+    );
+
+    // Indexed as soon as either message repeats the AVP
+    let new = message(1, vec![subscription_id("1")]);
+    assert_eq!(
+        old.diff(&new, &dict).to_string(),
+        "removed Subscription-Id[1]: \
+Subscription-Id={Subscription-Id-Type=END_USER_IMSI Subscription-Id-Data=\"2\"}"
+    );
+}
+
+#[test]
+fn reordered_avps() {
+    let dict = gx_dictionary();
+    let old = message(1, vec![session_id("a;1"), request_type(1)]);
+    let new = message(1, vec![request_type(1), session_id("a;1")]);
+    let diff = old.diff(&new, &dict);
+    assert_eq!(
+        diff.differences(),
+        vec![Difference::Reordered {
+            path: String::new()
+        }]
+    );
+    assert_eq!(diff.to_string(), "reordered AVPs");
+    assert!(old.diff(&new, &dict).unordered().is_empty());
+
+    let reordered = Avp::new(
+        443,
+        AvpFlags::M,
+        None,
+        Grouped::new(vec![
+            Avp::new(444, AvpFlags::M, None, UTF8String::new("1".to_string())),
+            Avp::new(450, AvpFlags::M, None, Enumerated::new(1)),
+        ]),
+    );
+    let old = message(1, vec![subscription_id("1")]);
+    let new = message(1, vec![reordered]);
+    assert_eq!(
+        old.diff(&new, &dict).to_string(),
+        "reordered AVPs of Subscription-Id"
+    );
+}
+
+#[test]
+fn unordered_matches_equal_avps() {
+    let dict = gx_dictionary();
+    let old = message(1, vec![subscription_id("1"), subscription_id("2")]);
+    let new = message(1, vec![subscription_id("2"), subscription_id("1")]);
+    // In order, both occurrences differ
+    assert_eq!(old.diff(&new, &dict).differences().len(), 2);
+    assert!(old.diff(&new, &dict).unordered().is_empty());
+
+    let new = message(1, vec![subscription_id("3"), subscription_id("1")]);
+    assert_eq!(
+        old.diff(&new, &dict).unordered().to_string(),
+        "changed Subscription-Id[1]/Subscription-Id-Data: \
+Subscription-Id-Data=\"2\" -> Subscription-Id-Data=\"3\""
+    );
+}
+
+#[test]
+fn ignored_avps_at_every_depth() {
+    let dict = gx_dictionary();
+    let old = message(1, vec![session_id("a;1"), mscc(1, 60)]);
+    let new = message(1, vec![session_id("a;2"), mscc(1, 120)]);
+    let diff = old
+        .diff(&new, &dict)
+        .ignore_avp_name("Session-Id")
+        .ignore_avp(420, None);
+    assert!(diff.is_empty());
+
+    // Unknown names ignore nothing
+    let diff = old.diff(&new, &dict).ignore_avp_name("No-Such-Avp");
+    assert_eq!(diff.differences().len(), 2);
+}
+
+#[test]
+fn ignored_names_of_every_vendor() {
+    let dict = gx_dictionary();
+    let offset =
+        |vendor_id, value| Avp::new(571, AvpFlags::O, Some(vendor_id), Integer32::new(value));
+    let old = message(1, vec![offset(10415, 1), offset(5535, 1)]);
+    let new = message(1, vec![offset(10415, 2), offset(5535, 2)]);
+    assert_eq!(old.diff(&new, &dict).differences().len(), 2);
+    assert!(
+        old.diff(&new, &dict)
+            .ignore_avp_name("Timezone-Offset")
+            .is_empty()
+    );
+    assert_eq!(
+        old.diff(&new, &dict)
+            .ignore_avp(571, Some(10415))
+            .differences()
+            .len(),
+        1
+    );
+}
+
+#[test]
+fn unknown_avps_by_code() {
+    let dict = gx_dictionary();
+    let old = message(
+        1,
+        vec![Avp::new(9999, AvpFlags::O, None, Unsigned32::new(1))],
+    );
+    let new = message(1, vec![]);
+    assert_eq!(old.diff(&new, &dict).to_string(), "removed 9999: 9999=1");
+}
@@ -0,0 +1,136 @@
+mod common;
+
+use chrono::{TimeZone, Utc};
+use common::dictionary;
+use diameter_interface::modeling::avp::address::Address;
+use diameter_interface::modeling::avp::avp::{Avp, AvpFlags};
+use diameter_interface::modeling::avp::enumerated::Enumerated;
+use diameter_interface::modeling::avp::group::Grouped;
+use diameter_interface::modeling::avp::integer32::Integer32;
+use diameter_interface::modeling::avp::octet_string::OctetString;
+use diameter_interface::modeling::avp::time::Time;
+use diameter_interface::modeling::avp::unsigned32::Unsigned32;
+use diameter_interface::modeling::avp::utf8_string::UTF8String;
+use diameter_interface::modeling::diameter::DiameterMessage;
+use diameter_interface::modeling::message::application_id::ApplicationId;
+use diameter_interface::modeling::message::command_code::CommandCode;
+use diameter_interface::modeling::message::command_flags::CommandFlag;
+use diameter_interface::modeling::message::dictionary::{self, Dictionary};
+use std::net::{IpAddr, Ipv4Addr};
+
+fn ccr() -> DiameterMessage {
+    let mut ccr = DiameterMessage::new(
+        CommandFlag::Request,
+        CommandCode::CreditControl,
+        ApplicationId::CreditControl,
+        1,
+        2,
+    );
+    ccr.set_flag(CommandFlag::Proxyable);
+    ccr.add_avp(
+        263,
+        AvpFlags::M,
+        None,
+        UTF8String::new("host;1;1".to_string()),
+    );
+    ccr.add_avp(416, AvpFlags::M, None, Enumerated::new(1));
+    ccr.add_avp(
+        443,
+        AvpFlags::M,
+        None,
+        Grouped::new(vec![
+            Avp::new(450, AvpFlags::M, None, Enumerated::new(1)),
+            Avp::new(
+                444,
+                AvpFlags::M,
+                None,
+                UTF8String::new("001010123456789".to_string()),
+            ),
+        ]),
+    );
+    ccr
+}
+
+#[test]
+fn message_tree() {
+    let expected = "\
+Version: 1
+Flags: 0xc0 (Request, Proxyable)
+Command Code: 272 Credit-Control Request
+Application Id: Charging Control (4)
+Hop-by-Hop Identifier: 0x00000001
+End-to-End Identifier: 0x00000002
+AVP: Session-Id(263) l=16 f=-M- val=host;1;1
+AVP: CC-Request-Type(416) l=12 f=-M- val=INITIAL_REQUEST (1)
+AVP: Subscription-Id(443) l=44 f=-M-
+    AVP: Subscription-Id-Type(450) l=12 f=-M- val=END_USER_IMSI (1)
+    AVP: Subscription-Id-Data(444) l=23 f=-M- val=001010123456789";
+    assert_eq!(ccr().display(&dictionary()).to_string(), expected);
+}
+
+#[test]
+fn message_compact() {
+    let expected = "Credit-Control-Request hbh=0x00000001 e2e=0x00000002 \
+Session-Id=\"host;1;1\" CC-Request-Type=INITIAL_REQUEST \
+Subscription-Id={Subscription-Id-Type=END_USER_IMSI Subscription-Id-Data=\"001010123456789\"}";
+    assert_eq!(ccr().display(&dictionary()).compact().to_string(), expected);
+
+    let mut answer = DiameterMessage::answer_for(&ccr());
+    answer.set_flag(CommandFlag::Error);
+    assert_eq!(
+        answer.display(&dictionary()).compact().to_string(),
+        "Credit-Control-Answer hbh=0x00000001 e2e=0x00000002 error Session-Id=\"host;1;1\""
+    );
+}
+
+#[test]
+fn value_types() {
+    let dict = dictionary();
+    let display = |avp: Avp| avp.display(&dict).to_string();
+
+    let time = Utc.with_ymd_and_hms(2024, 5, 1, 12, 0, 0).unwrap();
+    assert_eq!(
+        display(Avp::new(55, AvpFlags::M, None, Time::new(time))),
+        "AVP: Event-Timestamp(55) l=12 f=-M- val=2024-05-01T12:00:00+00:00"
+    );
+    assert_eq!(
+        display(Avp::new(
+            257,
+            AvpFlags::M,
+            None,
+            Address::new(IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1)))
+        )),
+        "AVP: Host-IP-Address(257) l=14 f=-M- val=192.0.2.1"
+    );
+    assert_eq!(
+        display(Avp::new(
+            25,
+            AvpFlags::M,
+            None,
+            OctetString::new(vec![0xca, 0xfe])
+        )),
+        "AVP: Class(25) l=10 f=-M- val=0xcafe"
+    );
+    assert_eq!(
+        display(Avp::new(416, AvpFlags::M, None, Enumerated::new(9))),
+        "AVP: CC-Request-Type(416) l=12 f=-M- val=9"
+    );
+}
+
+#[test]
+fn unknown_and_vendor_avps() {
+    let mut dict = Dictionary::new(&[&dictionary::DEFAULT_DICT_XML]);
+    dict.add_vendor(5535, "3GPP2");
+    let avp = Avp::new(9999, AvpFlags::O, Some(5535), Integer32::new(-1));
+    assert_eq!(
+        avp.display(&dict).to_string(),
+        "AVP: Unknown(9999) l=16 f=V-- vnd=3GPP2 val=-1"
+    );
+    assert_eq!(avp.display(&dict).compact().to_string(), "9999=-1");
+
+    let avp = Avp::new(9999, AvpFlags::M, Some(1234), Unsigned32::new(7));
+    assert_eq!(
+        avp.display(&dict).to_string(),
+        "AVP: Unknown(9999) l=16 f=VM- vnd=1234 val=7"
+    );
+}
@@ -0,0 +1,154 @@
+mod common;
+
+use common::{dictionary, encode};
+use diameter_interface::errors::Error;
+use diameter_interface::modeling::avp::avp::{Avp, AvpFlags};
+use diameter_interface::modeling::avp::enumerated::Enumerated;
+use diameter_interface::modeling::avp::group::Grouped;
+use diameter_interface::modeling::avp::octet_string::OctetString;
+use diameter_interface::modeling::avp::unsigned32::Unsigned32;
+use diameter_interface::modeling::avp::utf8_string::{Identity, UTF8String};
+use diameter_interface::modeling::diameter::DiameterMessage;
+use diameter_interface::modeling::message::application_id::ApplicationId;
+use diameter_interface::modeling::message::command_code::CommandCode;
+use diameter_interface::modeling::message::command_flags::CommandFlag;
+use std::io::{self, Write};
+
+fn ccr() -> DiameterMessage {
+    let mut ccr = DiameterMessage::new(
+        CommandFlag::Request,
+        CommandCode::CreditControl,
+        ApplicationId::CreditControl,
+        1,
+        2,
+    );
+    ccr.add_avp(
+        263,
+        AvpFlags::M,
+        None,
+        UTF8String::new("client.example.com;1;1".to_string()),
+    );
+    ccr.add_avp(
+        264,
+        AvpFlags::M,
+        None,
+        Identity::new("client.example.com".to_string()),
+    );
+    ccr.add_avp(258, AvpFlags::M, None, Unsigned32::new(4));
+    ccr.add_avp(416, AvpFlags::M, None, Enumerated::new(1));
+    ccr.add_avp(
+        443,
+        AvpFlags::M,
+        None,
+        Grouped::new(vec![
+            Avp::new(450, AvpFlags::M, None, Enumerated::new(1)),
+            Avp::new(
+                444,
+                AvpFlags::M,
+                None,
+                UTF8String::new("001010123456789".to_string()),
+            ),
+        ]),
+    );
+    ccr.add_avp(25, AvpFlags::M, None, OctetString::new(vec![1, 2, 3]));
+    ccr
+}
+
+/// A writer accepting at most `chunk` octets per call.
+struct ShortWrites {
+    data: Vec<u8>,
+    chunk: usize,
+}
+
+impl Write for ShortWrites {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = self.chunk.min(buf.len());
+        self.data.extend_from_slice(&buf[..written]);
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn encoded_len_matches_the_encoding() {
+    let mut message = ccr();
+    assert_eq!(message.encoded_len(), encode(&message).len());
+
+    message.set_avp(Avp::new(
+        263,
+        AvpFlags::M,
+        None,
+        UTF8String::new("a;2;2".to_string()),
+    ));
+    message.remove_avps(25, None);
+    assert_eq!(message.encoded_len(), encode(&message).len());
+
+    let encoded = encode(&message);
+    let decoded = DiameterMessage::decode_from(&mut encoded.as_slice(), dictionary()).unwrap();
+    assert_eq!(decoded.encoded_len(), encoded.len());
+}
+
+#[test]
+fn encode_into_a_slice() {
+    let message = ccr();
+    let expected = encode(&message);
+
+    let mut exact = vec![0; message.encoded_len()];
+    assert_eq!(message.encode_into(&mut exact).unwrap(), expected.len());
+    assert_eq!(exact, expected);
+
+    // Octets after the message are left unchanged
+    let mut larger = vec![0xaa; message.encoded_len() + 8];
+    assert_eq!(message.encode_into(&mut larger).unwrap(), expected.len());
+    assert_eq!(&larger[..expected.len()], &expected[..]);
+    assert!(larger[expected.len()..].iter().all(|octet| *octet == 0xaa));
+}
+
+#[test]
+fn buffer_too_small() {
+    let message = ccr();
+    let mut buffer = vec![0; message.encoded_len() - 1];
+    match message.encode_into(&mut buffer) {
+        Err(Error::EncodeError(reason)) => {
+            assert_eq!(reason, "Buffer is too small for the message")
+        }
+        other => panic!("unexpected {:?}", other),
+    }
+    assert!(message.encode_into(&mut []).is_err());
+}
+
+#[test]
+fn short_writes_are_completed() {
+    let message = ccr();
+    let mut writer = ShortWrites {
+        data: vec![],
+        chunk: 3,
+    };
+    message.encode_to(&mut writer).unwrap();
+    assert_eq!(writer.data, encode(&message));
+}
+
+#[test]
+fn write_errors_are_returned() {
+    let mut full = [0u8; 24];
+    let result = ccr().encode_to(&mut &mut full[..]);
+    assert!(matches!(result, Err(Error::IoError(_))));
+}
+
+#[cfg(feature = "bytes")]
+#[test]
+fn encode_into_bytes_appends() {
+    let message = ccr();
+    let mut buffer = bytes::BytesMut::new();
+    buffer.extend_from_slice(&[9, 9]);
+    message.encode_into_bytes(&mut buffer).unwrap();
+    message.encode_into_bytes(&mut buffer).unwrap();
+
+    let encoded = encode(&message);
+    assert_eq!(buffer.len(), 2 + 2 * encoded.len());
+    assert_eq!(&buffer[2..2 + encoded.len()], &encoded[..]);
+    assert_eq!(&buffer[2 + encoded.len()..], &encoded[..]);
+}
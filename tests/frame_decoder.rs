@@ -0,0 +1,234 @@
+mod common;
+
+use common::{encode, encode_avp};
+use diameter_interface::errors::Error;
+use diameter_interface::modeling::avp::avp::AvpFlags;
+use diameter_interface::modeling::avp::octet_string::OctetString;
+use diameter_interface::modeling::avp::utf8_string::UTF8String;
+use diameter_interface::modeling::decode_policy::{DecodePolicy, Deviation};
+use diameter_interface::modeling::diameter::DiameterMessage;
+use diameter_interface::modeling::message::application_id::ApplicationId;
+use diameter_interface::modeling::message::command_code::CommandCode;
+use diameter_interface::modeling::message::command_flags::CommandFlag;
+use diameter_interface::modeling::message::dictionary::{self, Dictionary};
+use diameter_interface::modeling::raw::RawMessage;
+use diameter_interface::modeling::view::MessageRef;
+use diameter_interface::transport::frame_decoder::FrameDecoder;
+use std::io::{self, ErrorKind, Read};
+use std::sync::Arc;
+
+fn decoder() -> FrameDecoder {
+    FrameDecoder::new(Arc::new(Dictionary::new(&[&dictionary::DEFAULT_DICT_XML])))
+}
+
+fn message(hop_by_hop: u32) -> DiameterMessage {
+    let mut message = DiameterMessage::new(
+        CommandFlag::Request,
+        CommandCode::DeviceWatchdog,
+        ApplicationId::Common,
+        hop_by_hop,
+        1,
+    );
+    message.add_avp(
+        264,
+        AvpFlags::M,
+        None,
+        UTF8String::new("client.example.com".to_string()),
+    );
+    message
+}
+
+fn rejected<T: std::fmt::Debug>(result: Result<T, Error>, reason: &str) {
+    match result {
+        Err(Error::DecodeError(message)) => assert_eq!(message, reason),
+        other => panic!("unexpected {:?}", other),
+    }
+}
+
+/// A reader handing out `chunk` octets per call, then `WouldBlock` once the data is read.
+struct Fragments {
+    data: Vec<u8>,
+    chunk: usize,
+}
+
+impl Read for Fragments {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.data.is_empty() {
+            return Err(ErrorKind::WouldBlock.into());
+        }
+        let read = self.chunk.min(buf.len()).min(self.data.len());
+        buf[..read].copy_from_slice(&self.data[..read]);
+        self.data.drain(..read);
+        Ok(read)
+    }
+}
+
+#[test]
+fn one_octet_at_a_time() {
+    let encoded = encode(&message(1));
+    let mut decoder = decoder();
+    for (i, octet) in encoded.iter().enumerate() {
+        assert!(decoder.next_message().unwrap().is_none());
+        decoder.feed(&[*octet]).unwrap();
+        assert_eq!(decoder.buffered(), i + 1);
+    }
+    let decoded = decoder.next_message().unwrap().unwrap();
+    assert_eq!(encode(&decoded), encoded);
+    assert_eq!(decoder.buffered(), 0);
+    assert!(decoder.next_message().unwrap().is_none());
+}
+
+#[test]
+fn several_messages_in_one_read() {
+    let mut stream = encode(&message(1));
+    stream.extend(encode(&message(2)));
+    let second_start = stream.len() / 2;
+    // The start of a third message is kept for the next read
+    let third = encode(&message(3));
+    stream.extend_from_slice(&third[..7]);
+
+    let mut decoder = decoder();
+    decoder.feed(&stream).unwrap();
+    assert_eq!(decoder.next_message().unwrap().unwrap().hop_by_hop(), 1);
+    assert_eq!(decoder.buffered(), stream.len() - second_start);
+    assert_eq!(decoder.next_message().unwrap().unwrap().hop_by_hop(), 2);
+    assert!(decoder.next_message().unwrap().is_none());
+    assert_eq!(decoder.buffered(), 7);
+
+    decoder.feed(&third[7..]).unwrap();
+    assert_eq!(decoder.next_message().unwrap().unwrap().hop_by_hop(), 3);
+    assert_eq!(decoder.buffered(), 0);
+}
+
+#[test]
+fn partial_reads() {
+    let mut data = encode(&message(1));
+    data.extend(encode(&message(2)));
+    let mut socket = Fragments { data, chunk: 5 };
+
+    let mut decoder = decoder();
+    let mut hop_by_hops = vec![];
+    loop {
+        match decoder.read_from(&mut socket) {
+            Ok(read) => assert!(read > 0 && read <= 5),
+            Err(e) if e.kind() == ErrorKind::WouldBlock => break,
+            Err(e) => panic!("unexpected {:?}", e),
+        }
+        while let Some(message) = decoder.next_message().unwrap() {
+            hop_by_hops.push(message.hop_by_hop());
+        }
+    }
+    assert_eq!(hop_by_hops, vec![1, 2]);
+    assert_eq!(decoder.buffered(), 0);
+
+    // The end of the stream
+    assert_eq!(decoder.read_from(&mut io::empty()).unwrap(), 0);
+}
+
+#[test]
+fn frames_without_decoding() {
+    let encoded = encode(&message(4));
+    let mut decoder = decoder();
+    decoder.feed(&encoded[..30]).unwrap();
+    assert!(decoder.next_frame().unwrap().is_none());
+    decoder.feed(&encoded[30..]).unwrap();
+    let frame = decoder.next_frame().unwrap().unwrap();
+    assert_eq!(frame.as_bytes(), &encoded[..]);
+    assert_eq!(frame.hop_by_hop(), 4);
+}
+
+#[test]
+fn invalid_headers() {
+    let encoded = encode(&message(1));
+
+    // The version is checked from the first octet
+    rejected(decoder().feed(&[2]), "Unsupported Diameter version");
+
+    let mut short = encoded.clone();
+    short[3] = 16;
+    rejected(decoder().feed(&short[..4]), "Invalid message length");
+
+    let mut unaligned = encoded.clone();
+    unaligned[3] += 1;
+    rejected(decoder().feed(&unaligned[..4]), "Invalid message length");
+
+    let mut decoder = decoder().with_max_length(encoded.len() - 4);
+    rejected(
+        decoder.feed(&encoded[..4]),
+        "Message length exceeds the maximum",
+    );
+    rejected(decoder.next_message(), "Message length exceeds the maximum");
+}
+
+#[test]
+fn undecodable_frames_are_dropped() {
+    let mut invalid = encode(&message(1));
+    // Origin-Host claiming fewer octets than its header
+    invalid[20 + 7] = 4;
+    let mut stream = invalid.clone();
+    stream.extend(encode(&message(2)));
+
+    let mut decoder = decoder();
+    decoder.feed(&stream).unwrap();
+    rejected(decoder.next_message(), "Invalid AVP length");
+    assert_eq!(decoder.buffered(), stream.len() - invalid.len());
+    assert_eq!(decoder.next_message().unwrap().unwrap().hop_by_hop(), 2);
+}
+
+#[test]
+fn missing_padding_follows_the_policy() {
+    let mut message = message(1);
+    message.add_avp(25, AvpFlags::M, None, OctetString::new(vec![1, 2, 3]));
+    let mut encoded = encode(&message);
+    // Drop the padding of the last AVP
+    encoded.pop();
+    encoded[3] -= 1;
+
+    rejected(decoder().feed(&encoded), "Invalid message length");
+
+    let policy = DecodePolicy::strict().with_tolerated(Deviation::MissingPadding);
+    let mut decoder = decoder().with_decode_policy(policy);
+    decoder.feed(&encoded).unwrap();
+    let decoded = decoder.next_message().unwrap().unwrap();
+    assert_eq!(
+        decoded.avps().iter().map(encode_avp).collect::<Vec<_>>(),
+        message.avps().iter().map(encode_avp).collect::<Vec<_>>()
+    );
+    assert_eq!(decoded.warnings().len(), 1);
+    assert_eq!(decoded.warnings()[0].deviation, Deviation::MissingPadding);
+}
+
+#[test]
+fn frames_restore_tolerated_padding() {
+    let mut message = message(1);
+    message.add_avp(25, AvpFlags::M, None, OctetString::new(vec![1, 2, 3]));
+    let padded = encode(&message);
+    let mut encoded = padded.clone();
+    encoded.pop();
+    encoded[3] -= 1;
+
+    let policy = DecodePolicy::strict().with_tolerated(Deviation::MissingPadding);
+    rejected(MessageRef::new(&encoded), "Invalid message length");
+    rejected(RawMessage::new(encoded.clone()), "Invalid message length");
+    assert_eq!(
+        MessageRef::new_with_policy(&encoded, policy)
+            .unwrap()
+            .message_length() as usize,
+        encoded.len()
+    );
+
+    let mut decoder = decoder().with_decode_policy(policy);
+    decoder.feed(&encoded).unwrap();
+    let mut frame = decoder.next_frame().unwrap().unwrap();
+    assert_eq!(frame.as_bytes(), &padded[..]);
+
+    // AVPs appended to the frame follow the restored padding
+    frame.add(message.avps()[0].clone()).unwrap();
+    let relayed = DiameterMessage::decode_from(
+        &mut frame.as_bytes(),
+        Arc::new(Dictionary::new(&[&dictionary::DEFAULT_DICT_XML])),
+    )
+    .unwrap();
+    assert_eq!(relayed.avps().len(), 3);
+    assert_eq!(relayed.encoded_len(), frame.as_bytes().len());
+}
@@ -0,0 +1,113 @@
+#![cfg(feature = "json")]
+
+use diameter_interface::errors::DiameterResult;
+use diameter_interface::modeling::avp::avp::AvpType;
+use diameter_interface::modeling::avp::custom::AvpCodec;
+use diameter_interface::modeling::codec::Writer;
+use diameter_interface::modeling::message::application_id::ApplicationId;
+use diameter_interface::modeling::message::command_code::CommandCode;
+use diameter_interface::modeling::message::dictionary::Dictionary;
+use diameter_interface::modeling::message::freediameter::FreeDiameterImport;
+use std::path::Path;
+
+const FIXTURE: &str = "tests/fixtures/freediameter/dictionary.json";
+
+fn import(dictionary: &mut Dictionary) -> FreeDiameterImport {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(FIXTURE);
+    let json = std::fs::read_to_string(path).unwrap();
+    dictionary.load_freediameter_json(&json).unwrap()
+}
+
+#[derive(Debug, Clone)]
+struct Tbcd(Vec<u8>);
+
+impl AvpCodec for Tbcd {
+    fn encode_to(&self, writer: &mut dyn Writer) -> DiameterResult<()> {
+        writer.write_all(&self.0)?;
+        Ok(())
+    }
+
+    fn encoded_len(&self) -> u32 {
+        self.0.len() as u32
+    }
+
+    fn decode(data: &[u8]) -> DiameterResult<Self> {
+        Ok(Tbcd(data.to_vec()))
+    }
+}
+
+#[test]
+fn imports_vendors_and_avps() {
+    let mut dict = Dictionary::empty();
+    import(&mut dict);
+
+    assert_eq!(dict.get_vendor_name(10415), Some("3GPP"));
+
+    let session_id = dict.get_avp(263, None).unwrap();
+    assert_eq!(session_id.name, "Session-Id");
+    assert_eq!(session_id.avp_type, AvpType::UTF8String);
+    assert!(session_id.m_flag);
+
+    let install = dict.get_avp(1001, Some(10415)).unwrap();
+    assert_eq!(install.avp_type, AvpType::Grouped);
+    assert!(install.m_flag);
+
+    let qci = dict.get_avp(1028, Some(10415)).unwrap();
+    assert_eq!(qci.avp_type, AvpType::Enumerated);
+    assert_eq!(qci.enum_values.get(&9).map(String::as_str), Some("QCI_9"));
+
+    // Types resolve through their Base chain
+    let plmn = dict.get_avp(1407, Some(10415)).unwrap();
+    assert_eq!(plmn.avp_type, AvpType::OctetString);
+    assert!(!plmn.m_flag);
+
+    // OctetString enumerations are not kept
+    assert!(dict.get_avp(999, None).unwrap().enum_values.is_empty());
+}
+
+#[test]
+fn types_resolve_to_registered_codecs() {
+    let mut dict = Dictionary::empty();
+    dict.register_codec::<Tbcd>("TBCDString");
+    import(&mut dict);
+
+    let plmn = dict.get_avp(1407, Some(10415)).unwrap();
+    assert_eq!(plmn.avp_type, AvpType::Custom("TBCDString".to_string()));
+}
+
+#[test]
+fn commands_drop_the_request_and_answer_suffix() {
+    let mut dict = Dictionary::empty();
+    let import = import(&mut dict);
+
+    let commands: Vec<_> = dict.commands().collect();
+    assert_eq!(
+        commands,
+        vec![("Credit-Control", &CommandCode::CreditControl)]
+    );
+    let applications: Vec<_> = dict.applications().collect();
+    assert_eq!(applications, vec![("Gx", &ApplicationId::Gx)]);
+
+    assert_eq!(
+        import.skipped_applications,
+        vec![(16777251, "S6a".to_string())]
+    );
+    assert_eq!(
+        import.skipped_commands,
+        vec![(316, "Update-Location".to_string())]
+    );
+    assert!(!import.is_complete());
+}
+
+#[test]
+fn malformed_json_is_an_error() {
+    let mut dict = Dictionary::empty();
+    assert!(
+        dict.load_freediameter_json("{ \"AVPs\": [ { \"Code\": 1 } ] }")
+            .is_err()
+    );
+    assert!(dict.load_freediameter_json("not json").is_err());
+
+    let empty = dict.load_freediameter_json("{}").unwrap();
+    assert!(empty.is_complete());
+}
@@ -0,0 +1,1866 @@
+// @generated by diameter_interface::codegen. Do not edit.
+
+#[allow(dead_code)]
+pub mod vendor {
+    pub const _3GPP: u32 = 10415;
+}
+
+#[allow(dead_code)]
+pub mod code {
+    pub const USER_NAME: u32 = 1;
+    pub const FILTER_ID: u32 = 11;
+    pub const CLASS: u32 = 25;
+    pub const SESSION_TIMEOUT: u32 = 27;
+    pub const CALLED_STATION_ID: u32 = 30;
+    pub const PROXY_STATE: u32 = 33;
+    pub const ACCOUNTING_SESSION_ID: u32 = 44;
+    pub const ACCT_MULTI_SESSION_ID: u32 = 50;
+    pub const EVENT_TIMESTAMP: u32 = 55;
+    pub const ACCT_INTERIM_INTERVAL: u32 = 85;
+    pub const HOST_IP_ADDRESS: u32 = 257;
+    pub const AUTH_APPLICATION_ID: u32 = 258;
+    pub const ACCT_APPLICATION_ID: u32 = 259;
+    pub const VENDOR_SPECIFIC_APPLICATION_ID: u32 = 260;
+    pub const REDIRECT_HOST_USAGE: u32 = 261;
+    pub const REDIRECT_MAX_CACHE_TIME: u32 = 262;
+    pub const SESSION_ID: u32 = 263;
+    pub const ORIGIN_HOST: u32 = 264;
+    pub const SUPPORTED_VENDOR_ID: u32 = 265;
+    pub const VENDOR_ID: u32 = 266;
+    pub const FIRMWARE_REVISION: u32 = 267;
+    pub const RESULT_CODE: u32 = 268;
+    pub const PRODUCT_NAME: u32 = 269;
+    pub const SESSION_BINDING: u32 = 270;
+    pub const SESSION_SERVER_FAILOVER: u32 = 271;
+    pub const MULTI_ROUND_TIME_OUT: u32 = 272;
+    pub const DISCONNECT_CAUSE: u32 = 273;
+    pub const AUTH_REQUEST_TYPE: u32 = 274;
+    pub const AUTH_GRACE_PERIOD: u32 = 276;
+    pub const AUTH_SESSION_STATE: u32 = 277;
+    pub const ORIGIN_STATE_ID: u32 = 278;
+    pub const FAILED_AVP: u32 = 279;
+    pub const PROXY_HOST: u32 = 280;
+    pub const ERROR_MESSAGE: u32 = 281;
+    pub const ROUTE_RECORD: u32 = 282;
+    pub const DESTINATION_REALM: u32 = 283;
+    pub const PROXY_INFO: u32 = 284;
+    pub const RE_AUTH_REQUEST_TYPE: u32 = 285;
+    pub const ACCOUNTING_SUB_SESSION_ID: u32 = 287;
+    pub const AUTHORIZATION_LIFETIME: u32 = 291;
+    pub const REDIRECT_HOST: u32 = 292;
+    pub const DESTINATION_HOST: u32 = 293;
+    pub const ERROR_REPORTING_HOST: u32 = 294;
+    pub const TERMINATION_CAUSE: u32 = 295;
+    pub const ORIGIN_REALM: u32 = 296;
+    pub const EXPERIMENTAL_RESULT: u32 = 297;
+    pub const EXPERIMENTAL_RESULT_CODE: u32 = 298;
+    pub const INBAND_SECURITY_ID: u32 = 299;
+    pub const DRMP: u32 = 301;
+    pub const CC_CORRELATION_ID: u32 = 411;
+    pub const CC_INPUT_OCTETS: u32 = 412;
+    pub const CC_MONEY: u32 = 413;
+    pub const CC_OUTPUT_OCTETS: u32 = 414;
+    pub const CC_REQUEST_NUMBER: u32 = 415;
+    pub const CC_REQUEST_TYPE: u32 = 416;
+    pub const CC_SERVICE_SPECIFIC_UNITS: u32 = 417;
+    pub const CC_SESSION_FAILOVER: u32 = 418;
+    pub const CC_SUB_SESSION_ID: u32 = 419;
+    pub const CC_TIME: u32 = 420;
+    pub const CC_TOTAL_OCTETS: u32 = 421;
+    pub const CHECK_BALANCE_RESULT: u32 = 422;
+    pub const COST_INFORMATION: u32 = 423;
+    pub const COST_UNIT: u32 = 424;
+    pub const CURRENCY_CODE: u32 = 425;
+    pub const CREDIT_CONTROL: u32 = 426;
+    pub const CREDIT_CONTROL_FAILURE_HANDLING: u32 = 427;
+    pub const DIRECT_DEBITING_FAILURE_HANDLING: u32 = 428;
+    pub const EXPONENT: u32 = 429;
+    pub const FINAL_UNIT_INDICATION: u32 = 430;
+    pub const GRANTED_SERVICE_UNIT: u32 = 431;
+    pub const RATING_GROUP: u32 = 432;
+    pub const REDIRECT_ADDRESS_TYPE: u32 = 433;
+    pub const REDIRECT_SERVER: u32 = 434;
+    pub const REDIRECT_SERVER_ADDRESS: u32 = 435;
+    pub const REQUESTED_ACTION: u32 = 436;
+    pub const REQUESTED_SERVICE_UNIT: u32 = 437;
+    pub const RESTRICTION_FILTER_RULE: u32 = 438;
+    pub const SERVICE_IDENTIFIER: u32 = 439;
+    pub const SERVICE_PARAMETER_INFO: u32 = 440;
+    pub const SERVICE_PARAMETER_TYPE: u32 = 441;
+    pub const SERVICE_PARAMETER_VALUE: u32 = 442;
+    pub const SUBSCRIPTION_ID: u32 = 443;
+    pub const SUBSCRIPTION_ID_DATA: u32 = 444;
+    pub const UNIT_VALUE: u32 = 445;
+    pub const USED_SERVICE_UNIT: u32 = 446;
+    pub const VALUE_DIGITS: u32 = 447;
+    pub const VALIDITY_TIME: u32 = 448;
+    pub const FINAL_UNIT_ACTION: u32 = 449;
+    pub const SUBSCRIPTION_ID_TYPE: u32 = 450;
+    pub const TARIFF_TIME_CHANGE: u32 = 451;
+    pub const TARIFF_CHANGE_USAGE: u32 = 452;
+    pub const G_S_U_POOL_IDENTIFIER: u32 = 453;
+    pub const CC_UNIT_TYPE: u32 = 454;
+    pub const MULTIPLE_SERVICES_INDICATOR: u32 = 455;
+    pub const MULTIPLE_SERVICES_CREDIT_CONTROL: u32 = 456;
+    pub const G_S_U_POOL_REFERENCE: u32 = 457;
+    pub const USER_EQUIPMENT_INFO: u32 = 458;
+    pub const USER_EQUIPMENT_INFO_TYPE: u32 = 459;
+    pub const USER_EQUIPMENT_INFO_VALUE: u32 = 460;
+    pub const SERVICE_CONTEXT_ID: u32 = 461;
+    pub const ACCOUNTING_RECORD_TYPE: u32 = 480;
+    pub const ACCOUNTING_REALTIME_REQUIRED: u32 = 483;
+    pub const ACCOUNTING_RECORD_NUMBER: u32 = 485;
+    pub const OC_SUPPORTED_FEATURES: u32 = 621;
+    pub const OC_FEATURE_VECTOR: u32 = 622;
+    pub const OC_OLR: u32 = 623;
+    pub const OC_SEQUENCE_NUMBER: u32 = 624;
+    pub const OC_VALIDITY_DURATION: u32 = 625;
+    pub const OC_REPORT_TYPE: u32 = 626;
+    pub const OC_REDUCTION_PERCENTAGE: u32 = 627;
+    pub const TIMEZONE_OFFSET: u32 = 571;
+    pub const SERVICE_INFORMATION: u32 = 873;
+    pub const PS_INFORMATION: u32 = 874;
+}
+
+#[allow(dead_code, clippy::enum_variant_names)]
+pub mod enums {
+    /// Values of the Redirect-Host-Usage AVP.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[repr(i32)]
+    pub enum RedirectHostUsage {
+        DontCache = 0,
+        AllSession = 1,
+        AllRealm = 2,
+        RealmAndApplication = 3,
+        AllApplication = 4,
+        AllHost = 5,
+        AllUser = 6,
+    }
+
+    impl ::core::convert::TryFrom<i32> for RedirectHostUsage {
+        type Error = i32;
+
+        fn try_from(value: i32) -> ::core::result::Result<Self, Self::Error> {
+            match value {
+                0 => ::core::result::Result::Ok(RedirectHostUsage::DontCache),
+                1 => ::core::result::Result::Ok(RedirectHostUsage::AllSession),
+                2 => ::core::result::Result::Ok(RedirectHostUsage::AllRealm),
+                3 => ::core::result::Result::Ok(RedirectHostUsage::RealmAndApplication),
+                4 => ::core::result::Result::Ok(RedirectHostUsage::AllApplication),
+                5 => ::core::result::Result::Ok(RedirectHostUsage::AllHost),
+                6 => ::core::result::Result::Ok(RedirectHostUsage::AllUser),
+                _ => ::core::result::Result::Err(value),
+            }
+        }
+    }
+
+    /// Values of the Session-Server-Failover AVP.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[repr(i32)]
+    pub enum SessionServerFailover {
+        RefuseService = 0,
+        TryAgain = 1,
+        AllowService = 2,
+        TryAgainAllowService = 3,
+    }
+
+    impl ::core::convert::TryFrom<i32> for SessionServerFailover {
+        type Error = i32;
+
+        fn try_from(value: i32) -> ::core::result::Result<Self, Self::Error> {
+            match value {
+                0 => ::core::result::Result::Ok(SessionServerFailover::RefuseService),
+                1 => ::core::result::Result::Ok(SessionServerFailover::TryAgain),
+                2 => ::core::result::Result::Ok(SessionServerFailover::AllowService),
+                3 => ::core::result::Result::Ok(SessionServerFailover::TryAgainAllowService),
+                _ => ::core::result::Result::Err(value),
+            }
+        }
+    }
+
+    /// Values of the Disconnect-Cause AVP.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[repr(i32)]
+    pub enum DisconnectCause {
+        Rebooting = 0,
+        Busy = 1,
+        DoNotWantToTalkToYou = 2,
+    }
+
+    impl ::core::convert::TryFrom<i32> for DisconnectCause {
+        type Error = i32;
+
+        fn try_from(value: i32) -> ::core::result::Result<Self, Self::Error> {
+            match value {
+                0 => ::core::result::Result::Ok(DisconnectCause::Rebooting),
+                1 => ::core::result::Result::Ok(DisconnectCause::Busy),
+                2 => ::core::result::Result::Ok(DisconnectCause::DoNotWantToTalkToYou),
+                _ => ::core::result::Result::Err(value),
+            }
+        }
+    }
+
+    /// Values of the Auth-Request-Type AVP.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[repr(i32)]
+    pub enum AuthRequestType {
+        AuthenticateOnly = 1,
+        AuthorizeOnly = 2,
+        AuthorizeAuthenticate = 3,
+    }
+
+    impl ::core::convert::TryFrom<i32> for AuthRequestType {
+        type Error = i32;
+
+        fn try_from(value: i32) -> ::core::result::Result<Self, Self::Error> {
+            match value {
+                1 => ::core::result::Result::Ok(AuthRequestType::AuthenticateOnly),
+                2 => ::core::result::Result::Ok(AuthRequestType::AuthorizeOnly),
+                3 => ::core::result::Result::Ok(AuthRequestType::AuthorizeAuthenticate),
+                _ => ::core::result::Result::Err(value),
+            }
+        }
+    }
+
+    /// Values of the Auth-Session-State AVP.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[repr(i32)]
+    pub enum AuthSessionState {
+        StateMaintained = 0,
+        NoStateMaintained = 1,
+    }
+
+    impl ::core::convert::TryFrom<i32> for AuthSessionState {
+        type Error = i32;
+
+        fn try_from(value: i32) -> ::core::result::Result<Self, Self::Error> {
+            match value {
+                0 => ::core::result::Result::Ok(AuthSessionState::StateMaintained),
+                1 => ::core::result::Result::Ok(AuthSessionState::NoStateMaintained),
+                _ => ::core::result::Result::Err(value),
+            }
+        }
+    }
+
+    /// Values of the Re-Auth-Request-Type AVP.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[repr(i32)]
+    pub enum ReAuthRequestType {
+        AuthorizeOnly = 0,
+        AuthorizeAuthenticate = 1,
+    }
+
+    impl ::core::convert::TryFrom<i32> for ReAuthRequestType {
+        type Error = i32;
+
+        fn try_from(value: i32) -> ::core::result::Result<Self, Self::Error> {
+            match value {
+                0 => ::core::result::Result::Ok(ReAuthRequestType::AuthorizeOnly),
+                1 => ::core::result::Result::Ok(ReAuthRequestType::AuthorizeAuthenticate),
+                _ => ::core::result::Result::Err(value),
+            }
+        }
+    }
+
+    /// Values of the Termination-Cause AVP.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[repr(i32)]
+    pub enum TerminationCause {
+        DiameterLogout = 1,
+        DiameterServiceNotProvided = 2,
+        DiameterBadAnswer = 3,
+        DiameterAdministrative = 4,
+        DiameterLinkBroken = 5,
+        DiameterAuthExpired = 6,
+        DiameterUserMoved = 7,
+        DiameterSessionTimeout = 8,
+    }
+
+    impl ::core::convert::TryFrom<i32> for TerminationCause {
+        type Error = i32;
+
+        fn try_from(value: i32) -> ::core::result::Result<Self, Self::Error> {
+            match value {
+                1 => ::core::result::Result::Ok(TerminationCause::DiameterLogout),
+                2 => ::core::result::Result::Ok(TerminationCause::DiameterServiceNotProvided),
+                3 => ::core::result::Result::Ok(TerminationCause::DiameterBadAnswer),
+                4 => ::core::result::Result::Ok(TerminationCause::DiameterAdministrative),
+                5 => ::core::result::Result::Ok(TerminationCause::DiameterLinkBroken),
+                6 => ::core::result::Result::Ok(TerminationCause::DiameterAuthExpired),
+                7 => ::core::result::Result::Ok(TerminationCause::DiameterUserMoved),
+                8 => ::core::result::Result::Ok(TerminationCause::DiameterSessionTimeout),
+                _ => ::core::result::Result::Err(value),
+            }
+        }
+    }
+
+    /// Values of the DRMP AVP.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[repr(i32)]
+    pub enum Drmp {
+        Priority0 = 0,
+        Priority1 = 1,
+        Priority2 = 2,
+        Priority3 = 3,
+        Priority4 = 4,
+        Priority5 = 5,
+        Priority6 = 6,
+        Priority7 = 7,
+        Priority8 = 8,
+        Priority9 = 9,
+        Priority10 = 10,
+        Priority11 = 11,
+        Priority12 = 12,
+        Priority13 = 13,
+        Priority14 = 14,
+        Priority15 = 15,
+    }
+
+    impl ::core::convert::TryFrom<i32> for Drmp {
+        type Error = i32;
+
+        fn try_from(value: i32) -> ::core::result::Result<Self, Self::Error> {
+            match value {
+                0 => ::core::result::Result::Ok(Drmp::Priority0),
+                1 => ::core::result::Result::Ok(Drmp::Priority1),
+                2 => ::core::result::Result::Ok(Drmp::Priority2),
+                3 => ::core::result::Result::Ok(Drmp::Priority3),
+                4 => ::core::result::Result::Ok(Drmp::Priority4),
+                5 => ::core::result::Result::Ok(Drmp::Priority5),
+                6 => ::core::result::Result::Ok(Drmp::Priority6),
+                7 => ::core::result::Result::Ok(Drmp::Priority7),
+                8 => ::core::result::Result::Ok(Drmp::Priority8),
+                9 => ::core::result::Result::Ok(Drmp::Priority9),
+                10 => ::core::result::Result::Ok(Drmp::Priority10),
+                11 => ::core::result::Result::Ok(Drmp::Priority11),
+                12 => ::core::result::Result::Ok(Drmp::Priority12),
+                13 => ::core::result::Result::Ok(Drmp::Priority13),
+                14 => ::core::result::Result::Ok(Drmp::Priority14),
+                15 => ::core::result::Result::Ok(Drmp::Priority15),
+                _ => ::core::result::Result::Err(value),
+            }
+        }
+    }
+
+    /// Values of the CC-Request-Type AVP.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[repr(i32)]
+    pub enum CcRequestType {
+        InitialRequest = 1,
+        UpdateRequest = 2,
+        TerminationRequest = 3,
+        EventRequest = 4,
+    }
+
+    impl ::core::convert::TryFrom<i32> for CcRequestType {
+        type Error = i32;
+
+        fn try_from(value: i32) -> ::core::result::Result<Self, Self::Error> {
+            match value {
+                1 => ::core::result::Result::Ok(CcRequestType::InitialRequest),
+                2 => ::core::result::Result::Ok(CcRequestType::UpdateRequest),
+                3 => ::core::result::Result::Ok(CcRequestType::TerminationRequest),
+                4 => ::core::result::Result::Ok(CcRequestType::EventRequest),
+                _ => ::core::result::Result::Err(value),
+            }
+        }
+    }
+
+    /// Values of the CC-Session-Failover AVP.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[repr(i32)]
+    pub enum CcSessionFailover {
+        FailoverNotSupported = 0,
+        FailoverSupported = 1,
+    }
+
+    impl ::core::convert::TryFrom<i32> for CcSessionFailover {
+        type Error = i32;
+
+        fn try_from(value: i32) -> ::core::result::Result<Self, Self::Error> {
+            match value {
+                0 => ::core::result::Result::Ok(CcSessionFailover::FailoverNotSupported),
+                1 => ::core::result::Result::Ok(CcSessionFailover::FailoverSupported),
+                _ => ::core::result::Result::Err(value),
+            }
+        }
+    }
+
+    /// Values of the Check-Balance-Result AVP.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[repr(i32)]
+    pub enum CheckBalanceResult {
+        EnoughCredit = 0,
+        NoCredit = 1,
+    }
+
+    impl ::core::convert::TryFrom<i32> for CheckBalanceResult {
+        type Error = i32;
+
+        fn try_from(value: i32) -> ::core::result::Result<Self, Self::Error> {
+            match value {
+                0 => ::core::result::Result::Ok(CheckBalanceResult::EnoughCredit),
+                1 => ::core::result::Result::Ok(CheckBalanceResult::NoCredit),
+                _ => ::core::result::Result::Err(value),
+            }
+        }
+    }
+
+    /// Values of the Credit-Control AVP.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[repr(i32)]
+    pub enum CreditControl {
+        CreditAuthorization = 0,
+        ReAuthorization = 1,
+    }
+
+    impl ::core::convert::TryFrom<i32> for CreditControl {
+        type Error = i32;
+
+        fn try_from(value: i32) -> ::core::result::Result<Self, Self::Error> {
+            match value {
+                0 => ::core::result::Result::Ok(CreditControl::CreditAuthorization),
+                1 => ::core::result::Result::Ok(CreditControl::ReAuthorization),
+                _ => ::core::result::Result::Err(value),
+            }
+        }
+    }
+
+    /// Values of the Credit-Control-Failure-Handling AVP.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[repr(i32)]
+    pub enum CreditControlFailureHandling {
+        Terminate = 0,
+        Continue = 1,
+        RetryAndTerminate = 2,
+    }
+
+    impl ::core::convert::TryFrom<i32> for CreditControlFailureHandling {
+        type Error = i32;
+
+        fn try_from(value: i32) -> ::core::result::Result<Self, Self::Error> {
+            match value {
+                0 => ::core::result::Result::Ok(CreditControlFailureHandling::Terminate),
+                1 => ::core::result::Result::Ok(CreditControlFailureHandling::Continue),
+                2 => ::core::result::Result::Ok(CreditControlFailureHandling::RetryAndTerminate),
+                _ => ::core::result::Result::Err(value),
+            }
+        }
+    }
+
+    /// Values of the Direct-Debiting-Failure-Handling AVP.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[repr(i32)]
+    pub enum DirectDebitingFailureHandling {
+        TerminateOrBuffer = 0,
+        Continue = 1,
+    }
+
+    impl ::core::convert::TryFrom<i32> for DirectDebitingFailureHandling {
+        type Error = i32;
+
+        fn try_from(value: i32) -> ::core::result::Result<Self, Self::Error> {
+            match value {
+                0 => ::core::result::Result::Ok(DirectDebitingFailureHandling::TerminateOrBuffer),
+                1 => ::core::result::Result::Ok(DirectDebitingFailureHandling::Continue),
+                _ => ::core::result::Result::Err(value),
+            }
+        }
+    }
+
+    /// Values of the Redirect-Address-Type AVP.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[repr(i32)]
+    pub enum RedirectAddressType {
+        Ipv4Address = 0,
+        Ipv6Address = 1,
+        Url = 2,
+        SipUri = 3,
+    }
+
+    impl ::core::convert::TryFrom<i32> for RedirectAddressType {
+        type Error = i32;
+
+        fn try_from(value: i32) -> ::core::result::Result<Self, Self::Error> {
+            match value {
+                0 => ::core::result::Result::Ok(RedirectAddressType::Ipv4Address),
+                1 => ::core::result::Result::Ok(RedirectAddressType::Ipv6Address),
+                2 => ::core::result::Result::Ok(RedirectAddressType::Url),
+                3 => ::core::result::Result::Ok(RedirectAddressType::SipUri),
+                _ => ::core::result::Result::Err(value),
+            }
+        }
+    }
+
+    /// Values of the Requested-Action AVP.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[repr(i32)]
+    pub enum RequestedAction {
+        DirectDebiting = 0,
+        RefundAccount = 1,
+        CheckBalance = 2,
+        PriceEnquiry = 3,
+    }
+
+    impl ::core::convert::TryFrom<i32> for RequestedAction {
+        type Error = i32;
+
+        fn try_from(value: i32) -> ::core::result::Result<Self, Self::Error> {
+            match value {
+                0 => ::core::result::Result::Ok(RequestedAction::DirectDebiting),
+                1 => ::core::result::Result::Ok(RequestedAction::RefundAccount),
+                2 => ::core::result::Result::Ok(RequestedAction::CheckBalance),
+                3 => ::core::result::Result::Ok(RequestedAction::PriceEnquiry),
+                _ => ::core::result::Result::Err(value),
+            }
+        }
+    }
+
+    /// Values of the Final-Unit-Action AVP.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[repr(i32)]
+    pub enum FinalUnitAction {
+        Terminate = 0,
+        Redirect = 1,
+        RestrictAccess = 2,
+    }
+
+    impl ::core::convert::TryFrom<i32> for FinalUnitAction {
+        type Error = i32;
+
+        fn try_from(value: i32) -> ::core::result::Result<Self, Self::Error> {
+            match value {
+                0 => ::core::result::Result::Ok(FinalUnitAction::Terminate),
+                1 => ::core::result::Result::Ok(FinalUnitAction::Redirect),
+                2 => ::core::result::Result::Ok(FinalUnitAction::RestrictAccess),
+                _ => ::core::result::Result::Err(value),
+            }
+        }
+    }
+
+    /// Values of the Subscription-Id-Type AVP.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[repr(i32)]
+    pub enum SubscriptionIdType {
+        EndUserE164 = 0,
+        EndUserImsi = 1,
+        EndUserSipUri = 2,
+        EndUserNai = 3,
+        EndUserPrivate = 4,
+    }
+
+    impl ::core::convert::TryFrom<i32> for SubscriptionIdType {
+        type Error = i32;
+
+        fn try_from(value: i32) -> ::core::result::Result<Self, Self::Error> {
+            match value {
+                0 => ::core::result::Result::Ok(SubscriptionIdType::EndUserE164),
+                1 => ::core::result::Result::Ok(SubscriptionIdType::EndUserImsi),
+                2 => ::core::result::Result::Ok(SubscriptionIdType::EndUserSipUri),
+                3 => ::core::result::Result::Ok(SubscriptionIdType::EndUserNai),
+                4 => ::core::result::Result::Ok(SubscriptionIdType::EndUserPrivate),
+                _ => ::core::result::Result::Err(value),
+            }
+        }
+    }
+
+    /// Values of the Tariff-Change-Usage AVP.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[repr(i32)]
+    pub enum TariffChangeUsage {
+        UnitBeforeTariffChange = 0,
+        UnitAfterTariffChange = 1,
+        UnitIndeterminate = 2,
+    }
+
+    impl ::core::convert::TryFrom<i32> for TariffChangeUsage {
+        type Error = i32;
+
+        fn try_from(value: i32) -> ::core::result::Result<Self, Self::Error> {
+            match value {
+                0 => ::core::result::Result::Ok(TariffChangeUsage::UnitBeforeTariffChange),
+                1 => ::core::result::Result::Ok(TariffChangeUsage::UnitAfterTariffChange),
+                2 => ::core::result::Result::Ok(TariffChangeUsage::UnitIndeterminate),
+                _ => ::core::result::Result::Err(value),
+            }
+        }
+    }
+
+    /// Values of the CC-Unit-Type AVP.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[repr(i32)]
+    pub enum CcUnitType {
+        Time = 0,
+        Money = 1,
+        TotalOctets = 2,
+        InputOctets = 3,
+        OutputOctets = 4,
+        ServiceSpecificUnits = 5,
+    }
+
+    impl ::core::convert::TryFrom<i32> for CcUnitType {
+        type Error = i32;
+
+        fn try_from(value: i32) -> ::core::result::Result<Self, Self::Error> {
+            match value {
+                0 => ::core::result::Result::Ok(CcUnitType::Time),
+                1 => ::core::result::Result::Ok(CcUnitType::Money),
+                2 => ::core::result::Result::Ok(CcUnitType::TotalOctets),
+                3 => ::core::result::Result::Ok(CcUnitType::InputOctets),
+                4 => ::core::result::Result::Ok(CcUnitType::OutputOctets),
+                5 => ::core::result::Result::Ok(CcUnitType::ServiceSpecificUnits),
+                _ => ::core::result::Result::Err(value),
+            }
+        }
+    }
+
+    /// Values of the Multiple-Services-Indicator AVP.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[repr(i32)]
+    pub enum MultipleServicesIndicator {
+        MultipleServicesNotSupported = 0,
+        MultipleServicesSupported = 1,
+    }
+
+    impl ::core::convert::TryFrom<i32> for MultipleServicesIndicator {
+        type Error = i32;
+
+        fn try_from(value: i32) -> ::core::result::Result<Self, Self::Error> {
+            match value {
+                0 => ::core::result::Result::Ok(MultipleServicesIndicator::MultipleServicesNotSupported),
+                1 => ::core::result::Result::Ok(MultipleServicesIndicator::MultipleServicesSupported),
+                _ => ::core::result::Result::Err(value),
+            }
+        }
+    }
+
+    /// Values of the User-Equipment-Info-Type AVP.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[repr(i32)]
+    pub enum UserEquipmentInfoType {
+        Imeisv = 0,
+        Mac = 1,
+        Eui64 = 2,
+        ModifiedEui64 = 3,
+    }
+
+    impl ::core::convert::TryFrom<i32> for UserEquipmentInfoType {
+        type Error = i32;
+
+        fn try_from(value: i32) -> ::core::result::Result<Self, Self::Error> {
+            match value {
+                0 => ::core::result::Result::Ok(UserEquipmentInfoType::Imeisv),
+                1 => ::core::result::Result::Ok(UserEquipmentInfoType::Mac),
+                2 => ::core::result::Result::Ok(UserEquipmentInfoType::Eui64),
+                3 => ::core::result::Result::Ok(UserEquipmentInfoType::ModifiedEui64),
+                _ => ::core::result::Result::Err(value),
+            }
+        }
+    }
+
+    /// Values of the Accounting-Record-Type AVP.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[repr(i32)]
+    pub enum AccountingRecordType {
+        EventRecord = 1,
+        StartRecord = 2,
+        InterimRecord = 3,
+        StopRecord = 4,
+    }
+
+    impl ::core::convert::TryFrom<i32> for AccountingRecordType {
+        type Error = i32;
+
+        fn try_from(value: i32) -> ::core::result::Result<Self, Self::Error> {
+            match value {
+                1 => ::core::result::Result::Ok(AccountingRecordType::EventRecord),
+                2 => ::core::result::Result::Ok(AccountingRecordType::StartRecord),
+                3 => ::core::result::Result::Ok(AccountingRecordType::InterimRecord),
+                4 => ::core::result::Result::Ok(AccountingRecordType::StopRecord),
+                _ => ::core::result::Result::Err(value),
+            }
+        }
+    }
+
+    /// Values of the Accounting-Realtime-Required AVP.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[repr(i32)]
+    pub enum AccountingRealtimeRequired {
+        DeliverAndGrant = 1,
+        GrantAndStore = 2,
+        GrantAndLose = 3,
+    }
+
+    impl ::core::convert::TryFrom<i32> for AccountingRealtimeRequired {
+        type Error = i32;
+
+        fn try_from(value: i32) -> ::core::result::Result<Self, Self::Error> {
+            match value {
+                1 => ::core::result::Result::Ok(AccountingRealtimeRequired::DeliverAndGrant),
+                2 => ::core::result::Result::Ok(AccountingRealtimeRequired::GrantAndStore),
+                3 => ::core::result::Result::Ok(AccountingRealtimeRequired::GrantAndLose),
+                _ => ::core::result::Result::Err(value),
+            }
+        }
+    }
+
+    /// Values of the OC-Report-Type AVP.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[repr(i32)]
+    pub enum OcReportType {
+        HostReport = 0,
+        RealmReport = 1,
+    }
+
+    impl ::core::convert::TryFrom<i32> for OcReportType {
+        type Error = i32;
+
+        fn try_from(value: i32) -> ::core::result::Result<Self, Self::Error> {
+            match value {
+                0 => ::core::result::Result::Ok(OcReportType::HostReport),
+                1 => ::core::result::Result::Ok(OcReportType::RealmReport),
+                _ => ::core::result::Result::Err(value),
+            }
+        }
+    }
+
+}
+
+#[allow(dead_code)]
+pub mod avp {
+    use ::diameter_interface::modeling::avp::avp::{Avp, AvpFlags};
+
+    fn find(avps: &[Avp], code: u32, vendor_id: Option<u32>) -> Option<&Avp> {
+        avps.iter().find(|avp| avp.code() == code && avp.vendor_id() == vendor_id)
+    }
+
+    /// Builds a User-Name AVP.
+    pub fn user_name(value: impl Into<String>) -> Avp {
+        Avp::new(super::code::USER_NAME, AvpFlags::M, None, ::diameter_interface::modeling::avp::utf8_string::UTF8String::new(value.into()))
+    }
+
+    /// Returns the first User-Name AVP in `avps`.
+    pub fn get_user_name(avps: &[Avp]) -> Option<&str> {
+        find(avps, super::code::USER_NAME, None).and_then(|avp| avp.value().as_str())
+    }
+
+    /// Builds a Filter-Id AVP.
+    pub fn filter_id(value: impl Into<String>) -> Avp {
+        Avp::new(super::code::FILTER_ID, AvpFlags::M, None, ::diameter_interface::modeling::avp::utf8_string::UTF8String::new(value.into()))
+    }
+
+    /// Returns the first Filter-Id AVP in `avps`.
+    pub fn get_filter_id(avps: &[Avp]) -> Option<&str> {
+        find(avps, super::code::FILTER_ID, None).and_then(|avp| avp.value().as_str())
+    }
+
+    /// Builds a Class AVP.
+    pub fn class(value: impl Into<Vec<u8>>) -> Avp {
+        Avp::new(super::code::CLASS, AvpFlags::M, None, ::diameter_interface::modeling::avp::octet_string::OctetString::new(value.into()))
+    }
+
+    /// Returns the first Class AVP in `avps`.
+    pub fn get_class(avps: &[Avp]) -> Option<&[u8]> {
+        find(avps, super::code::CLASS, None).and_then(|avp| avp.value().as_bytes())
+    }
+
+    /// Builds a Session-Timeout AVP.
+    pub fn session_timeout(value: u32) -> Avp {
+        Avp::new(super::code::SESSION_TIMEOUT, AvpFlags::M, None, ::diameter_interface::modeling::avp::unsigned32::Unsigned32::new(value))
+    }
+
+    /// Returns the first Session-Timeout AVP in `avps`.
+    pub fn get_session_timeout(avps: &[Avp]) -> Option<u32> {
+        find(avps, super::code::SESSION_TIMEOUT, None).and_then(|avp| avp.value().as_u32())
+    }
+
+    /// Builds a Called-Station-Id AVP.
+    pub fn called_station_id(value: impl Into<String>) -> Avp {
+        Avp::new(super::code::CALLED_STATION_ID, AvpFlags::M, None, ::diameter_interface::modeling::avp::utf8_string::UTF8String::new(value.into()))
+    }
+
+    /// Returns the first Called-Station-Id AVP in `avps`.
+    pub fn get_called_station_id(avps: &[Avp]) -> Option<&str> {
+        find(avps, super::code::CALLED_STATION_ID, None).and_then(|avp| avp.value().as_str())
+    }
+
+    /// Builds a Proxy-State AVP.
+    pub fn proxy_state(value: impl Into<Vec<u8>>) -> Avp {
+        Avp::new(super::code::PROXY_STATE, AvpFlags::M, None, ::diameter_interface::modeling::avp::octet_string::OctetString::new(value.into()))
+    }
+
+    /// Returns the first Proxy-State AVP in `avps`.
+    pub fn get_proxy_state(avps: &[Avp]) -> Option<&[u8]> {
+        find(avps, super::code::PROXY_STATE, None).and_then(|avp| avp.value().as_bytes())
+    }
+
+    /// Builds a Accounting-Session-Id AVP.
+    pub fn accounting_session_id(value: impl Into<Vec<u8>>) -> Avp {
+        Avp::new(super::code::ACCOUNTING_SESSION_ID, AvpFlags::M, None, ::diameter_interface::modeling::avp::octet_string::OctetString::new(value.into()))
+    }
+
+    /// Returns the first Accounting-Session-Id AVP in `avps`.
+    pub fn get_accounting_session_id(avps: &[Avp]) -> Option<&[u8]> {
+        find(avps, super::code::ACCOUNTING_SESSION_ID, None).and_then(|avp| avp.value().as_bytes())
+    }
+
+    /// Builds a Acct-Multi-Session-Id AVP.
+    pub fn acct_multi_session_id(value: impl Into<String>) -> Avp {
+        Avp::new(super::code::ACCT_MULTI_SESSION_ID, AvpFlags::M, None, ::diameter_interface::modeling::avp::utf8_string::UTF8String::new(value.into()))
+    }
+
+    /// Returns the first Acct-Multi-Session-Id AVP in `avps`.
+    pub fn get_acct_multi_session_id(avps: &[Avp]) -> Option<&str> {
+        find(avps, super::code::ACCT_MULTI_SESSION_ID, None).and_then(|avp| avp.value().as_str())
+    }
+
+    /// Builds a Event-Timestamp AVP.
+    pub fn event_timestamp(value: ::diameter_interface::modeling::avp::time::DateTime<::diameter_interface::modeling::avp::time::Utc>) -> Avp {
+        Avp::new(super::code::EVENT_TIMESTAMP, AvpFlags::M, None, ::diameter_interface::modeling::avp::time::Time::new(value))
+    }
+
+    /// Returns the first Event-Timestamp AVP in `avps`.
+    pub fn get_event_timestamp(avps: &[Avp]) -> Option<&::diameter_interface::modeling::avp::time::DateTime<::diameter_interface::modeling::avp::time::Utc>> {
+        find(avps, super::code::EVENT_TIMESTAMP, None).and_then(|avp| avp.value().as_time())
+    }
+
+    /// Builds a Acct-Interim-Interval AVP.
+    pub fn acct_interim_interval(value: u32) -> Avp {
+        Avp::new(super::code::ACCT_INTERIM_INTERVAL, AvpFlags::M, None, ::diameter_interface::modeling::avp::unsigned32::Unsigned32::new(value))
+    }
+
+    /// Returns the first Acct-Interim-Interval AVP in `avps`.
+    pub fn get_acct_interim_interval(avps: &[Avp]) -> Option<u32> {
+        find(avps, super::code::ACCT_INTERIM_INTERVAL, None).and_then(|avp| avp.value().as_u32())
+    }
+
+    /// Builds a Host-IP-Address AVP.
+    pub fn host_ip_address(value: std::net::IpAddr) -> Avp {
+        Avp::new(super::code::HOST_IP_ADDRESS, AvpFlags::M, None, ::diameter_interface::modeling::avp::address::Address::new(value))
+    }
+
+    /// Returns the first Host-IP-Address AVP in `avps`.
+    pub fn get_host_ip_address(avps: &[Avp]) -> Option<std::net::IpAddr> {
+        find(avps, super::code::HOST_IP_ADDRESS, None).and_then(|avp| avp.value().as_ip_addr())
+    }
+
+    /// Builds a Auth-Application-Id AVP.
+    pub fn auth_application_id(value: u32) -> Avp {
+        Avp::new(super::code::AUTH_APPLICATION_ID, AvpFlags::M, None, ::diameter_interface::modeling::avp::unsigned32::Unsigned32::new(value))
+    }
+
+    /// Returns the first Auth-Application-Id AVP in `avps`.
+    pub fn get_auth_application_id(avps: &[Avp]) -> Option<u32> {
+        find(avps, super::code::AUTH_APPLICATION_ID, None).and_then(|avp| avp.value().as_u32())
+    }
+
+    /// Builds a Acct-Application-Id AVP.
+    pub fn acct_application_id(value: u32) -> Avp {
+        Avp::new(super::code::ACCT_APPLICATION_ID, AvpFlags::M, None, ::diameter_interface::modeling::avp::unsigned32::Unsigned32::new(value))
+    }
+
+    /// Returns the first Acct-Application-Id AVP in `avps`.
+    pub fn get_acct_application_id(avps: &[Avp]) -> Option<u32> {
+        find(avps, super::code::ACCT_APPLICATION_ID, None).and_then(|avp| avp.value().as_u32())
+    }
+
+    /// Builds a Vendor-Specific-Application-Id AVP.
+    pub fn vendor_specific_application_id(value: Vec<Avp>) -> Avp {
+        Avp::new(super::code::VENDOR_SPECIFIC_APPLICATION_ID, AvpFlags::M, None, ::diameter_interface::modeling::avp::group::Grouped::new(value))
+    }
+
+    /// Returns the first Vendor-Specific-Application-Id AVP in `avps`.
+    pub fn get_vendor_specific_application_id(avps: &[Avp]) -> Option<&[Avp]> {
+        find(avps, super::code::VENDOR_SPECIFIC_APPLICATION_ID, None).and_then(|avp| avp.value().as_grouped())
+    }
+
+    /// Builds a Redirect-Host-Usage AVP.
+    pub fn redirect_host_usage(value: super::enums::RedirectHostUsage) -> Avp {
+        Avp::new(super::code::REDIRECT_HOST_USAGE, AvpFlags::M, None, ::diameter_interface::modeling::avp::enumerated::Enumerated::new(value as i32))
+    }
+
+    /// Returns the first Redirect-Host-Usage AVP in `avps`.
+    pub fn get_redirect_host_usage(avps: &[Avp]) -> Option<super::enums::RedirectHostUsage> {
+        find(avps, super::code::REDIRECT_HOST_USAGE, None).and_then(|avp| avp.value().as_i32().and_then(|value| value.try_into().ok()))
+    }
+
+    /// Builds a Redirect-Max-Cache-Time AVP.
+    pub fn redirect_max_cache_time(value: u32) -> Avp {
+        Avp::new(super::code::REDIRECT_MAX_CACHE_TIME, AvpFlags::M, None, ::diameter_interface::modeling::avp::unsigned32::Unsigned32::new(value))
+    }
+
+    /// Returns the first Redirect-Max-Cache-Time AVP in `avps`.
+    pub fn get_redirect_max_cache_time(avps: &[Avp]) -> Option<u32> {
+        find(avps, super::code::REDIRECT_MAX_CACHE_TIME, None).and_then(|avp| avp.value().as_u32())
+    }
+
+    /// Builds a Session-Id AVP.
+    pub fn session_id(value: impl Into<String>) -> Avp {
+        Avp::new(super::code::SESSION_ID, AvpFlags::M, None, ::diameter_interface::modeling::avp::utf8_string::UTF8String::new(value.into()))
+    }
+
+    /// Returns the first Session-Id AVP in `avps`.
+    pub fn get_session_id(avps: &[Avp]) -> Option<&str> {
+        find(avps, super::code::SESSION_ID, None).and_then(|avp| avp.value().as_str())
+    }
+
+    /// Builds a Origin-Host AVP.
+    pub fn origin_host(value: impl Into<String>) -> Avp {
+        Avp::new(super::code::ORIGIN_HOST, AvpFlags::M, None, ::diameter_interface::modeling::avp::utf8_string::UTF8String::new(value.into()))
+    }
+
+    /// Returns the first Origin-Host AVP in `avps`.
+    pub fn get_origin_host(avps: &[Avp]) -> Option<&str> {
+        find(avps, super::code::ORIGIN_HOST, None).and_then(|avp| avp.value().as_str())
+    }
+
+    /// Builds a Supported-Vendor-Id AVP.
+    pub fn supported_vendor_id(value: u32) -> Avp {
+        Avp::new(super::code::SUPPORTED_VENDOR_ID, AvpFlags::M, None, ::diameter_interface::modeling::avp::unsigned32::Unsigned32::new(value))
+    }
+
+    /// Returns the first Supported-Vendor-Id AVP in `avps`.
+    pub fn get_supported_vendor_id(avps: &[Avp]) -> Option<u32> {
+        find(avps, super::code::SUPPORTED_VENDOR_ID, None).and_then(|avp| avp.value().as_u32())
+    }
+
+    /// Builds a Vendor-Id AVP.
+    pub fn vendor_id(value: u32) -> Avp {
+        Avp::new(super::code::VENDOR_ID, AvpFlags::M, None, ::diameter_interface::modeling::avp::unsigned32::Unsigned32::new(value))
+    }
+
+    /// Returns the first Vendor-Id AVP in `avps`.
+    pub fn get_vendor_id(avps: &[Avp]) -> Option<u32> {
+        find(avps, super::code::VENDOR_ID, None).and_then(|avp| avp.value().as_u32())
+    }
+
+    /// Builds a Firmware-Revision AVP.
+    pub fn firmware_revision(value: u32) -> Avp {
+        Avp::new(super::code::FIRMWARE_REVISION, AvpFlags::O, None, ::diameter_interface::modeling::avp::unsigned32::Unsigned32::new(value))
+    }
+
+    /// Returns the first Firmware-Revision AVP in `avps`.
+    pub fn get_firmware_revision(avps: &[Avp]) -> Option<u32> {
+        find(avps, super::code::FIRMWARE_REVISION, None).and_then(|avp| avp.value().as_u32())
+    }
+
+    /// Builds a Result-Code AVP.
+    pub fn result_code(value: u32) -> Avp {
+        Avp::new(super::code::RESULT_CODE, AvpFlags::M, None, ::diameter_interface::modeling::avp::unsigned32::Unsigned32::new(value))
+    }
+
+    /// Returns the first Result-Code AVP in `avps`.
+    pub fn get_result_code(avps: &[Avp]) -> Option<u32> {
+        find(avps, super::code::RESULT_CODE, None).and_then(|avp| avp.value().as_u32())
+    }
+
+    /// Builds a Product-Name AVP.
+    pub fn product_name(value: impl Into<String>) -> Avp {
+        Avp::new(super::code::PRODUCT_NAME, AvpFlags::O, None, ::diameter_interface::modeling::avp::utf8_string::UTF8String::new(value.into()))
+    }
+
+    /// Returns the first Product-Name AVP in `avps`.
+    pub fn get_product_name(avps: &[Avp]) -> Option<&str> {
+        find(avps, super::code::PRODUCT_NAME, None).and_then(|avp| avp.value().as_str())
+    }
+
+    /// Builds a Session-Binding AVP.
+    pub fn session_binding(value: u32) -> Avp {
+        Avp::new(super::code::SESSION_BINDING, AvpFlags::M, None, ::diameter_interface::modeling::avp::unsigned32::Unsigned32::new(value))
+    }
+
+    /// Returns the first Session-Binding AVP in `avps`.
+    pub fn get_session_binding(avps: &[Avp]) -> Option<u32> {
+        find(avps, super::code::SESSION_BINDING, None).and_then(|avp| avp.value().as_u32())
+    }
+
+    /// Builds a Session-Server-Failover AVP.
+    pub fn session_server_failover(value: super::enums::SessionServerFailover) -> Avp {
+        Avp::new(super::code::SESSION_SERVER_FAILOVER, AvpFlags::M, None, ::diameter_interface::modeling::avp::enumerated::Enumerated::new(value as i32))
+    }
+
+    /// Returns the first Session-Server-Failover AVP in `avps`.
+    pub fn get_session_server_failover(avps: &[Avp]) -> Option<super::enums::SessionServerFailover> {
+        find(avps, super::code::SESSION_SERVER_FAILOVER, None).and_then(|avp| avp.value().as_i32().and_then(|value| value.try_into().ok()))
+    }
+
+    /// Builds a Multi-Round-Time-Out AVP.
+    pub fn multi_round_time_out(value: u32) -> Avp {
+        Avp::new(super::code::MULTI_ROUND_TIME_OUT, AvpFlags::M, None, ::diameter_interface::modeling::avp::unsigned32::Unsigned32::new(value))
+    }
+
+    /// Returns the first Multi-Round-Time-Out AVP in `avps`.
+    pub fn get_multi_round_time_out(avps: &[Avp]) -> Option<u32> {
+        find(avps, super::code::MULTI_ROUND_TIME_OUT, None).and_then(|avp| avp.value().as_u32())
+    }
+
+    /// Builds a Disconnect-Cause AVP.
+    pub fn disconnect_cause(value: super::enums::DisconnectCause) -> Avp {
+        Avp::new(super::code::DISCONNECT_CAUSE, AvpFlags::M, None, ::diameter_interface::modeling::avp::enumerated::Enumerated::new(value as i32))
+    }
+
+    /// Returns the first Disconnect-Cause AVP in `avps`.
+    pub fn get_disconnect_cause(avps: &[Avp]) -> Option<super::enums::DisconnectCause> {
+        find(avps, super::code::DISCONNECT_CAUSE, None).and_then(|avp| avp.value().as_i32().and_then(|value| value.try_into().ok()))
+    }
+
+    /// Builds a Auth-Request-Type AVP.
+    pub fn auth_request_type(value: super::enums::AuthRequestType) -> Avp {
+        Avp::new(super::code::AUTH_REQUEST_TYPE, AvpFlags::M, None, ::diameter_interface::modeling::avp::enumerated::Enumerated::new(value as i32))
+    }
+
+    /// Returns the first Auth-Request-Type AVP in `avps`.
+    pub fn get_auth_request_type(avps: &[Avp]) -> Option<super::enums::AuthRequestType> {
+        find(avps, super::code::AUTH_REQUEST_TYPE, None).and_then(|avp| avp.value().as_i32().and_then(|value| value.try_into().ok()))
+    }
+
+    /// Builds a Auth-Grace-Period AVP.
+    pub fn auth_grace_period(value: u32) -> Avp {
+        Avp::new(super::code::AUTH_GRACE_PERIOD, AvpFlags::M, None, ::diameter_interface::modeling::avp::unsigned32::Unsigned32::new(value))
+    }
+
+    /// Returns the first Auth-Grace-Period AVP in `avps`.
+    pub fn get_auth_grace_period(avps: &[Avp]) -> Option<u32> {
+        find(avps, super::code::AUTH_GRACE_PERIOD, None).and_then(|avp| avp.value().as_u32())
+    }
+
+    /// Builds a Auth-Session-State AVP.
+    pub fn auth_session_state(value: super::enums::AuthSessionState) -> Avp {
+        Avp::new(super::code::AUTH_SESSION_STATE, AvpFlags::M, None, ::diameter_interface::modeling::avp::enumerated::Enumerated::new(value as i32))
+    }
+
+    /// Returns the first Auth-Session-State AVP in `avps`.
+    pub fn get_auth_session_state(avps: &[Avp]) -> Option<super::enums::AuthSessionState> {
+        find(avps, super::code::AUTH_SESSION_STATE, None).and_then(|avp| avp.value().as_i32().and_then(|value| value.try_into().ok()))
+    }
+
+    /// Builds a Origin-State-Id AVP.
+    pub fn origin_state_id(value: u32) -> Avp {
+        Avp::new(super::code::ORIGIN_STATE_ID, AvpFlags::M, None, ::diameter_interface::modeling::avp::unsigned32::Unsigned32::new(value))
+    }
+
+    /// Returns the first Origin-State-Id AVP in `avps`.
+    pub fn get_origin_state_id(avps: &[Avp]) -> Option<u32> {
+        find(avps, super::code::ORIGIN_STATE_ID, None).and_then(|avp| avp.value().as_u32())
+    }
+
+    /// Builds a Failed-AVP AVP.
+    pub fn failed_avp(value: Vec<Avp>) -> Avp {
+        Avp::new(super::code::FAILED_AVP, AvpFlags::M, None, ::diameter_interface::modeling::avp::group::Grouped::new(value))
+    }
+
+    /// Returns the first Failed-AVP AVP in `avps`.
+    pub fn get_failed_avp(avps: &[Avp]) -> Option<&[Avp]> {
+        find(avps, super::code::FAILED_AVP, None).and_then(|avp| avp.value().as_grouped())
+    }
+
+    /// Builds a Proxy-Host AVP.
+    pub fn proxy_host(value: impl Into<String>) -> Avp {
+        Avp::new(super::code::PROXY_HOST, AvpFlags::M, None, ::diameter_interface::modeling::avp::utf8_string::UTF8String::new(value.into()))
+    }
+
+    /// Returns the first Proxy-Host AVP in `avps`.
+    pub fn get_proxy_host(avps: &[Avp]) -> Option<&str> {
+        find(avps, super::code::PROXY_HOST, None).and_then(|avp| avp.value().as_str())
+    }
+
+    /// Builds a Error-Message AVP.
+    pub fn error_message(value: impl Into<String>) -> Avp {
+        Avp::new(super::code::ERROR_MESSAGE, AvpFlags::O, None, ::diameter_interface::modeling::avp::utf8_string::UTF8String::new(value.into()))
+    }
+
+    /// Returns the first Error-Message AVP in `avps`.
+    pub fn get_error_message(avps: &[Avp]) -> Option<&str> {
+        find(avps, super::code::ERROR_MESSAGE, None).and_then(|avp| avp.value().as_str())
+    }
+
+    /// Builds a Route-Record AVP.
+    pub fn route_record(value: impl Into<String>) -> Avp {
+        Avp::new(super::code::ROUTE_RECORD, AvpFlags::M, None, ::diameter_interface::modeling::avp::utf8_string::UTF8String::new(value.into()))
+    }
+
+    /// Returns the first Route-Record AVP in `avps`.
+    pub fn get_route_record(avps: &[Avp]) -> Option<&str> {
+        find(avps, super::code::ROUTE_RECORD, None).and_then(|avp| avp.value().as_str())
+    }
+
+    /// Builds a Destination-Realm AVP.
+    pub fn destination_realm(value: impl Into<String>) -> Avp {
+        Avp::new(super::code::DESTINATION_REALM, AvpFlags::M, None, ::diameter_interface::modeling::avp::utf8_string::UTF8String::new(value.into()))
+    }
+
+    /// Returns the first Destination-Realm AVP in `avps`.
+    pub fn get_destination_realm(avps: &[Avp]) -> Option<&str> {
+        find(avps, super::code::DESTINATION_REALM, None).and_then(|avp| avp.value().as_str())
+    }
+
+    /// Builds a Proxy-Info AVP.
+    pub fn proxy_info(value: Vec<Avp>) -> Avp {
+        Avp::new(super::code::PROXY_INFO, AvpFlags::M, None, ::diameter_interface::modeling::avp::group::Grouped::new(value))
+    }
+
+    /// Returns the first Proxy-Info AVP in `avps`.
+    pub fn get_proxy_info(avps: &[Avp]) -> Option<&[Avp]> {
+        find(avps, super::code::PROXY_INFO, None).and_then(|avp| avp.value().as_grouped())
+    }
+
+    /// Builds a Re-Auth-Request-Type AVP.
+    pub fn re_auth_request_type(value: super::enums::ReAuthRequestType) -> Avp {
+        Avp::new(super::code::RE_AUTH_REQUEST_TYPE, AvpFlags::M, None, ::diameter_interface::modeling::avp::enumerated::Enumerated::new(value as i32))
+    }
+
+    /// Returns the first Re-Auth-Request-Type AVP in `avps`.
+    pub fn get_re_auth_request_type(avps: &[Avp]) -> Option<super::enums::ReAuthRequestType> {
+        find(avps, super::code::RE_AUTH_REQUEST_TYPE, None).and_then(|avp| avp.value().as_i32().and_then(|value| value.try_into().ok()))
+    }
+
+    /// Builds a Accounting-Sub-Session-Id AVP.
+    pub fn accounting_sub_session_id(value: u64) -> Avp {
+        Avp::new(super::code::ACCOUNTING_SUB_SESSION_ID, AvpFlags::M, None, ::diameter_interface::modeling::avp::unsigned64::Unsigned64::new(value))
+    }
+
+    /// Returns the first Accounting-Sub-Session-Id AVP in `avps`.
+    pub fn get_accounting_sub_session_id(avps: &[Avp]) -> Option<u64> {
+        find(avps, super::code::ACCOUNTING_SUB_SESSION_ID, None).and_then(|avp| avp.value().as_u64())
+    }
+
+    /// Builds a Authorization-Lifetime AVP.
+    pub fn authorization_lifetime(value: u32) -> Avp {
+        Avp::new(super::code::AUTHORIZATION_LIFETIME, AvpFlags::M, None, ::diameter_interface::modeling::avp::unsigned32::Unsigned32::new(value))
+    }
+
+    /// Returns the first Authorization-Lifetime AVP in `avps`.
+    pub fn get_authorization_lifetime(avps: &[Avp]) -> Option<u32> {
+        find(avps, super::code::AUTHORIZATION_LIFETIME, None).and_then(|avp| avp.value().as_u32())
+    }
+
+    /// Builds a Redirect-Host AVP.
+    pub fn redirect_host(value: ::diameter_interface::modeling::avp::diameter_uri::Uri) -> Avp {
+        Avp::new(super::code::REDIRECT_HOST, AvpFlags::M, None, ::diameter_interface::modeling::avp::diameter_uri::DiameterURI::new(value))
+    }
+
+    /// Returns the first Redirect-Host AVP in `avps`.
+    pub fn get_redirect_host(avps: &[Avp]) -> Option<&::diameter_interface::modeling::avp::diameter_uri::Uri> {
+        find(avps, super::code::REDIRECT_HOST, None).and_then(|avp| avp.value().as_uri())
+    }
+
+    /// Builds a Destination-Host AVP.
+    pub fn destination_host(value: impl Into<String>) -> Avp {
+        Avp::new(super::code::DESTINATION_HOST, AvpFlags::M, None, ::diameter_interface::modeling::avp::utf8_string::UTF8String::new(value.into()))
+    }
+
+    /// Returns the first Destination-Host AVP in `avps`.
+    pub fn get_destination_host(avps: &[Avp]) -> Option<&str> {
+        find(avps, super::code::DESTINATION_HOST, None).and_then(|avp| avp.value().as_str())
+    }
+
+    /// Builds a Error-Reporting-Host AVP.
+    pub fn error_reporting_host(value: impl Into<String>) -> Avp {
+        Avp::new(super::code::ERROR_REPORTING_HOST, AvpFlags::O, None, ::diameter_interface::modeling::avp::utf8_string::UTF8String::new(value.into()))
+    }
+
+    /// Returns the first Error-Reporting-Host AVP in `avps`.
+    pub fn get_error_reporting_host(avps: &[Avp]) -> Option<&str> {
+        find(avps, super::code::ERROR_REPORTING_HOST, None).and_then(|avp| avp.value().as_str())
+    }
+
+    /// Builds a Termination-Cause AVP.
+    pub fn termination_cause(value: super::enums::TerminationCause) -> Avp {
+        Avp::new(super::code::TERMINATION_CAUSE, AvpFlags::M, None, ::diameter_interface::modeling::avp::enumerated::Enumerated::new(value as i32))
+    }
+
+    /// Returns the first Termination-Cause AVP in `avps`.
+    pub fn get_termination_cause(avps: &[Avp]) -> Option<super::enums::TerminationCause> {
+        find(avps, super::code::TERMINATION_CAUSE, None).and_then(|avp| avp.value().as_i32().and_then(|value| value.try_into().ok()))
+    }
+
+    /// Builds a Origin-Realm AVP.
+    pub fn origin_realm(value: impl Into<String>) -> Avp {
+        Avp::new(super::code::ORIGIN_REALM, AvpFlags::M, None, ::diameter_interface::modeling::avp::utf8_string::UTF8String::new(value.into()))
+    }
+
+    /// Returns the first Origin-Realm AVP in `avps`.
+    pub fn get_origin_realm(avps: &[Avp]) -> Option<&str> {
+        find(avps, super::code::ORIGIN_REALM, None).and_then(|avp| avp.value().as_str())
+    }
+
+    /// Builds a Experimental-Result AVP.
+    pub fn experimental_result(value: Vec<Avp>) -> Avp {
+        Avp::new(super::code::EXPERIMENTAL_RESULT, AvpFlags::M, None, ::diameter_interface::modeling::avp::group::Grouped::new(value))
+    }
+
+    /// Returns the first Experimental-Result AVP in `avps`.
+    pub fn get_experimental_result(avps: &[Avp]) -> Option<&[Avp]> {
+        find(avps, super::code::EXPERIMENTAL_RESULT, None).and_then(|avp| avp.value().as_grouped())
+    }
+
+    /// Builds a Experimental-Result-Code AVP.
+    pub fn experimental_result_code(value: u32) -> Avp {
+        Avp::new(super::code::EXPERIMENTAL_RESULT_CODE, AvpFlags::M, None, ::diameter_interface::modeling::avp::unsigned32::Unsigned32::new(value))
+    }
+
+    /// Returns the first Experimental-Result-Code AVP in `avps`.
+    pub fn get_experimental_result_code(avps: &[Avp]) -> Option<u32> {
+        find(avps, super::code::EXPERIMENTAL_RESULT_CODE, None).and_then(|avp| avp.value().as_u32())
+    }
+
+    /// Builds a Inband-Security-Id AVP.
+    pub fn inband_security_id(value: u32) -> Avp {
+        Avp::new(super::code::INBAND_SECURITY_ID, AvpFlags::M, None, ::diameter_interface::modeling::avp::unsigned32::Unsigned32::new(value))
+    }
+
+    /// Returns the first Inband-Security-Id AVP in `avps`.
+    pub fn get_inband_security_id(avps: &[Avp]) -> Option<u32> {
+        find(avps, super::code::INBAND_SECURITY_ID, None).and_then(|avp| avp.value().as_u32())
+    }
+
+    /// Builds a DRMP AVP.
+    pub fn drmp(value: super::enums::Drmp) -> Avp {
+        Avp::new(super::code::DRMP, AvpFlags::O, None, ::diameter_interface::modeling::avp::enumerated::Enumerated::new(value as i32))
+    }
+
+    /// Returns the first DRMP AVP in `avps`.
+    pub fn get_drmp(avps: &[Avp]) -> Option<super::enums::Drmp> {
+        find(avps, super::code::DRMP, None).and_then(|avp| avp.value().as_i32().and_then(|value| value.try_into().ok()))
+    }
+
+    /// Builds a CC-Correlation-Id AVP.
+    pub fn cc_correlation_id(value: impl Into<Vec<u8>>) -> Avp {
+        Avp::new(super::code::CC_CORRELATION_ID, AvpFlags::O, None, ::diameter_interface::modeling::avp::octet_string::OctetString::new(value.into()))
+    }
+
+    /// Returns the first CC-Correlation-Id AVP in `avps`.
+    pub fn get_cc_correlation_id(avps: &[Avp]) -> Option<&[u8]> {
+        find(avps, super::code::CC_CORRELATION_ID, None).and_then(|avp| avp.value().as_bytes())
+    }
+
+    /// Builds a CC-Input-Octets AVP.
+    pub fn cc_input_octets(value: u64) -> Avp {
+        Avp::new(super::code::CC_INPUT_OCTETS, AvpFlags::M, None, ::diameter_interface::modeling::avp::unsigned64::Unsigned64::new(value))
+    }
+
+    /// Returns the first CC-Input-Octets AVP in `avps`.
+    pub fn get_cc_input_octets(avps: &[Avp]) -> Option<u64> {
+        find(avps, super::code::CC_INPUT_OCTETS, None).and_then(|avp| avp.value().as_u64())
+    }
+
+    /// Builds a CC-Money AVP.
+    pub fn cc_money(value: Vec<Avp>) -> Avp {
+        Avp::new(super::code::CC_MONEY, AvpFlags::M, None, ::diameter_interface::modeling::avp::group::Grouped::new(value))
+    }
+
+    /// Returns the first CC-Money AVP in `avps`.
+    pub fn get_cc_money(avps: &[Avp]) -> Option<&[Avp]> {
+        find(avps, super::code::CC_MONEY, None).and_then(|avp| avp.value().as_grouped())
+    }
+
+    /// Builds a CC-Output-Octets AVP.
+    pub fn cc_output_octets(value: u64) -> Avp {
+        Avp::new(super::code::CC_OUTPUT_OCTETS, AvpFlags::M, None, ::diameter_interface::modeling::avp::unsigned64::Unsigned64::new(value))
+    }
+
+    /// Returns the first CC-Output-Octets AVP in `avps`.
+    pub fn get_cc_output_octets(avps: &[Avp]) -> Option<u64> {
+        find(avps, super::code::CC_OUTPUT_OCTETS, None).and_then(|avp| avp.value().as_u64())
+    }
+
+    /// Builds a CC-Request-Number AVP.
+    pub fn cc_request_number(value: u32) -> Avp {
+        Avp::new(super::code::CC_REQUEST_NUMBER, AvpFlags::M, None, ::diameter_interface::modeling::avp::unsigned32::Unsigned32::new(value))
+    }
+
+    /// Returns the first CC-Request-Number AVP in `avps`.
+    pub fn get_cc_request_number(avps: &[Avp]) -> Option<u32> {
+        find(avps, super::code::CC_REQUEST_NUMBER, None).and_then(|avp| avp.value().as_u32())
+    }
+
+    /// Builds a CC-Request-Type AVP.
+    pub fn cc_request_type(value: super::enums::CcRequestType) -> Avp {
+        Avp::new(super::code::CC_REQUEST_TYPE, AvpFlags::M, None, ::diameter_interface::modeling::avp::enumerated::Enumerated::new(value as i32))
+    }
+
+    /// Returns the first CC-Request-Type AVP in `avps`.
+    pub fn get_cc_request_type(avps: &[Avp]) -> Option<super::enums::CcRequestType> {
+        find(avps, super::code::CC_REQUEST_TYPE, None).and_then(|avp| avp.value().as_i32().and_then(|value| value.try_into().ok()))
+    }
+
+    /// Builds a CC-Service-Specific-Units AVP.
+    pub fn cc_service_specific_units(value: u64) -> Avp {
+        Avp::new(super::code::CC_SERVICE_SPECIFIC_UNITS, AvpFlags::M, None, ::diameter_interface::modeling::avp::unsigned64::Unsigned64::new(value))
+    }
+
+    /// Returns the first CC-Service-Specific-Units AVP in `avps`.
+    pub fn get_cc_service_specific_units(avps: &[Avp]) -> Option<u64> {
+        find(avps, super::code::CC_SERVICE_SPECIFIC_UNITS, None).and_then(|avp| avp.value().as_u64())
+    }
+
+    /// Builds a CC-Session-Failover AVP.
+    pub fn cc_session_failover(value: super::enums::CcSessionFailover) -> Avp {
+        Avp::new(super::code::CC_SESSION_FAILOVER, AvpFlags::M, None, ::diameter_interface::modeling::avp::enumerated::Enumerated::new(value as i32))
+    }
+
+    /// Returns the first CC-Session-Failover AVP in `avps`.
+    pub fn get_cc_session_failover(avps: &[Avp]) -> Option<super::enums::CcSessionFailover> {
+        find(avps, super::code::CC_SESSION_FAILOVER, None).and_then(|avp| avp.value().as_i32().and_then(|value| value.try_into().ok()))
+    }
+
+    /// Builds a CC-Sub-Session-Id AVP.
+    pub fn cc_sub_session_id(value: u64) -> Avp {
+        Avp::new(super::code::CC_SUB_SESSION_ID, AvpFlags::M, None, ::diameter_interface::modeling::avp::unsigned64::Unsigned64::new(value))
+    }
+
+    /// Returns the first CC-Sub-Session-Id AVP in `avps`.
+    pub fn get_cc_sub_session_id(avps: &[Avp]) -> Option<u64> {
+        find(avps, super::code::CC_SUB_SESSION_ID, None).and_then(|avp| avp.value().as_u64())
+    }
+
+    /// Builds a CC-Time AVP.
+    pub fn cc_time(value: u32) -> Avp {
+        Avp::new(super::code::CC_TIME, AvpFlags::M, None, ::diameter_interface::modeling::avp::unsigned32::Unsigned32::new(value))
+    }
+
+    /// Returns the first CC-Time AVP in `avps`.
+    pub fn get_cc_time(avps: &[Avp]) -> Option<u32> {
+        find(avps, super::code::CC_TIME, None).and_then(|avp| avp.value().as_u32())
+    }
+
+    /// Builds a CC-Total-Octets AVP.
+    pub fn cc_total_octets(value: u64) -> Avp {
+        Avp::new(super::code::CC_TOTAL_OCTETS, AvpFlags::M, None, ::diameter_interface::modeling::avp::unsigned64::Unsigned64::new(value))
+    }
+
+    /// Returns the first CC-Total-Octets AVP in `avps`.
+    pub fn get_cc_total_octets(avps: &[Avp]) -> Option<u64> {
+        find(avps, super::code::CC_TOTAL_OCTETS, None).and_then(|avp| avp.value().as_u64())
+    }
+
+    /// Builds a Check-Balance-Result AVP.
+    pub fn check_balance_result(value: super::enums::CheckBalanceResult) -> Avp {
+        Avp::new(super::code::CHECK_BALANCE_RESULT, AvpFlags::M, None, ::diameter_interface::modeling::avp::enumerated::Enumerated::new(value as i32))
+    }
+
+    /// Returns the first Check-Balance-Result AVP in `avps`.
+    pub fn get_check_balance_result(avps: &[Avp]) -> Option<super::enums::CheckBalanceResult> {
+        find(avps, super::code::CHECK_BALANCE_RESULT, None).and_then(|avp| avp.value().as_i32().and_then(|value| value.try_into().ok()))
+    }
+
+    /// Builds a Cost-Information AVP.
+    pub fn cost_information(value: Vec<Avp>) -> Avp {
+        Avp::new(super::code::COST_INFORMATION, AvpFlags::M, None, ::diameter_interface::modeling::avp::group::Grouped::new(value))
+    }
+
+    /// Returns the first Cost-Information AVP in `avps`.
+    pub fn get_cost_information(avps: &[Avp]) -> Option<&[Avp]> {
+        find(avps, super::code::COST_INFORMATION, None).and_then(|avp| avp.value().as_grouped())
+    }
+
+    /// Builds a Cost-Unit AVP.
+    pub fn cost_unit(value: impl Into<String>) -> Avp {
+        Avp::new(super::code::COST_UNIT, AvpFlags::M, None, ::diameter_interface::modeling::avp::utf8_string::UTF8String::new(value.into()))
+    }
+
+    /// Returns the first Cost-Unit AVP in `avps`.
+    pub fn get_cost_unit(avps: &[Avp]) -> Option<&str> {
+        find(avps, super::code::COST_UNIT, None).and_then(|avp| avp.value().as_str())
+    }
+
+    /// Builds a Currency-Code AVP.
+    pub fn currency_code(value: u32) -> Avp {
+        Avp::new(super::code::CURRENCY_CODE, AvpFlags::M, None, ::diameter_interface::modeling::avp::unsigned32::Unsigned32::new(value))
+    }
+
+    /// Returns the first Currency-Code AVP in `avps`.
+    pub fn get_currency_code(avps: &[Avp]) -> Option<u32> {
+        find(avps, super::code::CURRENCY_CODE, None).and_then(|avp| avp.value().as_u32())
+    }
+
+    /// Builds a Credit-Control AVP.
+    pub fn credit_control(value: super::enums::CreditControl) -> Avp {
+        Avp::new(super::code::CREDIT_CONTROL, AvpFlags::M, None, ::diameter_interface::modeling::avp::enumerated::Enumerated::new(value as i32))
+    }
+
+    /// Returns the first Credit-Control AVP in `avps`.
+    pub fn get_credit_control(avps: &[Avp]) -> Option<super::enums::CreditControl> {
+        find(avps, super::code::CREDIT_CONTROL, None).and_then(|avp| avp.value().as_i32().and_then(|value| value.try_into().ok()))
+    }
+
+    /// Builds a Credit-Control-Failure-Handling AVP.
+    pub fn credit_control_failure_handling(value: super::enums::CreditControlFailureHandling) -> Avp {
+        Avp::new(super::code::CREDIT_CONTROL_FAILURE_HANDLING, AvpFlags::M, None, ::diameter_interface::modeling::avp::enumerated::Enumerated::new(value as i32))
+    }
+
+    /// Returns the first Credit-Control-Failure-Handling AVP in `avps`.
+    pub fn get_credit_control_failure_handling(avps: &[Avp]) -> Option<super::enums::CreditControlFailureHandling> {
+        find(avps, super::code::CREDIT_CONTROL_FAILURE_HANDLING, None).and_then(|avp| avp.value().as_i32().and_then(|value| value.try_into().ok()))
+    }
+
+    /// Builds a Direct-Debiting-Failure-Handling AVP.
+    pub fn direct_debiting_failure_handling(value: super::enums::DirectDebitingFailureHandling) -> Avp {
+        Avp::new(super::code::DIRECT_DEBITING_FAILURE_HANDLING, AvpFlags::M, None, ::diameter_interface::modeling::avp::enumerated::Enumerated::new(value as i32))
+    }
+
+    /// Returns the first Direct-Debiting-Failure-Handling AVP in `avps`.
+    pub fn get_direct_debiting_failure_handling(avps: &[Avp]) -> Option<super::enums::DirectDebitingFailureHandling> {
+        find(avps, super::code::DIRECT_DEBITING_FAILURE_HANDLING, None).and_then(|avp| avp.value().as_i32().and_then(|value| value.try_into().ok()))
+    }
+
+    /// Builds a Exponent AVP.
+    pub fn exponent(value: i32) -> Avp {
+        Avp::new(super::code::EXPONENT, AvpFlags::M, None, ::diameter_interface::modeling::avp::integer32::Integer32::new(value))
+    }
+
+    /// Returns the first Exponent AVP in `avps`.
+    pub fn get_exponent(avps: &[Avp]) -> Option<i32> {
+        find(avps, super::code::EXPONENT, None).and_then(|avp| avp.value().as_i32())
+    }
+
+    /// Builds a Final-Unit-Indication AVP.
+    pub fn final_unit_indication(value: Vec<Avp>) -> Avp {
+        Avp::new(super::code::FINAL_UNIT_INDICATION, AvpFlags::M, None, ::diameter_interface::modeling::avp::group::Grouped::new(value))
+    }
+
+    /// Returns the first Final-Unit-Indication AVP in `avps`.
+    pub fn get_final_unit_indication(avps: &[Avp]) -> Option<&[Avp]> {
+        find(avps, super::code::FINAL_UNIT_INDICATION, None).and_then(|avp| avp.value().as_grouped())
+    }
+
+    /// Builds a Granted-Service-Unit AVP.
+    pub fn granted_service_unit(value: Vec<Avp>) -> Avp {
+        Avp::new(super::code::GRANTED_SERVICE_UNIT, AvpFlags::M, None, ::diameter_interface::modeling::avp::group::Grouped::new(value))
+    }
+
+    /// Returns the first Granted-Service-Unit AVP in `avps`.
+    pub fn get_granted_service_unit(avps: &[Avp]) -> Option<&[Avp]> {
+        find(avps, super::code::GRANTED_SERVICE_UNIT, None).and_then(|avp| avp.value().as_grouped())
+    }
+
+    /// Builds a Rating-Group AVP.
+    pub fn rating_group(value: u32) -> Avp {
+        Avp::new(super::code::RATING_GROUP, AvpFlags::M, None, ::diameter_interface::modeling::avp::unsigned32::Unsigned32::new(value))
+    }
+
+    /// Returns the first Rating-Group AVP in `avps`.
+    pub fn get_rating_group(avps: &[Avp]) -> Option<u32> {
+        find(avps, super::code::RATING_GROUP, None).and_then(|avp| avp.value().as_u32())
+    }
+
+    /// Builds a Redirect-Address-Type AVP.
+    pub fn redirect_address_type(value: super::enums::RedirectAddressType) -> Avp {
+        Avp::new(super::code::REDIRECT_ADDRESS_TYPE, AvpFlags::M, None, ::diameter_interface::modeling::avp::enumerated::Enumerated::new(value as i32))
+    }
+
+    /// Returns the first Redirect-Address-Type AVP in `avps`.
+    pub fn get_redirect_address_type(avps: &[Avp]) -> Option<super::enums::RedirectAddressType> {
+        find(avps, super::code::REDIRECT_ADDRESS_TYPE, None).and_then(|avp| avp.value().as_i32().and_then(|value| value.try_into().ok()))
+    }
+
+    /// Builds a Redirect-Server AVP.
+    pub fn redirect_server(value: Vec<Avp>) -> Avp {
+        Avp::new(super::code::REDIRECT_SERVER, AvpFlags::M, None, ::diameter_interface::modeling::avp::group::Grouped::new(value))
+    }
+
+    /// Returns the first Redirect-Server AVP in `avps`.
+    pub fn get_redirect_server(avps: &[Avp]) -> Option<&[Avp]> {
+        find(avps, super::code::REDIRECT_SERVER, None).and_then(|avp| avp.value().as_grouped())
+    }
+
+    /// Builds a Redirect-Server-Address AVP.
+    pub fn redirect_server_address(value: impl Into<String>) -> Avp {
+        Avp::new(super::code::REDIRECT_SERVER_ADDRESS, AvpFlags::M, None, ::diameter_interface::modeling::avp::utf8_string::UTF8String::new(value.into()))
+    }
+
+    /// Returns the first Redirect-Server-Address AVP in `avps`.
+    pub fn get_redirect_server_address(avps: &[Avp]) -> Option<&str> {
+        find(avps, super::code::REDIRECT_SERVER_ADDRESS, None).and_then(|avp| avp.value().as_str())
+    }
+
+    /// Builds a Requested-Action AVP.
+    pub fn requested_action(value: super::enums::RequestedAction) -> Avp {
+        Avp::new(super::code::REQUESTED_ACTION, AvpFlags::M, None, ::diameter_interface::modeling::avp::enumerated::Enumerated::new(value as i32))
+    }
+
+    /// Returns the first Requested-Action AVP in `avps`.
+    pub fn get_requested_action(avps: &[Avp]) -> Option<super::enums::RequestedAction> {
+        find(avps, super::code::REQUESTED_ACTION, None).and_then(|avp| avp.value().as_i32().and_then(|value| value.try_into().ok()))
+    }
+
+    /// Builds a Requested-Service-Unit AVP.
+    pub fn requested_service_unit(value: Vec<Avp>) -> Avp {
+        Avp::new(super::code::REQUESTED_SERVICE_UNIT, AvpFlags::M, None, ::diameter_interface::modeling::avp::group::Grouped::new(value))
+    }
+
+    /// Returns the first Requested-Service-Unit AVP in `avps`.
+    pub fn get_requested_service_unit(avps: &[Avp]) -> Option<&[Avp]> {
+        find(avps, super::code::REQUESTED_SERVICE_UNIT, None).and_then(|avp| avp.value().as_grouped())
+    }
+
+    /// Builds a Restriction-Filter-Rule AVP.
+    pub fn restriction_filter_rule(value: impl Into<Vec<u8>>) -> Avp {
+        Avp::new(super::code::RESTRICTION_FILTER_RULE, AvpFlags::M, None, ::diameter_interface::modeling::avp::octet_string::OctetString::new(value.into()))
+    }
+
+    /// Returns the first Restriction-Filter-Rule AVP in `avps`.
+    pub fn get_restriction_filter_rule(avps: &[Avp]) -> Option<&[u8]> {
+        find(avps, super::code::RESTRICTION_FILTER_RULE, None).and_then(|avp| avp.value().as_bytes())
+    }
+
+    /// Builds a Service-Identifier AVP.
+    pub fn service_identifier(value: u32) -> Avp {
+        Avp::new(super::code::SERVICE_IDENTIFIER, AvpFlags::M, None, ::diameter_interface::modeling::avp::unsigned32::Unsigned32::new(value))
+    }
+
+    /// Returns the first Service-Identifier AVP in `avps`.
+    pub fn get_service_identifier(avps: &[Avp]) -> Option<u32> {
+        find(avps, super::code::SERVICE_IDENTIFIER, None).and_then(|avp| avp.value().as_u32())
+    }
+
+    /// Builds a Service-Parameter-Info AVP.
+    pub fn service_parameter_info(value: Vec<Avp>) -> Avp {
+        Avp::new(super::code::SERVICE_PARAMETER_INFO, AvpFlags::O, None, ::diameter_interface::modeling::avp::group::Grouped::new(value))
+    }
+
+    /// Returns the first Service-Parameter-Info AVP in `avps`.
+    pub fn get_service_parameter_info(avps: &[Avp]) -> Option<&[Avp]> {
+        find(avps, super::code::SERVICE_PARAMETER_INFO, None).and_then(|avp| avp.value().as_grouped())
+    }
+
+    /// Builds a Service-Parameter-Type AVP.
+    pub fn service_parameter_type(value: u32) -> Avp {
+        Avp::new(super::code::SERVICE_PARAMETER_TYPE, AvpFlags::O, None, ::diameter_interface::modeling::avp::unsigned32::Unsigned32::new(value))
+    }
+
+    /// Returns the first Service-Parameter-Type AVP in `avps`.
+    pub fn get_service_parameter_type(avps: &[Avp]) -> Option<u32> {
+        find(avps, super::code::SERVICE_PARAMETER_TYPE, None).and_then(|avp| avp.value().as_u32())
+    }
+
+    /// Builds a Service-Parameter-Value AVP.
+    pub fn service_parameter_value(value: impl Into<Vec<u8>>) -> Avp {
+        Avp::new(super::code::SERVICE_PARAMETER_VALUE, AvpFlags::O, None, ::diameter_interface::modeling::avp::octet_string::OctetString::new(value.into()))
+    }
+
+    /// Returns the first Service-Parameter-Value AVP in `avps`.
+    pub fn get_service_parameter_value(avps: &[Avp]) -> Option<&[u8]> {
+        find(avps, super::code::SERVICE_PARAMETER_VALUE, None).and_then(|avp| avp.value().as_bytes())
+    }
+
+    /// Builds a Subscription-Id AVP.
+    pub fn subscription_id(value: Vec<Avp>) -> Avp {
+        Avp::new(super::code::SUBSCRIPTION_ID, AvpFlags::M, None, ::diameter_interface::modeling::avp::group::Grouped::new(value))
+    }
+
+    /// Returns the first Subscription-Id AVP in `avps`.
+    pub fn get_subscription_id(avps: &[Avp]) -> Option<&[Avp]> {
+        find(avps, super::code::SUBSCRIPTION_ID, None).and_then(|avp| avp.value().as_grouped())
+    }
+
+    /// Builds a Subscription-Id-Data AVP.
+    pub fn subscription_id_data(value: impl Into<String>) -> Avp {
+        Avp::new(super::code::SUBSCRIPTION_ID_DATA, AvpFlags::M, None, ::diameter_interface::modeling::avp::utf8_string::UTF8String::new(value.into()))
+    }
+
+    /// Returns the first Subscription-Id-Data AVP in `avps`.
+    pub fn get_subscription_id_data(avps: &[Avp]) -> Option<&str> {
+        find(avps, super::code::SUBSCRIPTION_ID_DATA, None).and_then(|avp| avp.value().as_str())
+    }
+
+    /// Builds a Unit-Value AVP.
+    pub fn unit_value(value: Vec<Avp>) -> Avp {
+        Avp::new(super::code::UNIT_VALUE, AvpFlags::M, None, ::diameter_interface::modeling::avp::group::Grouped::new(value))
+    }
+
+    /// Returns the first Unit-Value AVP in `avps`.
+    pub fn get_unit_value(avps: &[Avp]) -> Option<&[Avp]> {
+        find(avps, super::code::UNIT_VALUE, None).and_then(|avp| avp.value().as_grouped())
+    }
+
+    /// Builds a Used-Service-Unit AVP.
+    pub fn used_service_unit(value: Vec<Avp>) -> Avp {
+        Avp::new(super::code::USED_SERVICE_UNIT, AvpFlags::M, None, ::diameter_interface::modeling::avp::group::Grouped::new(value))
+    }
+
+    /// Returns the first Used-Service-Unit AVP in `avps`.
+    pub fn get_used_service_unit(avps: &[Avp]) -> Option<&[Avp]> {
+        find(avps, super::code::USED_SERVICE_UNIT, None).and_then(|avp| avp.value().as_grouped())
+    }
+
+    /// Builds a Value-Digits AVP.
+    pub fn value_digits(value: i64) -> Avp {
+        Avp::new(super::code::VALUE_DIGITS, AvpFlags::M, None, ::diameter_interface::modeling::avp::integer64::Integer64::new(value))
+    }
+
+    /// Returns the first Value-Digits AVP in `avps`.
+    pub fn get_value_digits(avps: &[Avp]) -> Option<i64> {
+        find(avps, super::code::VALUE_DIGITS, None).and_then(|avp| avp.value().as_i64())
+    }
+
+    /// Builds a Validity-Time AVP.
+    pub fn validity_time(value: u32) -> Avp {
+        Avp::new(super::code::VALIDITY_TIME, AvpFlags::M, None, ::diameter_interface::modeling::avp::unsigned32::Unsigned32::new(value))
+    }
+
+    /// Returns the first Validity-Time AVP in `avps`.
+    pub fn get_validity_time(avps: &[Avp]) -> Option<u32> {
+        find(avps, super::code::VALIDITY_TIME, None).and_then(|avp| avp.value().as_u32())
+    }
+
+    /// Builds a Final-Unit-Action AVP.
+    pub fn final_unit_action(value: super::enums::FinalUnitAction) -> Avp {
+        Avp::new(super::code::FINAL_UNIT_ACTION, AvpFlags::M, None, ::diameter_interface::modeling::avp::enumerated::Enumerated::new(value as i32))
+    }
+
+    /// Returns the first Final-Unit-Action AVP in `avps`.
+    pub fn get_final_unit_action(avps: &[Avp]) -> Option<super::enums::FinalUnitAction> {
+        find(avps, super::code::FINAL_UNIT_ACTION, None).and_then(|avp| avp.value().as_i32().and_then(|value| value.try_into().ok()))
+    }
+
+    /// Builds a Subscription-Id-Type AVP.
+    pub fn subscription_id_type(value: super::enums::SubscriptionIdType) -> Avp {
+        Avp::new(super::code::SUBSCRIPTION_ID_TYPE, AvpFlags::M, None, ::diameter_interface::modeling::avp::enumerated::Enumerated::new(value as i32))
+    }
+
+    /// Returns the first Subscription-Id-Type AVP in `avps`.
+    pub fn get_subscription_id_type(avps: &[Avp]) -> Option<super::enums::SubscriptionIdType> {
+        find(avps, super::code::SUBSCRIPTION_ID_TYPE, None).and_then(|avp| avp.value().as_i32().and_then(|value| value.try_into().ok()))
+    }
+
+    /// Builds a Tariff-Time-Change AVP.
+    pub fn tariff_time_change(value: ::diameter_interface::modeling::avp::time::DateTime<::diameter_interface::modeling::avp::time::Utc>) -> Avp {
+        Avp::new(super::code::TARIFF_TIME_CHANGE, AvpFlags::M, None, ::diameter_interface::modeling::avp::time::Time::new(value))
+    }
+
+    /// Returns the first Tariff-Time-Change AVP in `avps`.
+    pub fn get_tariff_time_change(avps: &[Avp]) -> Option<&::diameter_interface::modeling::avp::time::DateTime<::diameter_interface::modeling::avp::time::Utc>> {
+        find(avps, super::code::TARIFF_TIME_CHANGE, None).and_then(|avp| avp.value().as_time())
+    }
+
+    /// Builds a Tariff-Change-Usage AVP.
+    pub fn tariff_change_usage(value: super::enums::TariffChangeUsage) -> Avp {
+        Avp::new(super::code::TARIFF_CHANGE_USAGE, AvpFlags::M, None, ::diameter_interface::modeling::avp::enumerated::Enumerated::new(value as i32))
+    }
+
+    /// Returns the first Tariff-Change-Usage AVP in `avps`.
+    pub fn get_tariff_change_usage(avps: &[Avp]) -> Option<super::enums::TariffChangeUsage> {
+        find(avps, super::code::TARIFF_CHANGE_USAGE, None).and_then(|avp| avp.value().as_i32().and_then(|value| value.try_into().ok()))
+    }
+
+    /// Builds a G-S-U-Pool-Identifier AVP.
+    pub fn g_s_u_pool_identifier(value: u32) -> Avp {
+        Avp::new(super::code::G_S_U_POOL_IDENTIFIER, AvpFlags::M, None, ::diameter_interface::modeling::avp::unsigned32::Unsigned32::new(value))
+    }
+
+    /// Returns the first G-S-U-Pool-Identifier AVP in `avps`.
+    pub fn get_g_s_u_pool_identifier(avps: &[Avp]) -> Option<u32> {
+        find(avps, super::code::G_S_U_POOL_IDENTIFIER, None).and_then(|avp| avp.value().as_u32())
+    }
+
+    /// Builds a CC-Unit-Type AVP.
+    pub fn cc_unit_type(value: super::enums::CcUnitType) -> Avp {
+        Avp::new(super::code::CC_UNIT_TYPE, AvpFlags::M, None, ::diameter_interface::modeling::avp::enumerated::Enumerated::new(value as i32))
+    }
+
+    /// Returns the first CC-Unit-Type AVP in `avps`.
+    pub fn get_cc_unit_type(avps: &[Avp]) -> Option<super::enums::CcUnitType> {
+        find(avps, super::code::CC_UNIT_TYPE, None).and_then(|avp| avp.value().as_i32().and_then(|value| value.try_into().ok()))
+    }
+
+    /// Builds a Multiple-Services-Indicator AVP.
+    pub fn multiple_services_indicator(value: super::enums::MultipleServicesIndicator) -> Avp {
+        Avp::new(super::code::MULTIPLE_SERVICES_INDICATOR, AvpFlags::M, None, ::diameter_interface::modeling::avp::enumerated::Enumerated::new(value as i32))
+    }
+
+    /// Returns the first Multiple-Services-Indicator AVP in `avps`.
+    pub fn get_multiple_services_indicator(avps: &[Avp]) -> Option<super::enums::MultipleServicesIndicator> {
+        find(avps, super::code::MULTIPLE_SERVICES_INDICATOR, None).and_then(|avp| avp.value().as_i32().and_then(|value| value.try_into().ok()))
+    }
+
+    /// Builds a Multiple-Services-Credit-Control AVP.
+    pub fn multiple_services_credit_control(value: Vec<Avp>) -> Avp {
+        Avp::new(super::code::MULTIPLE_SERVICES_CREDIT_CONTROL, AvpFlags::M, None, ::diameter_interface::modeling::avp::group::Grouped::new(value))
+    }
+
+    /// Returns the first Multiple-Services-Credit-Control AVP in `avps`.
+    pub fn get_multiple_services_credit_control(avps: &[Avp]) -> Option<&[Avp]> {
+        find(avps, super::code::MULTIPLE_SERVICES_CREDIT_CONTROL, None).and_then(|avp| avp.value().as_grouped())
+    }
+
+    /// Builds a G-S-U-Pool-Reference AVP.
+    pub fn g_s_u_pool_reference(value: Vec<Avp>) -> Avp {
+        Avp::new(super::code::G_S_U_POOL_REFERENCE, AvpFlags::M, None, ::diameter_interface::modeling::avp::group::Grouped::new(value))
+    }
+
+    /// Returns the first G-S-U-Pool-Reference AVP in `avps`.
+    pub fn get_g_s_u_pool_reference(avps: &[Avp]) -> Option<&[Avp]> {
+        find(avps, super::code::G_S_U_POOL_REFERENCE, None).and_then(|avp| avp.value().as_grouped())
+    }
+
+    /// Builds a User-Equipment-Info AVP.
+    pub fn user_equipment_info(value: Vec<Avp>) -> Avp {
+        Avp::new(super::code::USER_EQUIPMENT_INFO, AvpFlags::O, None, ::diameter_interface::modeling::avp::group::Grouped::new(value))
+    }
+
+    /// Returns the first User-Equipment-Info AVP in `avps`.
+    pub fn get_user_equipment_info(avps: &[Avp]) -> Option<&[Avp]> {
+        find(avps, super::code::USER_EQUIPMENT_INFO, None).and_then(|avp| avp.value().as_grouped())
+    }
+
+    /// Builds a User-Equipment-Info-Type AVP.
+    pub fn user_equipment_info_type(value: super::enums::UserEquipmentInfoType) -> Avp {
+        Avp::new(super::code::USER_EQUIPMENT_INFO_TYPE, AvpFlags::O, None, ::diameter_interface::modeling::avp::enumerated::Enumerated::new(value as i32))
+    }
+
+    /// Returns the first User-Equipment-Info-Type AVP in `avps`.
+    pub fn get_user_equipment_info_type(avps: &[Avp]) -> Option<super::enums::UserEquipmentInfoType> {
+        find(avps, super::code::USER_EQUIPMENT_INFO_TYPE, None).and_then(|avp| avp.value().as_i32().and_then(|value| value.try_into().ok()))
+    }
+
+    /// Builds a User-Equipment-Info-Value AVP.
+    pub fn user_equipment_info_value(value: impl Into<Vec<u8>>) -> Avp {
+        Avp::new(super::code::USER_EQUIPMENT_INFO_VALUE, AvpFlags::O, None, ::diameter_interface::modeling::avp::octet_string::OctetString::new(value.into()))
+    }
+
+    /// Returns the first User-Equipment-Info-Value AVP in `avps`.
+    pub fn get_user_equipment_info_value(avps: &[Avp]) -> Option<&[u8]> {
+        find(avps, super::code::USER_EQUIPMENT_INFO_VALUE, None).and_then(|avp| avp.value().as_bytes())
+    }
+
+    /// Builds a Service-Context-Id AVP.
+    pub fn service_context_id(value: impl Into<String>) -> Avp {
+        Avp::new(super::code::SERVICE_CONTEXT_ID, AvpFlags::M, None, ::diameter_interface::modeling::avp::utf8_string::UTF8String::new(value.into()))
+    }
+
+    /// Returns the first Service-Context-Id AVP in `avps`.
+    pub fn get_service_context_id(avps: &[Avp]) -> Option<&str> {
+        find(avps, super::code::SERVICE_CONTEXT_ID, None).and_then(|avp| avp.value().as_str())
+    }
+
+    /// Builds a Accounting-Record-Type AVP.
+    pub fn accounting_record_type(value: super::enums::AccountingRecordType) -> Avp {
+        Avp::new(super::code::ACCOUNTING_RECORD_TYPE, AvpFlags::M, None, ::diameter_interface::modeling::avp::enumerated::Enumerated::new(value as i32))
+    }
+
+    /// Returns the first Accounting-Record-Type AVP in `avps`.
+    pub fn get_accounting_record_type(avps: &[Avp]) -> Option<super::enums::AccountingRecordType> {
+        find(avps, super::code::ACCOUNTING_RECORD_TYPE, None).and_then(|avp| avp.value().as_i32().and_then(|value| value.try_into().ok()))
+    }
+
+    /// Builds a Accounting-Realtime-Required AVP.
+    pub fn accounting_realtime_required(value: super::enums::AccountingRealtimeRequired) -> Avp {
+        Avp::new(super::code::ACCOUNTING_REALTIME_REQUIRED, AvpFlags::M, None, ::diameter_interface::modeling::avp::enumerated::Enumerated::new(value as i32))
+    }
+
+    /// Returns the first Accounting-Realtime-Required AVP in `avps`.
+    pub fn get_accounting_realtime_required(avps: &[Avp]) -> Option<super::enums::AccountingRealtimeRequired> {
+        find(avps, super::code::ACCOUNTING_REALTIME_REQUIRED, None).and_then(|avp| avp.value().as_i32().and_then(|value| value.try_into().ok()))
+    }
+
+    /// Builds a Accounting-Record-Number AVP.
+    pub fn accounting_record_number(value: u32) -> Avp {
+        Avp::new(super::code::ACCOUNTING_RECORD_NUMBER, AvpFlags::M, None, ::diameter_interface::modeling::avp::unsigned32::Unsigned32::new(value))
+    }
+
+    /// Returns the first Accounting-Record-Number AVP in `avps`.
+    pub fn get_accounting_record_number(avps: &[Avp]) -> Option<u32> {
+        find(avps, super::code::ACCOUNTING_RECORD_NUMBER, None).and_then(|avp| avp.value().as_u32())
+    }
+
+    /// Builds a OC-Supported-Features AVP.
+    pub fn oc_supported_features(value: Vec<Avp>) -> Avp {
+        Avp::new(super::code::OC_SUPPORTED_FEATURES, AvpFlags::O, None, ::diameter_interface::modeling::avp::group::Grouped::new(value))
+    }
+
+    /// Returns the first OC-Supported-Features AVP in `avps`.
+    pub fn get_oc_supported_features(avps: &[Avp]) -> Option<&[Avp]> {
+        find(avps, super::code::OC_SUPPORTED_FEATURES, None).and_then(|avp| avp.value().as_grouped())
+    }
+
+    /// Builds a OC-Feature-Vector AVP.
+    pub fn oc_feature_vector(value: u64) -> Avp {
+        Avp::new(super::code::OC_FEATURE_VECTOR, AvpFlags::O, None, ::diameter_interface::modeling::avp::unsigned64::Unsigned64::new(value))
+    }
+
+    /// Returns the first OC-Feature-Vector AVP in `avps`.
+    pub fn get_oc_feature_vector(avps: &[Avp]) -> Option<u64> {
+        find(avps, super::code::OC_FEATURE_VECTOR, None).and_then(|avp| avp.value().as_u64())
+    }
+
+    /// Builds a OC-OLR AVP.
+    pub fn oc_olr(value: Vec<Avp>) -> Avp {
+        Avp::new(super::code::OC_OLR, AvpFlags::O, None, ::diameter_interface::modeling::avp::group::Grouped::new(value))
+    }
+
+    /// Returns the first OC-OLR AVP in `avps`.
+    pub fn get_oc_olr(avps: &[Avp]) -> Option<&[Avp]> {
+        find(avps, super::code::OC_OLR, None).and_then(|avp| avp.value().as_grouped())
+    }
+
+    /// Builds a OC-Sequence-Number AVP.
+    pub fn oc_sequence_number(value: u64) -> Avp {
+        Avp::new(super::code::OC_SEQUENCE_NUMBER, AvpFlags::O, None, ::diameter_interface::modeling::avp::unsigned64::Unsigned64::new(value))
+    }
+
+    /// Returns the first OC-Sequence-Number AVP in `avps`.
+    pub fn get_oc_sequence_number(avps: &[Avp]) -> Option<u64> {
+        find(avps, super::code::OC_SEQUENCE_NUMBER, None).and_then(|avp| avp.value().as_u64())
+    }
+
+    /// Builds a OC-Validity-Duration AVP.
+    pub fn oc_validity_duration(value: u32) -> Avp {
+        Avp::new(super::code::OC_VALIDITY_DURATION, AvpFlags::O, None, ::diameter_interface::modeling::avp::unsigned32::Unsigned32::new(value))
+    }
+
+    /// Returns the first OC-Validity-Duration AVP in `avps`.
+    pub fn get_oc_validity_duration(avps: &[Avp]) -> Option<u32> {
+        find(avps, super::code::OC_VALIDITY_DURATION, None).and_then(|avp| avp.value().as_u32())
+    }
+
+    /// Builds a OC-Report-Type AVP.
+    pub fn oc_report_type(value: super::enums::OcReportType) -> Avp {
+        Avp::new(super::code::OC_REPORT_TYPE, AvpFlags::O, None, ::diameter_interface::modeling::avp::enumerated::Enumerated::new(value as i32))
+    }
+
+    /// Returns the first OC-Report-Type AVP in `avps`.
+    pub fn get_oc_report_type(avps: &[Avp]) -> Option<super::enums::OcReportType> {
+        find(avps, super::code::OC_REPORT_TYPE, None).and_then(|avp| avp.value().as_i32().and_then(|value| value.try_into().ok()))
+    }
+
+    /// Builds a OC-Reduction-Percentage AVP.
+    pub fn oc_reduction_percentage(value: u32) -> Avp {
+        Avp::new(super::code::OC_REDUCTION_PERCENTAGE, AvpFlags::O, None, ::diameter_interface::modeling::avp::unsigned32::Unsigned32::new(value))
+    }
+
+    /// Returns the first OC-Reduction-Percentage AVP in `avps`.
+    pub fn get_oc_reduction_percentage(avps: &[Avp]) -> Option<u32> {
+        find(avps, super::code::OC_REDUCTION_PERCENTAGE, None).and_then(|avp| avp.value().as_u32())
+    }
+
+    /// Builds a Timezone-Offset AVP.
+    pub fn timezone_offset(value: i32) -> Avp {
+        Avp::new(super::code::TIMEZONE_OFFSET, AvpFlags::O, Some(10415), ::diameter_interface::modeling::avp::integer32::Integer32::new(value))
+    }
+
+    /// Returns the first Timezone-Offset AVP in `avps`.
+    pub fn get_timezone_offset(avps: &[Avp]) -> Option<i32> {
+        find(avps, super::code::TIMEZONE_OFFSET, Some(10415)).and_then(|avp| avp.value().as_i32())
+    }
+
+    /// Builds a Service-Information AVP.
+    pub fn service_information(value: Vec<Avp>) -> Avp {
+        Avp::new(super::code::SERVICE_INFORMATION, AvpFlags::M, Some(10415), ::diameter_interface::modeling::avp::group::Grouped::new(value))
+    }
+
+    /// Returns the first Service-Information AVP in `avps`.
+    pub fn get_service_information(avps: &[Avp]) -> Option<&[Avp]> {
+        find(avps, super::code::SERVICE_INFORMATION, Some(10415)).and_then(|avp| avp.value().as_grouped())
+    }
+
+    /// Builds a PS-Information AVP.
+    pub fn ps_information(value: Vec<Avp>) -> Avp {
+        Avp::new(super::code::PS_INFORMATION, AvpFlags::M, Some(10415), ::diameter_interface::modeling::avp::group::Grouped::new(value))
+    }
+
+    /// Returns the first PS-Information AVP in `avps`.
+    pub fn get_ps_information(avps: &[Avp]) -> Option<&[Avp]> {
+        find(avps, super::code::PS_INFORMATION, Some(10415)).and_then(|avp| avp.value().as_grouped())
+    }
+}
@@ -0,0 +1,195 @@
+// @generated by diameter_interface::codegen. Do not edit.
+
+#[allow(dead_code)]
+pub mod vendor {
+    pub const _3GPP: u32 = 10415;
+}
+
+#[allow(dead_code)]
+pub mod code {
+    pub const TYPE: u32 = 5000;
+    pub const SELF: u32 = 5001;
+    pub const RESULT: u32 = 5002;
+    pub const FIND: u32 = 5003;
+    pub const GET_FOO: u32 = 5004;
+    pub const FOO: u32 = 5005;
+    pub const TRY_FROM: u32 = 5006;
+    pub const CRATE: u32 = 5007;
+    pub const MATCH: u32 = 5009;
+    pub const SUPER: u32 = 5008;
+}
+
+#[allow(dead_code, clippy::enum_variant_names)]
+pub mod enums {
+    /// Values of the Self AVP.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[repr(i32)]
+    pub enum Self_ {
+        Self_ = 0,
+        Gen = 1,
+        Async = 2,
+        Value3gpp = 3,
+    }
+
+    impl ::core::convert::TryFrom<i32> for Self_ {
+        type Error = i32;
+
+        fn try_from(value: i32) -> ::core::result::Result<Self, Self::Error> {
+            match value {
+                0 => ::core::result::Result::Ok(Self_::Self_),
+                1 => ::core::result::Result::Ok(Self_::Gen),
+                2 => ::core::result::Result::Ok(Self_::Async),
+                3 => ::core::result::Result::Ok(Self_::Value3gpp),
+                _ => ::core::result::Result::Err(value),
+            }
+        }
+    }
+
+    /// Values of the Result AVP.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[repr(i32)]
+    pub enum Result {
+        Ok = 1,
+        Err = 2,
+    }
+
+    impl ::core::convert::TryFrom<i32> for Result {
+        type Error = i32;
+
+        fn try_from(value: i32) -> ::core::result::Result<Self, Self::Error> {
+            match value {
+                1 => ::core::result::Result::Ok(Result::Ok),
+                2 => ::core::result::Result::Ok(Result::Err),
+                _ => ::core::result::Result::Err(value),
+            }
+        }
+    }
+
+    /// Values of the Try-From AVP.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[repr(i32)]
+    pub enum TryFrom {
+        Dyn = 0,
+    }
+
+    impl ::core::convert::TryFrom<i32> for TryFrom {
+        type Error = i32;
+
+        fn try_from(value: i32) -> ::core::result::Result<Self, Self::Error> {
+            match value {
+                0 => ::core::result::Result::Ok(TryFrom::Dyn),
+                _ => ::core::result::Result::Err(value),
+            }
+        }
+    }
+
+}
+
+#[allow(dead_code)]
+pub mod avp {
+    use ::diameter_interface::modeling::avp::avp::{Avp, AvpFlags};
+
+    fn find(avps: &[Avp], code: u32, vendor_id: Option<u32>) -> Option<&Avp> {
+        avps.iter().find(|avp| avp.code() == code && avp.vendor_id() == vendor_id)
+    }
+
+    /// Builds a Type AVP.
+    pub fn type_(value: u32) -> Avp {
+        Avp::new(super::code::TYPE, AvpFlags::M, None, ::diameter_interface::modeling::avp::unsigned32::Unsigned32::new(value))
+    }
+
+    /// Returns the first Type AVP in `avps`.
+    pub fn get_type_(avps: &[Avp]) -> Option<u32> {
+        find(avps, super::code::TYPE, None).and_then(|avp| avp.value().as_u32())
+    }
+
+    /// Builds a Self AVP.
+    pub fn self_(value: super::enums::Self_) -> Avp {
+        Avp::new(super::code::SELF, AvpFlags::M, None, ::diameter_interface::modeling::avp::enumerated::Enumerated::new(value as i32))
+    }
+
+    /// Returns the first Self AVP in `avps`.
+    pub fn get_self_(avps: &[Avp]) -> Option<super::enums::Self_> {
+        find(avps, super::code::SELF, None).and_then(|avp| avp.value().as_i32().and_then(|value| value.try_into().ok()))
+    }
+
+    /// Builds a Result AVP.
+    pub fn result(value: super::enums::Result) -> Avp {
+        Avp::new(super::code::RESULT, AvpFlags::M, None, ::diameter_interface::modeling::avp::enumerated::Enumerated::new(value as i32))
+    }
+
+    /// Returns the first Result AVP in `avps`.
+    pub fn get_result(avps: &[Avp]) -> Option<super::enums::Result> {
+        find(avps, super::code::RESULT, None).and_then(|avp| avp.value().as_i32().and_then(|value| value.try_into().ok()))
+    }
+
+    /// Builds a Find AVP.
+    pub fn find_5003(value: impl Into<String>) -> Avp {
+        Avp::new(super::code::FIND, AvpFlags::M, None, ::diameter_interface::modeling::avp::utf8_string::UTF8String::new(value.into()))
+    }
+
+    /// Returns the first Find AVP in `avps`.
+    pub fn get_find_5003(avps: &[Avp]) -> Option<&str> {
+        find(avps, super::code::FIND, None).and_then(|avp| avp.value().as_str())
+    }
+
+    /// Builds a Get-Foo AVP.
+    pub fn get_foo(value: u32) -> Avp {
+        Avp::new(super::code::GET_FOO, AvpFlags::M, None, ::diameter_interface::modeling::avp::unsigned32::Unsigned32::new(value))
+    }
+
+    /// Returns the first Get-Foo AVP in `avps`.
+    pub fn get_get_foo(avps: &[Avp]) -> Option<u32> {
+        find(avps, super::code::GET_FOO, None).and_then(|avp| avp.value().as_u32())
+    }
+
+    /// Builds a Foo AVP.
+    pub fn foo_5005(value: u32) -> Avp {
+        Avp::new(super::code::FOO, AvpFlags::M, None, ::diameter_interface::modeling::avp::unsigned32::Unsigned32::new(value))
+    }
+
+    /// Returns the first Foo AVP in `avps`.
+    pub fn get_foo_5005(avps: &[Avp]) -> Option<u32> {
+        find(avps, super::code::FOO, None).and_then(|avp| avp.value().as_u32())
+    }
+
+    /// Builds a Try-From AVP.
+    pub fn try_from(value: super::enums::TryFrom) -> Avp {
+        Avp::new(super::code::TRY_FROM, AvpFlags::M, None, ::diameter_interface::modeling::avp::enumerated::Enumerated::new(value as i32))
+    }
+
+    /// Returns the first Try-From AVP in `avps`.
+    pub fn get_try_from(avps: &[Avp]) -> Option<super::enums::TryFrom> {
+        find(avps, super::code::TRY_FROM, None).and_then(|avp| avp.value().as_i32().and_then(|value| value.try_into().ok()))
+    }
+
+    /// Builds a Crate AVP.
+    pub fn crate_(value: impl Into<Vec<u8>>) -> Avp {
+        Avp::new(super::code::CRATE, AvpFlags::M, None, ::diameter_interface::modeling::avp::octet_string::OctetString::new(value.into()))
+    }
+
+    /// Returns the first Crate AVP in `avps`.
+    pub fn get_crate_(avps: &[Avp]) -> Option<&[u8]> {
+        find(avps, super::code::CRATE, None).and_then(|avp| avp.value().as_bytes())
+    }
+
+    /// Builds a Match AVP.
+    pub fn match_(value: ::diameter_interface::modeling::avp::time::DateTime<::diameter_interface::modeling::avp::time::Utc>) -> Avp {
+        Avp::new(super::code::MATCH, AvpFlags::M, None, ::diameter_interface::modeling::avp::time::Time::new(value))
+    }
+
+    /// Returns the first Match AVP in `avps`.
+    pub fn get_match_(avps: &[Avp]) -> Option<&::diameter_interface::modeling::avp::time::DateTime<::diameter_interface::modeling::avp::time::Utc>> {
+        find(avps, super::code::MATCH, None).and_then(|avp| avp.value().as_time())
+    }
+
+    /// Builds a Super AVP.
+    pub fn super_(value: Vec<Avp>) -> Avp {
+        Avp::new(super::code::SUPER, AvpFlags::O, Some(10415), ::diameter_interface::modeling::avp::group::Grouped::new(value))
+    }
+
+    /// Returns the first Super AVP in `avps`.
+    pub fn get_super_(avps: &[Avp]) -> Option<&[Avp]> {
+        find(avps, super::code::SUPER, Some(10415)).and_then(|avp| avp.value().as_grouped())
+    }
+}
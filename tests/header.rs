@@ -0,0 +1,65 @@
+mod common;
+
+use common::{dictionary, encode};
+use diameter_interface::modeling::avp::avp::AvpFlags;
+use diameter_interface::modeling::avp::utf8_string::UTF8String;
+use diameter_interface::modeling::diameter::DiameterMessage;
+use diameter_interface::modeling::message::application_id::ApplicationId;
+use diameter_interface::modeling::message::command_code::CommandCode;
+use diameter_interface::modeling::message::command_flags::CommandFlag;
+
+#[test]
+fn command_code_is_read_without_the_flags() {
+    let mut request = DiameterMessage::new(
+        CommandFlag::Request,
+        CommandCode::CreditControl,
+        ApplicationId::CreditControl,
+        1,
+        2,
+    );
+    request.add_avp(
+        263,
+        AvpFlags::M,
+        None,
+        UTF8String::new("host;1;1".to_string()),
+    );
+    let encoded = encode(&request);
+    assert_eq!(encoded[4..8], [0x80, 0, 1, 16]);
+
+    assert!(DiameterMessage::decode_from(&mut encoded.as_slice(), dictionary()).is_ok());
+}
+
+#[test]
+fn decoded_message_length_counts_each_avp_once() {
+    let mut request = DiameterMessage::new(
+        CommandFlag::Request,
+        CommandCode::CreditControl,
+        ApplicationId::CreditControl,
+        1,
+        2,
+    );
+    request.add_avp(
+        263,
+        AvpFlags::M,
+        None,
+        UTF8String::new("host;1;1".to_string()),
+    );
+    let encoded = encode(&request);
+
+    let decoded = DiameterMessage::decode_from(&mut encoded.as_slice(), dictionary()).unwrap();
+    assert_eq!(encode(&decoded), encoded);
+}
+
+#[test]
+fn unknown_command_code_is_rejected() {
+    let request = DiameterMessage::new(
+        CommandFlag::Request,
+        CommandCode::CreditControl,
+        ApplicationId::CreditControl,
+        1,
+        2,
+    );
+    let mut encoded = encode(&request);
+    encoded[7] = 17; // 273
+    assert!(DiameterMessage::decode_from(&mut encoded.as_slice(), dictionary()).is_err());
+}
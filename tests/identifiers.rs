@@ -0,0 +1,86 @@
+use diameter_interface::modeling::message::identifiers::{
+    EndToEndGenerator, HopByHopGenerator, SessionIdGenerator,
+};
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::thread;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+fn parts(session_id: &str) -> (String, u32, u32) {
+    let mut parts = session_id.split(';');
+    let identity = parts.next().unwrap().to_string();
+    let high = parts.next().unwrap().parse().unwrap();
+    let low = parts.next().unwrap().parse().unwrap();
+    (identity, high, low)
+}
+
+fn now() -> u32 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs() as u32
+}
+
+#[test]
+fn session_id_format() {
+    let before = now();
+    let sessions = SessionIdGenerator::new("client.example.com");
+    let (identity, high, low) = parts(&sessions.next());
+    assert_eq!(identity, "client.example.com");
+    assert!(high >= before && high <= now());
+
+    let (_, next_high, next_low) = parts(&sessions.next());
+    let value = |high: u32, low: u32| (high as u64) << 32 | low as u64;
+    assert_eq!(value(next_high, next_low), value(high, low) + 1);
+
+    let with_optional = sessions.next_with("pdn1");
+    assert!(with_optional.ends_with(";pdn1"));
+    assert_eq!(with_optional.split(';').count(), 4);
+}
+
+#[test]
+fn low_bits_are_random() {
+    // Generators created within the same second must not hand out the same Session-Ids
+    let lows: HashSet<u32> = (0..8)
+        .map(|_| parts(&SessionIdGenerator::new("client.example.com").next()).2)
+        .collect();
+    assert!(lows.len() > 1);
+}
+
+#[test]
+fn low_bits_from_origin_state_id() {
+    let sessions = SessionIdGenerator::new("client.example.com").with_origin_state_id(0xffff_ffff);
+    let (_, high, low) = parts(&sessions.next());
+    assert_eq!(low, 0xffff_ffff);
+    let (_, next_high, next_low) = parts(&sessions.next());
+    assert_eq!((next_high, next_low), (high + 1, 0));
+}
+
+#[test]
+fn unique_across_threads() {
+    let sessions = Arc::new(SessionIdGenerator::new("client.example.com"));
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            let sessions = Arc::clone(&sessions);
+            thread::spawn(move || (0..1000).map(|_| sessions.next()).collect::<Vec<_>>())
+        })
+        .collect();
+    let ids: HashSet<String> = handles
+        .into_iter()
+        .flat_map(|handle| handle.join().unwrap())
+        .collect();
+    assert_eq!(ids.len(), 4000);
+}
+
+#[test]
+fn header_identifiers() {
+    let hop_by_hop = HopByHopGenerator::new();
+    let first = hop_by_hop.next();
+    assert_eq!(hop_by_hop.next(), first.wrapping_add(1));
+
+    let before = now();
+    let end_to_end = EndToEndGenerator::new();
+    let first = end_to_end.next();
+    assert!([before & 0xfff, now() & 0xfff].contains(&(first >> 20)));
+    assert_eq!(end_to_end.next(), first.wrapping_add(1));
+}
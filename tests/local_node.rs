@@ -0,0 +1,165 @@
+mod common;
+
+use common::encode;
+use diameter_interface::modeling::avp::avp::AvpFlags;
+use diameter_interface::modeling::avp::unsigned32::Unsigned32;
+use diameter_interface::modeling::avp::utf8_string::{Identity, UTF8String};
+use diameter_interface::modeling::diameter::DiameterMessage;
+use diameter_interface::modeling::message::application_id::ApplicationId;
+use diameter_interface::modeling::message::command_code::CommandCode;
+use diameter_interface::modeling::message::command_flags::CommandFlag;
+use diameter_interface::modeling::message::result_code::ResultCode;
+use diameter_interface::transport::local_node::LocalNode;
+use std::net::{IpAddr, Ipv4Addr};
+
+fn node() -> LocalNode {
+    LocalNode::new("client.example.com", "example.com")
+        .with_vendor_id(10415)
+        .with_product_name("client")
+        .with_origin_state_id(7)
+        .with_host_ip_address(IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1)))
+}
+
+fn identity(value: &str) -> Identity {
+    Identity::new(value.to_string())
+}
+
+fn values(message: &DiameterMessage, code: u32) -> Vec<String> {
+    message
+        .avps()
+        .iter()
+        .filter(|avp| avp.code() == code)
+        .map(|avp| match avp.value().as_str() {
+            Some(value) => value.to_string(),
+            None => avp.value().as_u32().unwrap().to_string(),
+        })
+        .collect()
+}
+
+fn assert_consistent_length(message: &DiameterMessage) {
+    assert_eq!(encode(message).len(), message.encoded_len());
+}
+
+fn request(command_code: CommandCode) -> DiameterMessage {
+    DiameterMessage::new(
+        CommandFlag::Request,
+        command_code,
+        ApplicationId::Common,
+        1,
+        1,
+    )
+}
+
+#[test]
+fn remove_avps_keeps_the_length() {
+    let mut message = request(CommandCode::DeviceWatchdog);
+    message.add_avp(264, AvpFlags::M, None, identity("a.example.com"));
+    message.add_avp(296, AvpFlags::M, None, identity("example.com"));
+    message.add_avp(264, AvpFlags::M, None, identity("bb.example.com"));
+    message.add_avp(264, AvpFlags::M, Some(10415), identity("vendor"));
+
+    let removed = message.remove_avps(264, None);
+    assert_eq!(removed.len(), 2);
+    assert_eq!(removed[1].value().as_str(), Some("bb.example.com"));
+    let codes: Vec<_> = message
+        .avps()
+        .iter()
+        .map(|avp| (avp.code(), avp.vendor_id()))
+        .collect();
+    assert_eq!(codes, vec![(296, None), (264, Some(10415))]);
+    assert_consistent_length(&message);
+
+    assert!(message.remove_avps(1, None).is_empty());
+    assert_consistent_length(&message);
+}
+
+#[test]
+fn apply_leaves_a_single_occurrence() {
+    let mut message = request(CommandCode::DeviceWatchdog);
+    message.add_avp(264, AvpFlags::M, None, identity("a.example.com"));
+    message.add_avp(278, AvpFlags::M, None, Unsigned32::new(1));
+    message.add_avp(264, AvpFlags::M, None, identity("b.example.com"));
+    message.add_avp(278, AvpFlags::M, None, Unsigned32::new(2));
+
+    node().apply(&mut message);
+    assert_eq!(values(&message, 264), vec!["client.example.com"]);
+    assert_eq!(values(&message, 296), vec!["example.com"]);
+    assert_eq!(values(&message, 278), vec!["7"]);
+    assert_consistent_length(&message);
+
+    let before = encode(&message);
+    node().apply(&mut message);
+    assert_eq!(encode(&message), before);
+}
+
+#[test]
+fn single_occurrence_keeps_its_position() {
+    let mut message = request(CommandCode::DeviceWatchdog);
+    message.add_avp(
+        263,
+        AvpFlags::M,
+        None,
+        UTF8String::new("client.example.com;1;1".to_string()),
+    );
+    message.add_avp(264, AvpFlags::M, None, identity("stale.example.com"));
+    message.add_avp(1, AvpFlags::M, None, UTF8String::new("user".to_string()));
+
+    node().apply(&mut message);
+    let codes: Vec<_> = message.avps().iter().map(|avp| avp.code()).collect();
+    assert_eq!(codes, vec![263, 264, 1, 296, 278]);
+}
+
+#[test]
+fn capabilities_exchange_gets_the_node_capabilities() {
+    let mut cer = request(CommandCode::CapabilitiesExchange);
+    cer.add_avp(266, AvpFlags::M, None, Unsigned32::new(1));
+    cer.add_avp(266, AvpFlags::M, None, Unsigned32::new(2));
+
+    node().apply(&mut cer);
+    assert_eq!(values(&cer, 266), vec!["10415"]);
+    assert_eq!(values(&cer, 269), vec!["client"]);
+    let host_ip_address = cer.get_avp(257, None).unwrap().value().as_ip_addr();
+    assert_eq!(
+        host_ip_address,
+        Some(IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1)))
+    );
+    assert_consistent_length(&cer);
+
+    let mut dwr = request(CommandCode::DeviceWatchdog);
+    node().apply(&mut dwr);
+    assert!(dwr.get_avp(266, None).is_none());
+}
+
+#[test]
+fn answers_from_the_node() {
+    let request = request(CommandCode::DeviceWatchdog);
+    let answer = node().error_answer_for(&request, ResultCode::TOO_BUSY, vec![]);
+    assert!(answer.has_flag(CommandFlag::Error));
+    assert_eq!(values(&answer, 264), vec!["client.example.com"]);
+    assert_eq!(values(&answer, 296), vec!["example.com"]);
+    assert_consistent_length(&answer);
+
+    let answer = node().answer_for(&request);
+    assert!(!answer.is_request());
+    assert_eq!(values(&answer, 278), vec!["7"]);
+}
+
+#[test]
+fn state_file_counts_restarts() {
+    let path = std::env::temp_dir().join(format!("local-node-state-{}", std::process::id()));
+    let temporary = path.with_file_name(format!("local-node-state-{}.tmp", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+
+    let first = node().with_state_file(&path).unwrap();
+    assert_eq!(first.origin_state_id(), 7);
+    let second = node().with_state_file(&path).unwrap();
+    assert_eq!(second.origin_state_id(), 8);
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "8");
+    // The new value is renamed into place, no temporary file is left behind
+    assert!(!temporary.exists());
+
+    std::fs::write(&path, "not a number").unwrap();
+    assert!(node().with_state_file(&path).is_err());
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "not a number");
+    std::fs::remove_file(&path).unwrap();
+}
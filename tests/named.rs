@@ -0,0 +1,191 @@
+mod common;
+
+use common::{encode, encode_avp};
+use diameter_interface::errors::Error;
+use diameter_interface::modeling::avp::avp::{Avp, AvpFlags};
+use diameter_interface::modeling::avp::enumerated::Enumerated;
+use diameter_interface::modeling::avp::integer32::Integer32;
+use diameter_interface::modeling::diameter::DiameterMessage;
+use diameter_interface::modeling::message::application_id::ApplicationId;
+use diameter_interface::modeling::message::command_code::CommandCode;
+use diameter_interface::modeling::message::command_flags::CommandFlag;
+use diameter_interface::modeling::message::dictionary::{self, Dictionary};
+use diameter_interface::modeling::named::{NamedAvp, NamedMessage, NamedValue};
+
+const EXTRA_XML: &str = r#"
+<diameter>
+	<vendor id="10415" name="3GPP"/>
+	<vendor id="5535" name="3GPP2"/>
+	<application id="16777238" name="Gx">
+		<avp name="Timezone-Offset" code="571" must="V" may="P" must-not="M" may-encrypt="Y" vendor-id="10415">
+			<data type="Integer32"/>
+		</avp>
+		<avp name="Timezone-Offset" code="571" must="V" may="P" must-not="M" may-encrypt="Y" vendor-id="5535">
+			<data type="Integer32"/>
+		</avp>
+		<avp name="Bearer-Operation" code="1021" must="M,V" may="P" may-encrypt="Y" vendor-id="10415">
+			<data type="Enumerated">
+				<item code="0" name="TERMINATION"/>
+				<item code="1" name="ESTABLISHMENT"/>
+			</data>
+		</avp>
+		<avp name="Priority-Level" code="1046" must="M,V" may="P" may-encrypt="Y" vendor-id="10415">
+			<data type="Integer32">
+				<item code="1" name="HIGHEST"/>
+				<item code="15" name="LOWEST"/>
+			</data>
+		</avp>
+	</application>
+</diameter>"#;
+
+fn gx_dictionary() -> Dictionary {
+    Dictionary::new(&[&dictionary::DEFAULT_DICT_XML, EXTRA_XML])
+}
+
+fn round_trip(avp: &Avp, dict: &Dictionary) -> (NamedAvp, Avp) {
+    let named = NamedAvp::from_avp(avp, dict);
+    let back = named.to_avp(dict).unwrap();
+    (named, back)
+}
+
+#[test]
+fn enumerated_names() {
+    let dict = gx_dictionary();
+    let avp = Avp::new(1021, AvpFlags::M, Some(10415), Enumerated::new(1));
+    let (named, back) = round_trip(&avp, &dict);
+    assert_eq!(named, NamedAvp::new("Bearer-Operation", "ESTABLISHMENT"));
+    assert_eq!(encode_avp(&back), encode_avp(&avp));
+
+    let numeric = NamedAvp::new("Bearer-Operation", 7).to_avp(&dict).unwrap();
+    assert_eq!(numeric.value().as_i32(), Some(7));
+}
+
+#[test]
+fn integer32_enum_names() {
+    let dict = gx_dictionary();
+    let avp = Avp::new(1046, AvpFlags::M, Some(10415), Integer32::new(15));
+    let (named, back) = round_trip(&avp, &dict);
+    assert_eq!(named, NamedAvp::new("Priority-Level", "LOWEST"));
+    assert_eq!(encode_avp(&back), encode_avp(&avp));
+
+    match NamedAvp::new("Priority-Level", "MEDIUM").to_avp(&dict) {
+        Err(Error::EncodeError("Unknown enumeration name")) => {}
+        other => panic!("unexpected {:?}", other),
+    }
+}
+
+#[test]
+fn names_shared_by_vendors() {
+    let dict = gx_dictionary();
+    assert!(dict.get_unique_avp_by_name("Timezone-Offset").is_none());
+    assert_eq!(dict.get_avps_by_name("Timezone-Offset").count(), 2);
+    assert_eq!(
+        dict.get_avp_by_name_and_vendor("Timezone-Offset", Some(5535))
+            .unwrap()
+            .vendor_id,
+        Some(5535)
+    );
+    assert_eq!(
+        dict.get_avp_by_name("Bearer-Operation").unwrap().vendor_id,
+        Some(10415)
+    );
+
+    for vendor_id in [10415, 5535] {
+        let avp = Avp::new(571, AvpFlags::O, Some(vendor_id), Integer32::new(-4));
+        let (named, back) = round_trip(&avp, &dict);
+        assert_eq!(back.vendor_id(), Some(vendor_id));
+        assert_eq!(encode_avp(&back), encode_avp(&avp));
+        assert_eq!(named.value, NamedValue::Integer(-4));
+        let expected = match vendor_id {
+            10415 => "Timezone-Offset:3GPP",
+            _ => "Timezone-Offset:3GPP2",
+        };
+        assert_eq!(named.name, expected);
+    }
+
+    let by_id = NamedAvp::new("Timezone-Offset:5535", -4)
+        .to_avp(&dict)
+        .unwrap();
+    assert_eq!(by_id.vendor_id(), Some(5535));
+    match NamedAvp::new("Timezone-Offset", -4).to_avp(&dict) {
+        Err(Error::EncodeError("AVP name defined by several vendors")) => {}
+        other => panic!("unexpected {:?}", other),
+    }
+    match NamedAvp::new("Timezone-Offset:ETSI", -4).to_avp(&dict) {
+        Err(Error::EncodeError("Unknown vendor name")) => {}
+        other => panic!("unexpected {:?}", other),
+    }
+    match NamedAvp::new("Timezone-Offset:0", -4).to_avp(&dict) {
+        Err(Error::EncodeError("Unknown AVP name")) => {}
+        other => panic!("unexpected {:?}", other),
+    }
+}
+
+#[test]
+fn unknown_avps_by_code() {
+    let dict = gx_dictionary();
+    let avp = Avp::new(9999, AvpFlags::O, Some(1234), Integer32::new(1));
+    let (named, back) = round_trip(&avp, &dict);
+    assert_eq!(named.name, "9999:1234");
+    assert_eq!(encode_avp(&back), encode_avp(&avp));
+    assert!(NamedAvp::new("No-Such-Avp", 1).to_avp(&dict).is_err());
+}
+
+#[test]
+fn message_round_trip() {
+    let dict = gx_dictionary();
+    let mut message = DiameterMessage::new(
+        CommandFlag::Request,
+        CommandCode::CreditControl,
+        ApplicationId::Gx,
+        5,
+        6,
+    );
+    message.set_flag(CommandFlag::Proxyable);
+    message.add_avp(1021, AvpFlags::M, Some(10415), Enumerated::new(0));
+    message.add_avp(571, AvpFlags::O, Some(5535), Integer32::new(2));
+
+    let named = NamedMessage::from_message(&message, &dict);
+    assert_eq!(named.command, "Credit-Control-Request");
+    assert!(named.proxyable);
+    let back = named.to_message(&dict).unwrap();
+    assert_eq!(encode(&back), encode(&message));
+    assert_eq!(back.application_id(), ApplicationId::Gx);
+    assert_eq!((back.hop_by_hop(), back.end_to_end()), (5, 6));
+}
+
+#[cfg(all(feature = "serde", feature = "json"))]
+#[test]
+fn serde_form_is_lossless() {
+    use diameter_interface::modeling::avp::group::Grouped;
+    use diameter_interface::modeling::avp::octet_string::OctetString;
+    use diameter_interface::modeling::avp::utf8_string::UTF8String;
+
+    let mut message = DiameterMessage::new(
+        CommandFlag::Request,
+        CommandCode::CreditControl,
+        ApplicationId::Gx,
+        1,
+        2,
+    );
+    message.add_avp(
+        263,
+        AvpFlags::M,
+        None,
+        UTF8String::new("host;1;1".to_string()),
+    );
+    message.add_avp(1032, AvpFlags::M, Some(10415), Enumerated::new(1004));
+    message.add_avp(25, AvpFlags::M, None, OctetString::new(vec![0xca, 0xfe]));
+    message.add_avp(
+        443,
+        AvpFlags::M,
+        None,
+        Grouped::new(vec![Avp::new(450, AvpFlags::M, None, Enumerated::new(1))]),
+    );
+
+    let json = serde_json::to_string(&message).unwrap();
+    assert!(json.contains(r#"{"code":263,"flags":64,"value":{"UTF8String":"host;1;1"}}"#));
+    assert!(json.contains(r#""OctetString":"0xcafe""#));
+    let back: DiameterMessage = serde_json::from_str(&json).unwrap();
+    assert_eq!(encode(&back), encode(&message));
+}
@@ -0,0 +1,34 @@
+//! Builds the library without the default `std` feature, as
+//! `cargo build --lib --no-default-features`, so that a `no_std` regression fails the tests.
+
+use std::process::Command;
+
+fn build(extra_args: &[&str]) {
+    let status = Command::new(env!("CARGO"))
+        .args(["build", "--lib", "--no-default-features"])
+        .args(extra_args)
+        .arg("--manifest-path")
+        .arg(concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml"))
+        // A separate target directory, the one of the tests is locked while they run
+        .env(
+            "CARGO_TARGET_DIR",
+            concat!(env!("CARGO_TARGET_TMPDIR"), "/no_std"),
+        )
+        .status()
+        .unwrap();
+    assert!(
+        status.success(),
+        "no_std build failed with {:?}",
+        extra_args
+    );
+}
+
+#[test]
+fn builds_without_std() {
+    build(&[]);
+}
+
+#[test]
+fn builds_without_std_with_bytes() {
+    build(&["--features", "bytes"]);
+}
@@ -0,0 +1,90 @@
+mod common;
+
+use common::{dictionary, encode};
+use diameter_interface::modeling::avp::avp::{Avp, AvpFlags};
+use diameter_interface::modeling::avp::group::Grouped;
+use diameter_interface::modeling::avp::octet_string::OctetString;
+use diameter_interface::modeling::avp::unsigned32::Unsigned32;
+use diameter_interface::modeling::avp::utf8_string::UTF8String;
+use diameter_interface::modeling::diameter::DiameterMessage;
+use diameter_interface::modeling::message::application_id::ApplicationId;
+use diameter_interface::modeling::message::command_code::CommandCode;
+use diameter_interface::modeling::message::command_flags::CommandFlag;
+
+fn message() -> DiameterMessage {
+    DiameterMessage::new(
+        CommandFlag::Request,
+        CommandCode::CreditControl,
+        ApplicationId::CreditControl,
+        1,
+        1,
+    )
+}
+
+/// Decodes the AVPs in `data` one by one and encodes them again.
+fn reencode_avps(mut data: &[u8]) -> Vec<u8> {
+    let mut encoded = vec![];
+    while !data.is_empty() {
+        let avp = Avp::decode_from(&mut data, dictionary()).unwrap();
+        avp.encode_to(&mut encoded).unwrap();
+    }
+    encoded
+}
+
+/// The (code, length) of the AVPs in `data`, checking that each one is padded to 32 bits.
+fn avps(mut data: &[u8]) -> Vec<(u32, usize)> {
+    let mut avps = vec![];
+    while !data.is_empty() {
+        let code = u32::from_be_bytes([data[0], data[1], data[2], data[3]]);
+        let length = u32::from_be_bytes([0, data[5], data[6], data[7]]) as usize;
+        let padded = length.div_ceil(4) * 4;
+        assert!(data[length..padded].iter().all(|octet| *octet == 0));
+        avps.push((code, length));
+        data = &data[padded..];
+    }
+    avps
+}
+
+#[test]
+fn avps_are_padded_to_32_bits() {
+    let mut message = message();
+    for length in 0..=4 {
+        message.add_avp(25, AvpFlags::M, None, OctetString::new(vec![0xab; length]));
+    }
+    let encoded = encode(&message);
+    let message_length = u32::from_be_bytes([0, encoded[1], encoded[2], encoded[3]]);
+    assert_eq!(message_length as usize, encoded.len());
+    assert_eq!(encoded.len(), 20 + 8 + 12 + 12 + 12 + 12);
+    assert_eq!(
+        avps(&encoded[20..]),
+        vec![(25, 8), (25, 9), (25, 10), (25, 11), (25, 12)]
+    );
+
+    assert_eq!(reencode_avps(&encoded[20..]), &encoded[20..]);
+}
+
+#[test]
+fn grouped_lengths_include_the_padding_of_their_avps() {
+    let mut message = message();
+    message.add_avp(
+        443,
+        AvpFlags::M,
+        None,
+        Grouped::new(vec![
+            Avp::new(
+                444,
+                AvpFlags::M,
+                None,
+                UTF8String::new("001010123456789".to_string()),
+            ),
+            Avp::new(450, AvpFlags::M, None, Unsigned32::new(1)),
+        ]),
+    );
+    let encoded = encode(&message);
+    assert_eq!(encoded.len(), 20 + 8 + 24 + 12);
+    // Subscription-Id-Data is 23 octets, padded to 24 inside the Grouped AVP
+    assert_eq!(avps(&encoded[20..]), vec![(443, 44)]);
+    assert_eq!(avps(&encoded[28..]), vec![(444, 23), (450, 12)]);
+
+    assert_eq!(reencode_avps(&encoded[20..]), &encoded[20..]);
+}
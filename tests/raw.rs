@@ -0,0 +1,196 @@
+mod common;
+
+use common::{dictionary, encode, encode_avp};
+use diameter_interface::modeling::avp::avp::{Avp, AvpFlags};
+use diameter_interface::modeling::avp::octet_string::OctetString;
+use diameter_interface::modeling::avp::unsigned32::Unsigned32;
+use diameter_interface::modeling::avp::utf8_string::{Identity, UTF8String};
+use diameter_interface::modeling::diameter::DiameterMessage;
+use diameter_interface::modeling::message::application_id::ApplicationId;
+use diameter_interface::modeling::message::command_code::CommandCode;
+use diameter_interface::modeling::message::command_flags::CommandFlag;
+use diameter_interface::modeling::raw::RawMessage;
+
+fn identity(value: &str) -> Identity {
+    Identity::new(value.to_string())
+}
+
+fn request() -> DiameterMessage {
+    let mut request = DiameterMessage::new(
+        CommandFlag::Request,
+        CommandCode::CreditControl,
+        ApplicationId::CreditControl,
+        0x11,
+        0x22,
+    );
+    request.set_flag(CommandFlag::Proxyable);
+    request.add_avp(
+        263,
+        AvpFlags::M,
+        None,
+        UTF8String::new("client.example.com;1;1".to_string()),
+    );
+    request.add_avp(282, AvpFlags::M, None, identity("a.example.com"));
+    // Unknown to the dictionary, without the M bit
+    request.add_avp(
+        9999,
+        AvpFlags::O,
+        Some(1234),
+        OctetString::new(vec![1, 2, 3]),
+    );
+    request.add_avp(282, AvpFlags::M, None, identity("b.example.com"));
+    request.add_avp(258, AvpFlags::M, None, Unsigned32::new(4));
+    request
+}
+
+/// The Message Length matches the bytes, and the message decodes to the same length.
+fn assert_consistent(message: &RawMessage) {
+    let bytes = message.as_bytes();
+    let length = u32::from_be_bytes([0, bytes[1], bytes[2], bytes[3]]) as usize;
+    assert_eq!(length, bytes.len());
+    assert_eq!(message.message_length() as usize, bytes.len());
+    let decoded = message.to_message(dictionary()).unwrap();
+    assert_eq!(decoded.encoded_len(), bytes.len());
+}
+
+fn codes(message: &RawMessage) -> Vec<u32> {
+    message.view().avps().map(|avp| avp.code()).collect()
+}
+
+#[test]
+fn header_edits() {
+    let mut message = RawMessage::from_message(&request()).unwrap();
+    assert!(message.is_request());
+    assert!(message.has_flag(CommandFlag::Proxyable));
+    assert_eq!((message.hop_by_hop(), message.end_to_end()), (0x11, 0x22));
+
+    message.set_hop_by_hop(0xdeadbeef);
+    message.set_end_to_end(7);
+    message.set_flag(CommandFlag::Retransmit);
+    message.clear_flag(CommandFlag::Proxyable);
+    assert_eq!(
+        (message.hop_by_hop(), message.end_to_end()),
+        (0xdeadbeef, 7)
+    );
+    assert!(message.has_flag(CommandFlag::Retransmit));
+    assert!(!message.has_flag(CommandFlag::Proxyable));
+
+    let decoded = message.to_message(dictionary()).unwrap();
+    assert_eq!(decoded.hop_by_hop(), 0xdeadbeef);
+    assert_eq!(decoded.end_to_end(), 7);
+    assert!(decoded.has_flag(CommandFlag::Retransmit));
+    assert!(!decoded.has_flag(CommandFlag::Proxyable));
+    assert_eq!(
+        decoded.avps().iter().map(encode_avp).collect::<Vec<_>>(),
+        request().avps().iter().map(encode_avp).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn add_keeps_the_length() {
+    let mut message = RawMessage::from_message(&request()).unwrap();
+    message
+        .add(Avp::new(
+            282,
+            AvpFlags::M,
+            None,
+            identity("relay.example.com"),
+        ))
+        .unwrap();
+    assert_consistent(&message);
+    assert_eq!(codes(&message), vec![263, 282, 9999, 282, 258, 282]);
+    assert_eq!(
+        message.view().avps().last().unwrap().as_str(),
+        Some("relay.example.com")
+    );
+}
+
+#[test]
+fn set_avp_replaces_the_first_occurrence() {
+    let mut message = RawMessage::from_message(&request()).unwrap();
+    message
+        .set_avp(Avp::new(
+            282,
+            AvpFlags::M,
+            None,
+            identity("longer.relay.example.com"),
+        ))
+        .unwrap();
+    assert_consistent(&message);
+    assert_eq!(codes(&message), vec![263, 282, 9999, 282, 258]);
+    let route_records: Vec<_> = message
+        .view()
+        .avps()
+        .filter(|avp| avp.code() == 282)
+        .map(|avp| avp.as_str().unwrap().to_string())
+        .collect();
+    assert_eq!(
+        route_records,
+        vec!["longer.relay.example.com", "b.example.com"]
+    );
+
+    // Appended when absent
+    message
+        .set_avp(Avp::new(
+            293,
+            AvpFlags::M,
+            None,
+            identity("server.example.com"),
+        ))
+        .unwrap();
+    assert_consistent(&message);
+    assert_eq!(codes(&message), vec![263, 282, 9999, 282, 258, 293]);
+}
+
+#[test]
+fn remove_avps_keeps_the_length() {
+    let mut message = RawMessage::from_message(&request()).unwrap();
+    assert_eq!(message.remove_avps(282, None), 2);
+    assert_consistent(&message);
+    assert_eq!(codes(&message), vec![263, 9999, 258]);
+
+    assert_eq!(message.remove_avps(9999, None), 0);
+    assert_eq!(message.remove_avps(9999, Some(1234)), 1);
+    assert_consistent(&message);
+    assert_eq!(codes(&message), vec![263, 258]);
+}
+
+#[test]
+fn unknown_avps_are_preserved() {
+    let encoded = encode(&request());
+    let unknown = RawMessage::new(encoded.clone())
+        .unwrap()
+        .get_avp(9999, Some(1234))
+        .unwrap()
+        .as_bytes()
+        .to_vec();
+
+    let mut message = RawMessage::new(encoded).unwrap();
+    message.set_hop_by_hop(1);
+    message.remove_avps(282, None);
+    message
+        .set_avp(Avp::new(
+            263,
+            AvpFlags::M,
+            None,
+            UTF8String::new("x".to_string()),
+        ))
+        .unwrap();
+    let avp = message.get_avp(9999, Some(1234)).unwrap();
+    assert_eq!(avp.as_bytes(), &unknown[..]);
+    assert!(!avp.is_mandatory());
+    assert_consistent(&message);
+}
+
+#[test]
+fn received_bytes() {
+    let mut encoded = encode(&request());
+    let length = encoded.len();
+    encoded.extend_from_slice(&[1, 2, 3, 4]);
+    let message = RawMessage::new(encoded.clone()).unwrap();
+    assert_eq!(message.as_bytes(), &encoded[..length]);
+    assert_eq!(message.clone().into_bytes().len(), length);
+
+    assert!(RawMessage::new(encoded[..length - 4].to_vec()).is_err());
+    assert!(RawMessage::new(vec![1, 0, 0]).is_err());
+}
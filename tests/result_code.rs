@@ -0,0 +1,89 @@
+mod common;
+
+use common::{encode_avp, wire_round_trip};
+use diameter_interface::modeling::message::base::{ExperimentalResult, ReAuthAnswer};
+use diameter_interface::modeling::message::credit_control::{CcRequestType, CreditControlAnswer};
+use diameter_interface::modeling::message::result_code::{ResultClass, ResultCode};
+use diameter_interface::modeling::typed::{AvpGroup, TypedMessage};
+
+fn cca() -> CreditControlAnswer {
+    CreditControlAnswer::new(
+        "client.example.com;1;1",
+        "ocs.example.com",
+        "example.com",
+        CcRequestType::InitialRequest,
+        0,
+    )
+}
+
+#[test]
+fn classes_and_names() {
+    assert_eq!(ResultCode::SUCCESS.class(), ResultClass::Success);
+    assert!(ResultCode::TOO_BUSY.is_protocol_error());
+    assert_eq!(
+        ResultCode::CREDIT_LIMIT_REACHED.class(),
+        ResultClass::TransientFailure
+    );
+    assert_eq!(ResultCode::new(7000).class(), ResultClass::PermanentFailure);
+    assert_eq!(ResultCode::SUCCESS.to_string(), "DIAMETER_SUCCESS (2001)");
+    assert_eq!(ResultCode::new(2999).to_string(), "2999");
+    assert_eq!(
+        ResultCode::experimental(5535, 5001).to_string(),
+        "5001 (vendor 5535)"
+    );
+    assert_ne!(ResultCode::ERROR_USER_UNKNOWN, ResultCode::AVP_UNSUPPORTED);
+}
+
+#[test]
+fn experimental_result_mapping() {
+    let experimental =
+        ExperimentalResult::from_result_code(ResultCode::ERROR_USER_UNKNOWN).unwrap();
+    assert_eq!(experimental, ExperimentalResult::new(10415, 5001));
+    assert_eq!(experimental.result_code(), ResultCode::ERROR_USER_UNKNOWN);
+    assert!(ExperimentalResult::from_result_code(ResultCode::AVP_UNSUPPORTED).is_none());
+
+    let avps = experimental.to_avps();
+    let avp = ResultCode::ERROR_USER_UNKNOWN.to_avp();
+    let grouped = avp.value().as_grouped().unwrap();
+    assert_eq!(
+        avps.iter().map(encode_avp).collect::<Vec<_>>(),
+        grouped.iter().map(encode_avp).collect::<Vec<_>>()
+    );
+    assert_eq!(ExperimentalResult::from_avps(&avps).unwrap(), experimental);
+}
+
+#[test]
+fn experimental_result_keeps_the_vendor() {
+    let mut cca = cca();
+    cca.result_code = None;
+    cca.experimental_result = ExperimentalResult::from_result_code(ResultCode::ERROR_USER_UNKNOWN);
+
+    let message = wire_round_trip(&cca.to_message(1, 1));
+    assert_eq!(message.result_code(), Some(ResultCode::ERROR_USER_UNKNOWN));
+    let decoded = CreditControlAnswer::from_message(&message).unwrap();
+    assert_eq!(decoded.result(), Some(ResultCode::ERROR_USER_UNKNOWN));
+    assert_eq!(decoded.result_code, None);
+}
+
+#[test]
+fn result_code_fields_are_plain_values() {
+    let mut cca = cca();
+    cca.result_code = Some(ResultCode::CREDIT_LIMIT_REACHED.value());
+    let decoded =
+        CreditControlAnswer::from_message(&wire_round_trip(&cca.to_message(1, 1))).unwrap();
+    assert_eq!(decoded.result(), Some(ResultCode::CREDIT_LIMIT_REACHED));
+
+    let mut raa = ReAuthAnswer::new(
+        "client.example.com;1;1",
+        "client.example.com",
+        "example.com",
+    );
+    raa.result_code = ResultCode::UNKNOWN_SESSION_ID.value();
+    let message = raa.to_message(1, 1);
+    assert_eq!(message.result_code(), Some(ResultCode::UNKNOWN_SESSION_ID));
+    let decoded = ReAuthAnswer::from_message(&message).unwrap();
+    assert_eq!(
+        ResultCode::from(decoded.result_code),
+        ResultCode::UNKNOWN_SESSION_ID
+    );
+}
@@ -0,0 +1,106 @@
+mod common;
+
+use chrono::{DateTime, TimeZone, Utc};
+use common::dictionary;
+use diameter_interface::errors::Error;
+use diameter_interface::modeling::avp::avp::AvpFlags;
+use diameter_interface::modeling::avp::time::Time;
+use diameter_interface::modeling::diameter::DiameterMessage;
+use diameter_interface::modeling::message::application_id::ApplicationId;
+use diameter_interface::modeling::message::command_code::CommandCode;
+use diameter_interface::modeling::message::command_flags::CommandFlag;
+
+const EVENT_TIMESTAMP: u32 = 55;
+
+fn utc(year: i32, month: u32, day: u32, hour: u32, min: u32, sec: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(year, month, day, hour, min, sec)
+        .unwrap()
+}
+
+fn message(time: DateTime<Utc>) -> DiameterMessage {
+    let mut message = DiameterMessage::new(
+        CommandFlag::Request,
+        CommandCode::Accounting,
+        ApplicationId::Accounting,
+        1,
+        1,
+    );
+    message.add_avp(EVENT_TIMESTAMP, AvpFlags::M, None, Time::new(time));
+    message
+}
+
+fn try_encode(message: &DiameterMessage) -> Result<Vec<u8>, Error> {
+    let mut encoded = vec![];
+    message.encode_to(&mut encoded)?;
+    Ok(encoded)
+}
+
+fn decode(encoded: &[u8]) -> Result<DiameterMessage, Error> {
+    DiameterMessage::decode_from(&mut &encoded[..], dictionary())
+}
+
+/// Encodes `time`, checks its 4 octets and decodes it back.
+fn round_trip(time: DateTime<Utc>, expected: u32) {
+    let message = message(time);
+    let encoded = try_encode(&message).unwrap();
+    assert_eq!(encoded.len(), message.encoded_len());
+    assert_eq!(&encoded[28..], &expected.to_be_bytes());
+
+    let decoded = decode(&encoded).unwrap();
+    let value = decoded.get_avp(EVENT_TIMESTAMP, None).unwrap().value();
+    assert_eq!(value.as_time(), Some(&time));
+    assert_eq!(try_encode(&decoded).unwrap(), encoded);
+}
+
+#[test]
+fn era_boundaries() {
+    // The last second of era 0 and the first of era 1
+    round_trip(utc(2036, 2, 7, 6, 28, 15), 0xffff_ffff);
+    round_trip(utc(2036, 2, 7, 6, 28, 16), 0);
+    // The first second of era 0, with the high bit set
+    round_trip(utc(1968, 1, 20, 3, 14, 8), 0x8000_0000);
+    // The last second of era 1, with the high bit clear
+    round_trip(utc(2104, 2, 26, 9, 42, 23), 0x7fff_ffff);
+    round_trip(utc(2024, 5, 1, 12, 0, 0), 3_923_553_600);
+}
+
+#[test]
+fn outside_the_range() {
+    for time in [utc(1968, 1, 20, 3, 14, 7), utc(2104, 2, 26, 9, 42, 24)] {
+        match try_encode(&message(time)) {
+            Err(Error::EncodeError(reason)) => {
+                assert_eq!(reason, "Time is outside the range of 1968 to 2104")
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+}
+
+#[test]
+fn fractions_are_dropped() {
+    let time = utc(2024, 5, 1, 12, 0, 0);
+    let encoded = try_encode(&message(time + chrono::Duration::milliseconds(750))).unwrap();
+    let decoded = decode(&encoded).unwrap();
+    let value = decoded.get_avp(EVENT_TIMESTAMP, None).unwrap().value();
+    assert_eq!(value.as_time(), Some(&time));
+}
+
+#[test]
+fn other_lengths_are_rejected() {
+    let encoded = try_encode(&message(utc(2024, 5, 1, 12, 0, 0))).unwrap();
+    for length in [0u8, 3, 8] {
+        let value_length = length as usize;
+        let mut invalid = encoded[..20].to_vec();
+        invalid.extend_from_slice(&EVENT_TIMESTAMP.to_be_bytes());
+        invalid.extend_from_slice(&[0x40, 0, 0, 8 + length]);
+        invalid.extend(std::iter::repeat_n(0xe9, value_length));
+        invalid.resize(20 + (8 + value_length).div_ceil(4) * 4, 0);
+        let message_length = invalid.len() as u32;
+        invalid[1..4].copy_from_slice(&message_length.to_be_bytes()[1..]);
+
+        match decode(&invalid) {
+            Err(Error::DecodeError(reason)) => assert_eq!(reason, "Invalid Time length"),
+            other => panic!("unexpected {:?} for length {}", other, length),
+        }
+    }
+}
@@ -0,0 +1,277 @@
+mod common;
+
+use common::wire_round_trip_with;
+use diameter_interface::avp_enum;
+use diameter_interface::errors::Error;
+use diameter_interface::modeling::avp::avp::{Avp, AvpFlags};
+use diameter_interface::modeling::avp::enumerated::Enumerated;
+use diameter_interface::modeling::avp::unsigned32::Unsigned32;
+use diameter_interface::modeling::avp::utf8_string::UTF8String;
+use diameter_interface::modeling::diameter::DiameterMessage;
+use diameter_interface::modeling::message::application_id::ApplicationId;
+use diameter_interface::modeling::message::command_code::CommandCode;
+use diameter_interface::modeling::message::command_flags::CommandFlag;
+use diameter_interface::modeling::message::dictionary::{self, Dictionary};
+use diameter_interface::modeling::typed::{
+    AvpField, AvpGroup, DiameterAvp, DiameterMessage, TypedMessage,
+};
+use std::net::{IpAddr, Ipv4Addr};
+use std::sync::Arc;
+
+avp_enum! {
+    pub enum SubscriptionIdType {
+        EndUserE164 = 0,
+        EndUserImsi = 1,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, DiameterAvp)]
+pub struct SubscriptionId {
+    #[avp(code = 450, mandatory)]
+    pub subscription_id_type: SubscriptionIdType,
+    #[avp(code = 444, mandatory)]
+    pub subscription_id_data: String,
+}
+
+#[derive(Debug, Clone, PartialEq, DiameterAvp)]
+pub struct UsedServiceUnit {
+    #[avp(code = 420, mandatory)]
+    pub cc_time: Option<u32>,
+    #[avp(code = 421, mandatory)]
+    pub cc_total_octets: Option<u64>,
+}
+
+#[derive(Debug, Clone, PartialEq, DiameterMessage)]
+#[diameter(command = CreditControl, application = Gx, request, proxyable)]
+pub struct Ccr {
+    #[avp(code = 263, mandatory)]
+    pub session_id: String,
+    #[avp(code = 416, mandatory)]
+    pub cc_request_type: i32,
+    #[avp(code = 443, mandatory, min = 1)]
+    pub subscription_id: Vec<SubscriptionId>,
+    #[avp(code = 446, mandatory)]
+    pub used_service_unit: Option<UsedServiceUnit>,
+    #[avp(code = 8, mandatory)]
+    pub framed_ip_address: Option<Vec<u8>>,
+    #[avp(code = 1027, vendor = 10415, mandatory)]
+    pub ip_can_type: Option<i32>,
+    #[avp(code = 501, vendor = 10415)]
+    pub an_gw_address: Vec<IpAddr>,
+    #[avp(rest)]
+    pub other: Vec<Avp>,
+}
+
+#[derive(Debug, Clone, PartialEq, DiameterMessage)]
+#[diameter(command = CreditControl, application = Gx)]
+pub struct Cca {
+    #[avp(code = 263, mandatory)]
+    pub session_id: String,
+    #[avp(code = 268, mandatory)]
+    pub result_code: Option<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, DiameterMessage)]
+#[diameter(command = CreditControl, application = CreditControl, request,
+           application_field = auth_application_id)]
+pub struct SessionRequest {
+    #[avp(code = 263, mandatory)]
+    pub session_id: String,
+    #[avp(code = 258, mandatory)]
+    pub auth_application_id: u32,
+}
+
+fn ccr() -> Ccr {
+    Ccr {
+        session_id: "host.example.com;1;1".to_string(),
+        cc_request_type: 1,
+        subscription_id: vec![
+            SubscriptionId {
+                subscription_id_type: SubscriptionIdType::EndUserImsi,
+                subscription_id_data: "001010123456789".to_string(),
+            },
+            SubscriptionId {
+                subscription_id_type: SubscriptionIdType::Unknown(4),
+                subscription_id_data: "sip:user@example.com".to_string(),
+            },
+        ],
+        used_service_unit: Some(UsedServiceUnit {
+            cc_time: Some(60),
+            cc_total_octets: None,
+        }),
+        framed_ip_address: Some(vec![10, 0, 0, 1]),
+        ip_can_type: Some(5),
+        an_gw_address: vec![IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1))],
+        other: vec![Avp::new(
+            461,
+            AvpFlags::M,
+            None,
+            UTF8String::new("service".to_string()),
+        )],
+    }
+}
+
+const GX_XML: &str = r#"
+<diameter>
+	<application id="16777238" name="Gx">
+		<avp name="Framed-IP-Address" code="8" must="M" may="P" must-not="V" may-encrypt="Y">
+			<data type="OctetString"/>
+		</avp>
+		<avp name="IP-CAN-Type" code="1027" must="M,V" may="P" may-encrypt="Y" vendor-id="10415">
+			<data type="Enumerated"/>
+		</avp>
+		<avp name="AN-GW-Address" code="501" must="V" may="P" must-not="M" may-encrypt="Y" vendor-id="10415">
+			<data type="Address"/>
+		</avp>
+	</application>
+</diameter>"#;
+
+fn gx_round_trip(message: &DiameterMessage) -> DiameterMessage {
+    let dict = Arc::new(Dictionary::new(&[&dictionary::DEFAULT_DICT_XML, GX_XML]));
+    wire_round_trip_with(message, dict)
+}
+
+#[test]
+fn message_round_trip() {
+    let ccr = ccr();
+    let message = ccr.to_message(7, 9);
+    assert_eq!(message.command_code(), CommandCode::CreditControl);
+    assert_eq!(message.application_id(), ApplicationId::Gx);
+    assert!(message.is_request());
+    assert!(message.has_flag(CommandFlag::Proxyable));
+    assert_eq!((message.hop_by_hop(), message.end_to_end()), (7, 9));
+
+    assert_eq!(Ccr::from_message(&message).unwrap(), ccr);
+    assert_eq!(Ccr::from_message(&gx_round_trip(&message)).unwrap(), ccr);
+}
+
+#[test]
+fn field_order_and_flags() {
+    let avps = ccr().to_avps();
+    let keys: Vec<_> = avps
+        .iter()
+        .map(|avp| (avp.code(), avp.vendor_id()))
+        .collect();
+    assert_eq!(
+        keys,
+        vec![
+            (263, None),
+            (416, None),
+            (443, None),
+            (443, None),
+            (446, None),
+            (8, None),
+            (1027, Some(10415)),
+            (501, Some(10415)),
+            (461, None),
+        ]
+    );
+    assert!(avps[6].is_mandatory());
+    assert!(!avps[7].is_mandatory());
+}
+
+#[test]
+fn unknown_enum_values_are_kept() {
+    let avp = Avp::new(450, AvpFlags::M, None, Enumerated::new(4));
+    assert_eq!(
+        SubscriptionIdType::from_avp_value(avp.value()).unwrap(),
+        SubscriptionIdType::Unknown(4)
+    );
+    assert_eq!(SubscriptionIdType::Unknown(4).to_avp_value(), *avp.value());
+    assert_eq!(SubscriptionIdType::from(1), SubscriptionIdType::EndUserImsi);
+    assert_eq!(SubscriptionIdType::EndUserImsi.value(), 1);
+}
+
+#[test]
+fn extra_occurrences_go_to_rest() {
+    let mut avps = ccr().to_avps();
+    let second_session_id = Avp::new(
+        263,
+        AvpFlags::M,
+        None,
+        UTF8String::new("other;1;1".to_string()),
+    );
+    avps.push(second_session_id.clone());
+
+    let decoded = Ccr::from_avps(&avps).unwrap();
+    assert_eq!(decoded.session_id, "host.example.com;1;1");
+    assert_eq!(decoded.other.last(), Some(&second_session_id));
+}
+
+#[test]
+fn extra_occurrences_without_rest_fail() {
+    let mut message = Cca {
+        session_id: "host.example.com;1;1".to_string(),
+        result_code: Some(2001),
+    }
+    .to_message(1, 1);
+    message.add_avp(268, AvpFlags::M, None, Unsigned32::new(5012));
+
+    match Cca::from_message(&message) {
+        Err(Error::TooManyAvps(268, None)) => {}
+        other => panic!("unexpected {:?}", other),
+    }
+}
+
+#[test]
+fn missing_and_minimum_occurrences() {
+    let mut without_subscription = ccr();
+    without_subscription.subscription_id.clear();
+    match Ccr::from_avps(&without_subscription.to_avps()) {
+        Err(Error::MissingAvp(443, None)) => {}
+        other => panic!("unexpected {:?}", other),
+    }
+
+    let avps: Vec<Avp> = ccr()
+        .to_avps()
+        .into_iter()
+        .filter(|avp| avp.code() != 263)
+        .collect();
+    match Ccr::from_avps(&avps) {
+        Err(Error::MissingAvp(263, None)) => {}
+        other => panic!("unexpected {:?}", other),
+    }
+}
+
+#[test]
+fn unexpected_command_or_direction() {
+    let answer = Cca {
+        session_id: "host.example.com;1;1".to_string(),
+        result_code: None,
+    }
+    .to_message(1, 1);
+    assert!(Ccr::from_message(&answer).is_err());
+
+    let mut dwr = DiameterMessage::new(
+        CommandFlag::Request,
+        CommandCode::DeviceWatchdog,
+        ApplicationId::Common,
+        1,
+        1,
+    );
+    dwr.add_avp(
+        263,
+        AvpFlags::M,
+        None,
+        UTF8String::new("host.example.com;1;1".to_string()),
+    );
+    assert!(Ccr::from_message(&dwr).is_err());
+}
+
+#[test]
+fn application_field_sets_header() {
+    let request = SessionRequest {
+        session_id: "host.example.com;1;1".to_string(),
+        auth_application_id: 16777238,
+    };
+    assert_eq!(request.to_message(1, 1).application_id(), ApplicationId::Gx);
+
+    let unknown = SessionRequest {
+        auth_application_id: 99,
+        ..request
+    };
+    assert_eq!(
+        unknown.to_message(1, 1).application_id(),
+        ApplicationId::CreditControl
+    );
+}
@@ -0,0 +1,142 @@
+mod common;
+
+use common::encode;
+use diameter_interface::modeling::diameter::DiameterMessage;
+use diameter_interface::modeling::message::application_id::ApplicationId;
+use diameter_interface::modeling::message::base::{
+    CapabilitiesExchangeAnswer, CapabilitiesExchangeRequest,
+};
+use diameter_interface::modeling::message::command_code::CommandCode;
+use diameter_interface::modeling::message::command_flags::CommandFlag;
+use diameter_interface::modeling::message::dictionary::{self, Dictionary};
+use diameter_interface::modeling::typed::TypedMessage;
+use std::net::{IpAddr, Ipv4Addr};
+
+const VENDOR_XML: &str = r#"
+<diameter>
+	<vendor id="10415" name="3GPP"/>
+	<vendor id="5535" name="3GPP2"/>
+	<application id="16777238" name="Gx">
+		<avp name="Timezone-Offset" code="571" must="V" may="P" must-not="M" may-encrypt="Y" vendor-id="10415">
+			<data type="Integer32"/>
+		</avp>
+		<avp name="Timezone-Offset" code="571" must="V" may="P" must-not="M" may-encrypt="Y" vendor-id="5535">
+			<data type="Integer32"/>
+		</avp>
+		<avp name="Session-Id" code="263" must="M" may="P" must-not="V" may-encrypt="Y">
+			<data type="UTF8String"/>
+		</avp>
+	</application>
+</diameter>"#;
+
+#[test]
+fn vendors_from_xml() {
+    let dict = Dictionary::new(&[VENDOR_XML]);
+    let vendors: Vec<_> = dict.vendors().collect();
+    assert_eq!(vendors, vec![(5535, "3GPP2"), (10415, "3GPP")]);
+    assert_eq!(dict.get_vendor_name(10415), Some("3GPP"));
+    assert_eq!(dict.get_vendor_name(1), None);
+    assert_eq!(dict.get_vendor_id("3gpp"), Some(10415));
+    assert_eq!(dict.get_vendor_id("ETSI"), None);
+}
+
+#[test]
+fn default_dictionary_knows_3gpp() {
+    let dict = Dictionary::new(&[&dictionary::DEFAULT_DICT_XML]);
+    assert_eq!(dict.get_vendor_name(10415), Some("3GPP"));
+}
+
+#[test]
+fn avps_by_vendor() {
+    let mut dict = Dictionary::new(&[VENDOR_XML]);
+    dict.add_vendor(13019, "ETSI");
+
+    let tgpp: Vec<_> = dict
+        .get_vendor_avps(Some(10415))
+        .map(|avp| avp.code)
+        .collect();
+    assert_eq!(tgpp, vec![571]);
+    let ietf: Vec<_> = dict.get_vendor_avps(None).map(|avp| avp.code).collect();
+    assert_eq!(ietf, vec![263]);
+    assert_eq!(dict.get_vendor_avps(Some(13019)).count(), 0);
+
+    let avp = dict
+        .get_vendor_avp_by_name("Timezone-Offset", "3GPP2")
+        .unwrap();
+    assert_eq!(avp.vendor_id, Some(5535));
+    assert!(dict.get_vendor_avp_by_name("Session-Id", "3GPP").is_none());
+    assert!(
+        dict.get_vendor_avp_by_name("Timezone-Offset", "ETSI")
+            .is_none()
+    );
+}
+
+#[test]
+fn supported_vendor_ids_in_cer() {
+    let dict = Dictionary::new(&[VENDOR_XML]);
+    let mut cer = DiameterMessage::new(
+        CommandFlag::Request,
+        CommandCode::CapabilitiesExchange,
+        ApplicationId::Common,
+        1,
+        1,
+    );
+    cer.add_supported_vendor_ids(&dict);
+
+    let encoded = encode(&cer);
+    assert_eq!(encoded.len(), 20 + 2 * 12);
+    let vendor_ids: Vec<_> = encoded[20..]
+        .chunks(12)
+        .map(|avp| {
+            // Supported-Vendor-Id, M bit set, length 12
+            assert_eq!(avp[..8], [0, 0, 1, 9, 0x40, 0, 0, 12]);
+            u32::from_be_bytes([avp[8], avp[9], avp[10], avp[11]])
+        })
+        .collect();
+    assert_eq!(vendor_ids, vec![5535, 10415]);
+}
+
+#[test]
+fn avps_by_name_and_vendor() {
+    let dict = Dictionary::new(&[VENDOR_XML]);
+
+    // The first definition, whichever vendor defines it
+    let first = dict.get_avp_by_name("Timezone-Offset").unwrap();
+    assert_eq!(first.code, 571);
+    assert!(dict.get_unique_avp_by_name("Timezone-Offset").is_none());
+    assert_eq!(dict.get_unique_avp_by_name("Session-Id").unwrap().code, 263);
+
+    let tgpp = dict
+        .get_avp_by_name_and_vendor("Timezone-Offset", Some(10415))
+        .unwrap();
+    assert_eq!(tgpp.vendor_id, Some(10415));
+    assert!(
+        dict.get_avp_by_name_and_vendor("Timezone-Offset", None)
+            .is_none()
+    );
+    assert!(
+        dict.get_avp_by_name_and_vendor("Session-Id", None)
+            .is_some()
+    );
+}
+
+#[test]
+fn supported_vendor_ids_in_typed_capabilities_exchange() {
+    let dict = Dictionary::new(&[VENDOR_XML]);
+    let ip = IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1));
+
+    let cer =
+        CapabilitiesExchangeRequest::new("client.example.com", "example.com", ip, 0, "client")
+            .with_supported_vendor_ids(&dict)
+            .to_message(1, 1);
+    let cer = CapabilitiesExchangeRequest::from_message(&cer).unwrap();
+    assert_eq!(cer.supported_vendor_id, vec![5535, 10415]);
+
+    let cea = CapabilitiesExchangeAnswer::new("server.example.com", "example.com", ip, 0, "server")
+        .with_supported_vendor_ids(&dict)
+        .to_message(1, 1);
+    let supported = cea.avps().iter().filter(|avp| avp.code() == 265).count();
+    assert_eq!(supported, 2);
+    let cea = CapabilitiesExchangeAnswer::from_message(&cea).unwrap();
+    assert_eq!(cea.supported_vendor_id, vec![5535, 10415]);
+}
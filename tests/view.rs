@@ -0,0 +1,210 @@
+mod common;
+
+use common::{dictionary, encode, encode_avp};
+use diameter_interface::errors::Error;
+use diameter_interface::modeling::avp::address::Address;
+use diameter_interface::modeling::avp::avp::{Avp, AvpFlags};
+use diameter_interface::modeling::avp::group::Grouped;
+use diameter_interface::modeling::avp::integer64::Integer64;
+use diameter_interface::modeling::avp::octet_string::OctetString;
+use diameter_interface::modeling::avp::unsigned32::Unsigned32;
+use diameter_interface::modeling::avp::utf8_string::UTF8String;
+use diameter_interface::modeling::diameter::DiameterMessage;
+use diameter_interface::modeling::message::application_id::ApplicationId;
+use diameter_interface::modeling::message::command_code::CommandCode;
+use diameter_interface::modeling::message::command_flags::CommandFlag;
+use diameter_interface::modeling::view::MessageRef;
+use std::net::{IpAddr, Ipv6Addr};
+
+fn message() -> DiameterMessage {
+    let mut message = DiameterMessage::new(
+        CommandFlag::Request,
+        CommandCode::CreditControl,
+        ApplicationId::CreditControl,
+        0x01020304,
+        0x05060708,
+    );
+    message.set_flag(CommandFlag::Proxyable);
+    message.add_avp(
+        263,
+        AvpFlags::M,
+        None,
+        UTF8String::new("host;1;1".to_string()),
+    );
+    message.add_avp(415, AvpFlags::M, None, Unsigned32::new(3));
+    message.add_avp(
+        257,
+        AvpFlags::M,
+        None,
+        Address::new(IpAddr::V6(Ipv6Addr::LOCALHOST)),
+    );
+    message.add_avp(
+        443,
+        AvpFlags::M,
+        None,
+        Grouped::new(vec![
+            Avp::new(450, AvpFlags::M, None, Unsigned32::new(1)),
+            Avp::new(
+                444,
+                AvpFlags::M,
+                None,
+                UTF8String::new("001010123456789".to_string()),
+            ),
+        ]),
+    );
+    message.add_avp(1001, AvpFlags::O, Some(10415), Integer64::new(-2));
+    message.add_avp(25, AvpFlags::M, None, OctetString::new(vec![1, 2, 3]));
+    message
+}
+
+#[test]
+fn header_fields() {
+    let encoded = encode(&message());
+    let view = MessageRef::new(&encoded).unwrap();
+    assert_eq!(view.version(), 1);
+    assert_eq!(view.message_length() as usize, encoded.len());
+    assert_eq!(view.command_flags(), 0xc0);
+    assert!(view.is_request());
+    assert!(view.has_flag(CommandFlag::Proxyable));
+    assert_eq!(view.command_code().unwrap(), CommandCode::CreditControl);
+    assert_eq!(view.application_id().unwrap(), ApplicationId::CreditControl);
+    assert_eq!(view.hop_by_hop(), 0x01020304);
+    assert_eq!(view.end_to_end(), 0x05060708);
+    assert_eq!(view.as_bytes(), &encoded[..]);
+}
+
+#[test]
+fn borrowed_values() {
+    let encoded = encode(&message());
+    let view = MessageRef::new(&encoded).unwrap();
+
+    let codes: Vec<_> = view
+        .avps()
+        .map(|avp| (avp.code(), avp.vendor_id()))
+        .collect();
+    assert_eq!(
+        codes,
+        vec![
+            (263, None),
+            (415, None),
+            (257, None),
+            (443, None),
+            (1001, Some(10415)),
+            (25, None)
+        ]
+    );
+
+    let session_id = view.get_avp(263, None).unwrap();
+    assert_eq!(session_id.as_str(), Some("host;1;1"));
+    assert!(session_id.is_mandatory());
+    assert_eq!((session_id.get_length(), session_id.get_padding()), (16, 0));
+    // The value points into the received buffer
+    let range = encoded.as_ptr_range();
+    assert!(range.contains(&session_id.as_bytes().as_ptr()));
+
+    assert_eq!(view.get_avp(415, None).unwrap().as_u32(), Some(3));
+    assert_eq!(
+        view.get_avp(257, None).unwrap().as_ip_addr(),
+        Some(IpAddr::V6(Ipv6Addr::LOCALHOST))
+    );
+    let vendor = view.get_avp(1001, Some(10415)).unwrap();
+    assert!(!vendor.is_mandatory());
+    assert_eq!(vendor.as_i64(), Some(-2));
+    assert!(view.get_avp(1001, None).is_none());
+    let class = view.get_avp(25, None).unwrap();
+    assert_eq!(class.as_bytes(), &[1, 2, 3]);
+    assert_eq!((class.get_length(), class.get_padding()), (11, 1));
+    assert_eq!(class.as_u32(), None);
+}
+
+#[test]
+fn grouped_avps() {
+    let encoded = encode(&message());
+    let view = MessageRef::new(&encoded).unwrap();
+    let subscription_id: Vec<_> = view
+        .get_avp(443, None)
+        .unwrap()
+        .as_grouped()
+        .unwrap()
+        .map(|avp| avp.code())
+        .collect();
+    assert_eq!(subscription_id, vec![450, 444]);
+
+    // A value that is not a sequence of AVPs
+    assert!(view.get_avp(263, None).unwrap().as_grouped().is_none());
+}
+
+#[test]
+fn conversion_to_owned() {
+    let message = message();
+    let encoded = encode(&message);
+    let view = MessageRef::new(&encoded).unwrap();
+    let owned = view.to_message(dictionary()).unwrap();
+    let decoded = DiameterMessage::decode_from(&mut encoded.as_slice(), dictionary()).unwrap();
+    assert_eq!(encode(&owned), encode(&decoded));
+    assert_eq!(encode(&owned), encoded);
+
+    let class = view
+        .get_avp(25, None)
+        .unwrap()
+        .to_avp(dictionary())
+        .unwrap();
+    assert_eq!(
+        encode_avp(&class),
+        encode_avp(message.get_avp(25, None).unwrap())
+    );
+}
+
+#[test]
+fn trailing_bytes_are_ignored() {
+    let mut encoded = encode(&message());
+    let length = encoded.len();
+    encoded.extend_from_slice(&[1, 0, 0, 20]);
+    let view = MessageRef::new(&encoded).unwrap();
+    assert_eq!(view.as_bytes().len(), length);
+    assert_eq!(view.avps().count(), 6);
+}
+
+fn rejected(data: &[u8], reason: &str) {
+    match MessageRef::new(data) {
+        Err(Error::DecodeError(message)) => assert_eq!(message, reason),
+        other => panic!("unexpected {:?}", other),
+    }
+}
+
+#[test]
+fn invalid_messages() {
+    let encoded = encode(&message());
+    rejected(&encoded[..19], "Message header is truncated");
+    rejected(
+        &encoded[..encoded.len() - 4],
+        "Message is shorter than its length",
+    );
+
+    let mut version = encoded.clone();
+    version[0] = 2;
+    rejected(&version, "Unsupported Diameter version");
+
+    let mut length = encoded.clone();
+    length[3] = 18;
+    length[2] = 0;
+    length[1] = 0;
+    rejected(&length, "Invalid message length");
+
+    // Session-Id claiming more octets than the message holds
+    let mut avp_length = encoded.clone();
+    avp_length[20 + 7] = 0xff;
+    rejected(&avp_length, "AVP length exceeds the enclosing length");
+
+    let mut short_avp = encoded.clone();
+    short_avp[20 + 7] = 4;
+    rejected(&short_avp, "Invalid AVP length");
+}
+
+#[cfg(feature = "bytes")]
+#[test]
+fn views_over_bytes() {
+    let encoded = bytes::Bytes::from(encode(&message()));
+    let view = MessageRef::new(&encoded).unwrap();
+    assert_eq!(view.get_avp(415, None).unwrap().as_u32(), Some(3));
+}
@@ -0,0 +1,110 @@
+use diameter_interface::errors::{DiameterResult, Error};
+use diameter_interface::modeling::avp::avp::AvpType;
+use diameter_interface::modeling::avp::custom::AvpCodec;
+use diameter_interface::modeling::codec::Writer;
+use diameter_interface::modeling::message::dictionary::Dictionary;
+use diameter_interface::modeling::message::wireshark::WiresharkImport;
+use std::path::Path;
+
+const FIXTURE: &str = "tests/fixtures/wireshark/dictionary.xml";
+
+fn import(dictionary: &mut Dictionary) -> WiresharkImport {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(FIXTURE);
+    dictionary.load_wireshark_file(path).unwrap()
+}
+
+#[derive(Debug, Clone)]
+struct Tbcd(String);
+
+impl AvpCodec for Tbcd {
+    fn encode_to(&self, writer: &mut dyn Writer) -> DiameterResult<()> {
+        writer.write_all(self.0.as_bytes())?;
+        Ok(())
+    }
+
+    fn encoded_len(&self) -> u32 {
+        self.0.len() as u32
+    }
+
+    fn decode(data: &[u8]) -> DiameterResult<Self> {
+        let value = String::from_utf8(data.to_vec()).map_err(|_| Error::DecodeError("TBCD"))?;
+        Ok(Tbcd(value))
+    }
+}
+
+#[test]
+fn imports_avps_through_typedefs() {
+    let mut dict = Dictionary::empty();
+    import(&mut dict);
+
+    let avp_type = |name: &str| &dict.get_avp_by_name(name).unwrap().avp_type;
+    assert_eq!(avp_type("Session-Id"), &AvpType::UTF8String);
+    assert_eq!(avp_type("Origin-Host"), &AvpType::Identity);
+    assert_eq!(avp_type("Auth-Application-Id"), &AvpType::Unsigned32);
+    assert_eq!(avp_type("Host-IP-Address"), &AvpType::Address);
+    assert_eq!(avp_type("Charging-Rule-Install"), &AvpType::Grouped);
+    assert_eq!(avp_type("MSISDN"), &AvpType::OctetString); // TBCDString without a codec
+
+    let session_id = dict.get_avp(263, None).unwrap();
+    assert!(session_id.m_flag);
+}
+
+#[test]
+fn resolves_vendors_and_entities() {
+    let mut dict = Dictionary::empty();
+    import(&mut dict);
+
+    assert_eq!(
+        dict.get_avp_name(1001, Some(10415)),
+        Some("Charging-Rule-Install")
+    );
+    // From TGPP.xml, included through the &TGPP; entity
+    assert_eq!(dict.get_avp_name(701, Some(10415)), Some("MSISDN"));
+    assert!(!dict.get_avp(2, Some(10415)).unwrap().m_flag);
+}
+
+#[test]
+fn registered_codec_ends_typedef_chain() {
+    let mut dict = Dictionary::empty();
+    dict.register_codec::<Tbcd>("TBCDString");
+    import(&mut dict);
+    assert_eq!(
+        dict.get_avp_type(701, Some(10415)),
+        Some(&AvpType::Custom("TBCDString".into()))
+    );
+}
+
+#[test]
+fn reports_skipped_applications_and_commands() {
+    let mut dict = Dictionary::empty();
+    let import = import(&mut dict);
+
+    assert_eq!(
+        import.skipped_applications,
+        vec![(16777251, "3GPP S6a/S6d".to_string())]
+    );
+    assert_eq!(
+        import.skipped_commands,
+        vec![
+            (316, "Update-Location".to_string()),
+            (317, "Cancel-Location".to_string())
+        ]
+    );
+    assert!(!import.is_complete());
+
+    // AVPs of skipped applications are still imported
+    assert_eq!(dict.get_avp_name(1405, Some(10415)), Some("ULR-Flags"));
+}
+
+#[test]
+fn entities_need_a_file() {
+    let xml = std::fs::read_to_string(Path::new(env!("CARGO_MANIFEST_DIR")).join(FIXTURE)).unwrap();
+    assert!(Dictionary::empty().load_wireshark_xml(&xml).is_err());
+
+    let inline = r#"<dictionary><base>
+        <avp name="Session-Id" code="263" mandatory="must"><type type-name="UTF8String"/></avp>
+    </base></dictionary>"#;
+    let mut dict = Dictionary::empty();
+    assert!(dict.load_wireshark_xml(inline).unwrap().is_complete());
+    assert_eq!(dict.get_avp_name(263, None), Some("Session-Id"));
+}